@@ -4,17 +4,6 @@ use std::{fs, mem};
 
 use json::validation::Checked::Valid;
 use json::validation::USize64;
-use std::borrow::Cow;
-use std::io::Write;
-
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-enum Output {
-    /// Output standard glTF.
-    Standard,
-
-    /// Output binary glTF.
-    Binary,
-}
 
 #[derive(Copy, Clone, Debug, bytemuck::NoUninit)]
 #[repr(C)]
@@ -38,22 +27,7 @@ fn bounding_coords(points: &[Vertex]) -> ([f32; 3], [f32; 3]) {
     (min, max)
 }
 
-fn align_to_multiple_of_four(n: &mut usize) {
-    *n = (*n + 3) & !3;
-}
-
-fn to_padded_byte_vector<T: bytemuck::NoUninit>(data: &[T]) -> Vec<u8> {
-    let byte_slice: &[u8] = bytemuck::cast_slice(data);
-    let mut new_vec: Vec<u8> = byte_slice.to_owned();
-
-    while new_vec.len() % 4 != 0 {
-        new_vec.push(0); // pad to multiple of four bytes
-    }
-
-    new_vec
-}
-
-fn export(output: Output) {
+fn export() {
     let triangle_vertices = vec![
         Vertex {
             position: [0.0, 0.5, 0.0],
@@ -79,11 +53,8 @@ fn export(output: Output) {
         extensions: Default::default(),
         extras: Default::default(),
         name: None,
-        uri: if output == Output::Standard {
-            Some("buffer0.bin".into())
-        } else {
-            None
-        },
+        // Assigned by `gltf::export`.
+        uri: None,
     });
     let buffer_view = root.push(json::buffer::View {
         buffer,
@@ -163,40 +134,16 @@ fn export(output: Output) {
         nodes: vec![node],
     });
 
-    match output {
-        Output::Standard => {
-            let _ = fs::create_dir("triangle");
-
-            let writer = fs::File::create("triangle/triangle.gltf").expect("I/O error");
-            json::serialize::to_writer_pretty(writer, &root).expect("Serialization error");
+    let document = gltf::Document::from_json(root).expect("Validation error");
+    let buffers = [gltf::buffer::Data(
+        bytemuck::cast_slice(&triangle_vertices).to_vec(),
+    )];
 
-            let bin = to_padded_byte_vector(&triangle_vertices);
-            let mut writer = fs::File::create("triangle/buffer0.bin").expect("I/O error");
-            writer.write_all(&bin).expect("I/O error");
-        }
-        Output::Binary => {
-            let json_string = json::serialize::to_string(&root).expect("Serialization error");
-            let mut json_offset = json_string.len();
-            align_to_multiple_of_four(&mut json_offset);
-            let glb = gltf::binary::Glb {
-                header: gltf::binary::Header {
-                    magic: *b"glTF",
-                    version: 2,
-                    // N.B., the size of binary glTF file is limited to range of `u32`.
-                    length: (json_offset + buffer_length)
-                        .try_into()
-                        .expect("file size exceeds binary glTF limit"),
-                },
-                bin: Some(Cow::Owned(to_padded_byte_vector(&triangle_vertices))),
-                json: Cow::Owned(json_string.into_bytes()),
-            };
-            let writer = std::fs::File::create("triangle.glb").expect("I/O error");
-            glb.to_writer(writer).expect("glTF binary output error");
-        }
-    }
+    let _ = fs::create_dir("triangle");
+    gltf::export("triangle/triangle.gltf", &document, &buffers, None).expect("Export error");
+    gltf::export("triangle.glb", &document, &buffers, None).expect("Export error");
 }
 
 fn main() {
-    export(Output::Standard);
-    export(Output::Binary);
+    export();
 }
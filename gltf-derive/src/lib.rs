@@ -14,6 +14,11 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
     expand(&syn::parse_macro_input!(input as DeriveInput)).into()
 }
 
+#[proc_macro_derive(Visit)]
+pub fn derive_visit(input: TokenStream) -> TokenStream {
+    expand_visit(&syn::parse_macro_input!(input as DeriveInput)).into()
+}
+
 struct ValidateHook(pub syn::Ident);
 
 impl syn::parse::Parse for ValidateHook {
@@ -53,10 +58,10 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
     let ident = &ast.ident;
     let validations: Vec<TokenStream> = fields
         .iter()
-        .map(|f| f.ident.as_ref().unwrap())
-        .map(|ident| {
+        .map(|f| {
             use inflections::Inflect;
-            let field = ident.to_string().to_camel_case();
+            let ident = f.ident.as_ref().unwrap();
+            let field = serde_rename(f).unwrap_or_else(|| ident.to_string().to_camel_case());
             quote!(
                 self.#ident.validate(
                     _root,
@@ -89,3 +94,72 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
         }
     )
 }
+
+/// Returns the name given to a field by `#[serde(rename = "...")]`, so that
+/// validation paths match the JSON, e.g. `extensions.KHR_lights_punctual`.
+fn serde_rename(field: &syn::Field) -> Option<String> {
+    use syn::punctuated::Punctuated;
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+        let metas = attr
+            .parse_args_with(Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+            .expect("failed to parse serde attribute");
+        for meta in metas {
+            if let syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(name),
+                        ..
+                    }),
+                ..
+            }) = meta
+            {
+                if path.is_ident("rename") {
+                    return Some(name.value());
+                }
+            }
+        }
+    }
+    None
+}
+
+fn expand_visit(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    use proc_macro2::TokenStream;
+    use quote::quote;
+
+    let fields = match ast.data {
+        syn::Data::Struct(ref data_struct) => &data_struct.fields,
+        _ => panic!("#[derive(Visit)] only works on `struct`s"),
+    };
+    let ident = &ast.ident;
+    let visits: Vec<TokenStream> = fields
+        .iter()
+        .map(|f| f.ident.as_ref().unwrap())
+        .map(|ident| {
+            quote!(
+                _keep &= self.#ident.visit(_visitor)
+            )
+        })
+        .collect();
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    quote!(
+        impl #impl_generics crate::visit::Visit
+            for #ident #ty_generics #where_clause
+        {
+            fn visit<V>(&mut self, _visitor: &mut V) -> bool
+            where
+                V: crate::visit::Visitor,
+            {
+                let mut _keep = true;
+                #(
+                    #visits;
+                )*
+                _keep
+            }
+        }
+    )
+}
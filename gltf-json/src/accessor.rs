@@ -1,6 +1,6 @@
 use crate::validation::{Checked, USize64};
 use crate::{buffer, extensions, Extras, Index};
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde::{de, ser};
 use serde_derive::{Deserialize, Serialize};
 use serde_json::Value;
@@ -83,7 +83,7 @@ pub mod sparse {
     use crate::extensions;
 
     /// Indices of those attributes that deviate from their initialization value.
-    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
     pub struct Indices {
         /// The parent buffer view containing the sparse indices.
         ///
@@ -112,7 +112,7 @@ pub mod sparse {
     }
 
     /// Sparse storage of attributes that deviate from their initialization value.
-    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
     pub struct Sparse {
         /// The number of attributes encoded in this sparse accessor.
         pub count: USize64,
@@ -143,7 +143,7 @@ pub mod sparse {
 
     /// Array of size `count * number_of_components` storing the displaced
     /// accessor attributes pointed by `accessor::sparse::Indices`.
-    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
     pub struct Values {
         /// The parent buffer view containing the sparse indices.
         ///
@@ -169,7 +169,7 @@ pub mod sparse {
 }
 
 /// A typed view into a buffer view.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct Accessor {
     /// The parent buffer view this accessor reads from.
     ///
@@ -1,6 +1,7 @@
 use crate::validation::{Checked, Error, Validate};
+use crate::visit::{Visit, Visitor};
 use crate::{accessor, extensions, scene, Extras, Index, Path, Root};
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde::{de, ser};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -9,7 +10,14 @@ use std::fmt;
 pub const VALID_INTERPOLATIONS: &[&str] = &["LINEAR", "STEP", "CUBICSPLINE"];
 
 /// All valid animation property names.
-pub const VALID_PROPERTIES: &[&str] = &["translation", "rotation", "scale", "weights"];
+pub const VALID_PROPERTIES: &[&str] = &[
+    "translation",
+    "rotation",
+    "scale",
+    "weights",
+    #[cfg(feature = "KHR_animation_pointer")]
+    "pointer",
+];
 
 /// Specifies an interpolation algorithm.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
@@ -50,6 +58,10 @@ pub enum Property {
     Scale,
     /// Weights of morph targets.
     MorphTargetWeights,
+    /// Any animatable property, selected by the JSON pointer of the
+    /// `KHR_animation_pointer` extension.
+    #[cfg(feature = "KHR_animation_pointer")]
+    Pointer,
 }
 
 /// A keyframe animation.
@@ -84,7 +96,7 @@ pub struct Animation {
 }
 
 /// Targets an animation's sampler at a node's property.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, Visit)]
 pub struct Channel {
     /// The index of a sampler in this animation used to compute the value for the
     /// target.
@@ -142,6 +154,8 @@ pub struct Target {
     pub extras: Extras,
 
     /// The index of the node to target.
+    ///
+    /// Absent when the property is selected by a `KHR_animation_pointer`.
     #[serde(default = "node_default", skip_serializing_if = "node_is_empty")]
     pub node: Index<scene::Node>,
 
@@ -158,13 +172,35 @@ impl Validate for Target {
     {
         self.extensions
             .validate(root, || path().field("extensions"), report);
+        #[cfg(feature = "KHR_animation_pointer")]
+        if self.path == Checked::Valid(Property::Pointer) {
+            // spec: the node must not be defined when targeting a pointer.
+            if !node_is_empty(&self.node) {
+                report(&|| path().field("node"), Error::Invalid);
+            }
+            if self.pointer().is_none() {
+                let path = || path().field("extensions").field("KHR_animation_pointer");
+                report(&path, Error::Missing);
+            }
+            return;
+        }
         node_validate(&self.node, root, || path().field("node"), report);
         self.path.validate(root, || path().field("path"), report);
     }
 }
 
+impl Target {
+    /// Returns the `KHR_animation_pointer` extension of the target, if any.
+    #[cfg(feature = "KHR_animation_pointer")]
+    pub fn pointer(
+        &self,
+    ) -> Option<&extensions::animation::khr_animation_pointer::AnimationPointer> {
+        self.extensions.as_ref()?.khr_animation_pointer.as_ref()
+    }
+}
+
 /// Defines a keyframe graph but not its target.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct Sampler {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -187,12 +223,47 @@ pub struct Sampler {
     pub output: Index<accessor::Accessor>,
 }
 
+impl Visit for Target {
+    fn visit<V>(&mut self, visitor: &mut V) -> bool
+    where
+        V: Visitor,
+    {
+        let mut keep = self.extensions.visit(visitor);
+        if !node_is_empty(&self.node) {
+            keep &= self.node.visit(visitor);
+        }
+        // The channel is removed along with the object its pointer targets.
+        #[cfg(feature = "KHR_animation_pointer")]
+        if self.path == Checked::Valid(Property::Pointer) {
+            keep &= self.pointer().is_some();
+        }
+        keep
+    }
+}
+
+impl Visit for Animation {
+    fn visit<V>(&mut self, visitor: &mut V) -> bool
+    where
+        V: Visitor,
+    {
+        self.extensions.visit(visitor);
+        self.channels.visit(visitor);
+        // Samplers are never removed since channels refer to them by index.
+        for sampler in &mut self.samplers {
+            sampler.visit(visitor);
+        }
+        true
+    }
+}
+
 impl Validate for Animation {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
     where
         P: Fn() -> Path,
         R: FnMut(&dyn Fn() -> Path, Error),
     {
+        self.extensions
+            .validate(root, || path().field("extensions"), report);
         self.samplers
             .validate(root, || path().field("samplers"), report);
         for (index, channel) in self.channels.iter().enumerate() {
@@ -200,6 +271,52 @@ impl Validate for Animation {
                 let path = || path().field("channels").index(index).field("sampler");
                 report(&path, Error::IndexOutOfBounds);
             }
+            // Only targets selected by a pointer are validated here, since
+            // the validation of node targets predates this extension.
+            #[cfg(feature = "KHR_animation_pointer")]
+            if channel.target.path == Checked::Valid(Property::Pointer) {
+                let path = || path().field("channels").index(index).field("target");
+                channel.target.validate(root, path, report);
+                if let Some(animation_pointer) = channel.target.pointer() {
+                    pointer_output_validate(animation_pointer, channel, self, root, path, report);
+                }
+            }
+        }
+    }
+}
+
+/// Checks that the output accessor of the channel sampler provides values of
+/// the type of the property targeted by the pointer.
+#[cfg(feature = "KHR_animation_pointer")]
+fn pointer_output_validate<P, R>(
+    animation_pointer: &extensions::animation::khr_animation_pointer::AnimationPointer,
+    channel: &Channel,
+    animation: &Animation,
+    root: &Root,
+    path: P,
+    report: &mut R,
+) where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, Error),
+{
+    use extensions::animation::khr_animation_pointer::is_valid_output;
+    let resolved = match animation_pointer.resolve() {
+        Some(resolved) => resolved,
+        None => return,
+    };
+    let output = animation
+        .samplers
+        .get(channel.sampler.value())
+        .and_then(|sampler| root.get(sampler.output));
+    if let Some(output) = output {
+        if !is_valid_output(resolved.value_type, output) {
+            let path = || {
+                path()
+                    .field("extensions")
+                    .field("KHR_animation_pointer")
+                    .field("pointer")
+            };
+            report(&path, Error::Invalid);
         }
     }
 }
@@ -278,6 +395,8 @@ impl<'de> de::Deserialize<'de> for Checked<Property> {
                     "rotation" => Valid(Rotation),
                     "scale" => Valid(Scale),
                     "weights" => Valid(MorphTargetWeights),
+                    #[cfg(feature = "KHR_animation_pointer")]
+                    "pointer" => Valid(Pointer),
                     _ => Invalid,
                 })
             }
@@ -296,6 +415,8 @@ impl ser::Serialize for Property {
             Property::Rotation => "rotation",
             Property::Scale => "scale",
             Property::MorphTargetWeights => "weights",
+            #[cfg(feature = "KHR_animation_pointer")]
+            Property::Pointer => "pointer",
         })
     }
 }
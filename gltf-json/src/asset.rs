@@ -1,9 +1,9 @@
 use crate::{extensions, Extras};
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 
 /// Metadata about the glTF asset.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct Asset {
     /// A copyright message suitable for display to credit the content creator.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1,6 +1,7 @@
 use crate::validation::{Checked, Error, USize64, Validate};
+use crate::visit::Visit;
 use crate::{extensions, Extras, Index, Path, Root};
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde::{de, ser};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -46,6 +47,8 @@ impl ser::Serialize for Target {
 #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Stride(pub usize);
 
+impl Visit for Stride {}
+
 impl Validate for Stride {
     fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
     where
@@ -59,7 +62,7 @@ impl Validate for Stride {
 }
 
 /// A buffer points to binary data representing geometry, animations, or skins.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct Buffer {
     /// The length of the buffer in bytes.
     #[serde(default, rename = "byteLength")]
@@ -90,7 +93,7 @@ pub struct Buffer {
 ///
 /// <https://github.com/KhronosGroup/glTF/tree/master/specification/2.0#reference-bufferview>
 ///
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct View {
     /// The parent `Buffer`.
     pub buffer: Index<Buffer>,
@@ -1,4 +1,4 @@
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "extensions")]
 use serde_json::{Map, Value};
@@ -8,21 +8,21 @@ pub mod sparse {
     use super::*;
 
     /// Indices of those attributes that deviate from their initialization value.
-    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
     pub struct Indices {}
 
     /// Sparse storage of attributes that deviate from their initialization value.
-    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
     pub struct Sparse {}
 
     /// Array of size `count * number_of_components` storing the displaced
     /// accessor attributes pointed by `accessor::sparse::Indices`.
-    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
     pub struct Values {}
 }
 
 /// A typed view into a buffer view.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Accessor {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -1,24 +1,452 @@
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "extensions")]
 use serde_json::{Map, Value};
 
 /// A keyframe animation.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Animation {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
 }
 
 /// Targets an animation's sampler at a node's property.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Channel {}
 
 /// The index of the node and TRS property that an animation channel targets.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Target {}
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
+pub struct Target {
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[serde(
+        default,
+        rename = "KHR_animation_pointer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_animation_pointer: Option<khr_animation_pointer::AnimationPointer>,
+}
 
 /// Defines a keyframe graph but not its target.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Sampler {}
+
+#[cfg(feature = "KHR_animation_pointer")]
+pub mod khr_animation_pointer {
+    use crate::accessor::{self, Accessor};
+    use crate::validation::{Checked, Error};
+    use crate::visit::{Visit, Visitor};
+    use crate::{camera, material, mesh, scene, Extras, Index, Path, Root};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// Targets an animation channel at any animatable property of the asset
+    /// through a JSON pointer.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[gltf(validate_hook = "animation_pointer_validate_hook")]
+    pub struct AnimationPointer {
+        /// The JSON pointer to the animated property, for example
+        /// `/materials/0/pbrMetallicRoughness/baseColorFactor`.
+        pub pointer: String,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// The type of the values of an animatable property.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum ValueType {
+        /// A single `float`.
+        Float,
+
+        /// A `float2` vector.
+        Float2,
+
+        /// A `float3` vector or color.
+        Float3,
+
+        /// A `float4` vector, color or quaternion.
+        Float4,
+
+        /// A `float[]` array, such as morph target weights.
+        FloatArray,
+    }
+
+    /// An object whose properties may be animated.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Object {
+        /// A camera, in `/cameras`.
+        Camera(Index<camera::Camera>),
+
+        /// A punctual light, in `/extensions/KHR_lights_punctual/lights`.
+        #[cfg(feature = "KHR_lights_punctual")]
+        Light(Index<super::super::scene::khr_lights_punctual::Light>),
+
+        /// A material, in `/materials`.
+        Material(Index<material::Material>),
+
+        /// A mesh, in `/meshes`.
+        Mesh(Index<mesh::Mesh>),
+
+        /// A node, in `/nodes`.
+        Node(Index<scene::Node>),
+    }
+
+    /// A JSON pointer resolved to an animatable property of an object.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct Resolved<'a> {
+        /// The object containing the property.
+        pub object: Object,
+
+        /// The pointer to the property relative to the object, for example
+        /// `pbrMetallicRoughness/baseColorFactor`.
+        pub property: &'a str,
+
+        /// The type of the values of the property.
+        pub value_type: ValueType,
+    }
+
+    /// Animatable node properties.
+    const NODE_PROPERTIES: &[(&str, ValueType)] = &[
+        ("translation", ValueType::Float3),
+        ("rotation", ValueType::Float4),
+        ("scale", ValueType::Float3),
+        ("weights", ValueType::FloatArray),
+    ];
+
+    /// Animatable mesh properties.
+    const MESH_PROPERTIES: &[(&str, ValueType)] = &[("weights", ValueType::FloatArray)];
+
+    /// Animatable camera properties.
+    const CAMERA_PROPERTIES: &[(&str, ValueType)] = &[
+        ("perspective/aspectRatio", ValueType::Float),
+        ("perspective/yfov", ValueType::Float),
+        ("perspective/zfar", ValueType::Float),
+        ("perspective/znear", ValueType::Float),
+        ("orthographic/xmag", ValueType::Float),
+        ("orthographic/ymag", ValueType::Float),
+        ("orthographic/zfar", ValueType::Float),
+        ("orthographic/znear", ValueType::Float),
+    ];
+
+    /// Animatable material properties, excluding texture transforms.
+    const MATERIAL_PROPERTIES: &[(&str, ValueType)] = &[
+        ("alphaCutoff", ValueType::Float),
+        ("emissiveFactor", ValueType::Float3),
+        ("normalTexture/scale", ValueType::Float),
+        ("occlusionTexture/strength", ValueType::Float),
+        ("pbrMetallicRoughness/baseColorFactor", ValueType::Float4),
+        ("pbrMetallicRoughness/metallicFactor", ValueType::Float),
+        ("pbrMetallicRoughness/roughnessFactor", ValueType::Float),
+        (
+            "extensions/KHR_materials_anisotropy/anisotropyStrength",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_anisotropy/anisotropyRotation",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_clearcoat/clearcoatFactor",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_clearcoat/clearcoatRoughnessFactor",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_clearcoat/clearcoatNormalTexture/scale",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_diffuse_transmission/diffuseTransmissionFactor",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_diffuse_transmission/diffuseTransmissionColorFactor",
+            ValueType::Float3,
+        ),
+        (
+            "extensions/KHR_materials_dispersion/dispersion",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_emissive_strength/emissiveStrength",
+            ValueType::Float,
+        ),
+        ("extensions/KHR_materials_ior/ior", ValueType::Float),
+        (
+            "extensions/KHR_materials_iridescence/iridescenceFactor",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_iridescence/iridescenceIor",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_iridescence/iridescenceThicknessMinimum",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_iridescence/iridescenceThicknessMaximum",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_sheen/sheenColorFactor",
+            ValueType::Float3,
+        ),
+        (
+            "extensions/KHR_materials_sheen/sheenRoughnessFactor",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_specular/specularFactor",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_specular/specularColorFactor",
+            ValueType::Float3,
+        ),
+        (
+            "extensions/KHR_materials_transmission/transmissionFactor",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_volume/thicknessFactor",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_volume/attenuationDistance",
+            ValueType::Float,
+        ),
+        (
+            "extensions/KHR_materials_volume/attenuationColor",
+            ValueType::Float3,
+        ),
+    ];
+
+    /// Animatable texture transform properties of any texture of a material.
+    const TEXTURE_TRANSFORM_PROPERTIES: &[(&str, ValueType)] = &[
+        ("offset", ValueType::Float2),
+        ("rotation", ValueType::Float),
+        ("scale", ValueType::Float2),
+    ];
+
+    /// Animatable punctual light properties.
+    #[cfg(feature = "KHR_lights_punctual")]
+    const LIGHT_PROPERTIES: &[(&str, ValueType)] = &[
+        ("color", ValueType::Float3),
+        ("intensity", ValueType::Float),
+        ("range", ValueType::Float),
+        ("spot/innerConeAngle", ValueType::Float),
+        ("spot/outerConeAngle", ValueType::Float),
+    ];
+
+    /// The pointer prefix of punctual lights.
+    #[cfg(feature = "KHR_lights_punctual")]
+    const LIGHTS: &str = "/extensions/KHR_lights_punctual/lights/";
+
+    impl ValueType {
+        /// Returns the accessor type of the keyframe output values.
+        pub fn accessor_type(&self) -> accessor::Type {
+            match self {
+                ValueType::Float | ValueType::FloatArray => accessor::Type::Scalar,
+                ValueType::Float2 => accessor::Type::Vec2,
+                ValueType::Float3 => accessor::Type::Vec3,
+                ValueType::Float4 => accessor::Type::Vec4,
+            }
+        }
+    }
+
+    impl Object {
+        /// Returns the JSON pointer to the object.
+        pub fn pointer(&self) -> String {
+            match self {
+                Object::Camera(index) => format!("/cameras/{}", index),
+                #[cfg(feature = "KHR_lights_punctual")]
+                Object::Light(index) => format!("{}{}", LIGHTS, index),
+                Object::Material(index) => format!("/materials/{}", index),
+                Object::Mesh(index) => format!("/meshes/{}", index),
+                Object::Node(index) => format!("/nodes/{}", index),
+            }
+        }
+
+        /// Returns `true` if the object exists in `root`.
+        pub fn exists(&self, root: &Root) -> bool {
+            match *self {
+                Object::Camera(index) => root.get(index).is_some(),
+                #[cfg(feature = "KHR_lights_punctual")]
+                Object::Light(index) => root.get(index).is_some(),
+                Object::Material(index) => root.get(index).is_some(),
+                Object::Mesh(index) => root.get(index).is_some(),
+                Object::Node(index) => root.get(index).is_some(),
+            }
+        }
+    }
+
+    impl AnimationPointer {
+        /// Resolves the pointer to an animatable property.
+        ///
+        /// Returns `None` if the pointer does not designate an animatable
+        /// property. The existence of the targeted object is not checked.
+        pub fn resolve(&self) -> Option<Resolved<'_>> {
+            resolve(&self.pointer)
+        }
+    }
+
+    /// Resolves a JSON pointer to an animatable property.
+    ///
+    /// Returns `None` if the pointer does not designate an animatable
+    /// property. The existence of the targeted object is not checked.
+    pub fn resolve(pointer: &str) -> Option<Resolved<'_>> {
+        #[cfg(feature = "KHR_lights_punctual")]
+        if let Some(rest) = pointer.strip_prefix(LIGHTS) {
+            let (index, property) = split_index(rest)?;
+            let value_type = lookup(LIGHT_PROPERTIES, property)?;
+            return Some(Resolved {
+                object: Object::Light(Index::new(index)),
+                property,
+                value_type,
+            });
+        }
+
+        let (collection, rest) = pointer.strip_prefix('/')?.split_once('/')?;
+        let (index, property) = split_index(rest)?;
+        let (object, value_type) = match collection {
+            "cameras" => (
+                Object::Camera(Index::new(index)),
+                lookup(CAMERA_PROPERTIES, property)?,
+            ),
+            "materials" => (
+                Object::Material(Index::new(index)),
+                lookup_material(property)?,
+            ),
+            "meshes" => (
+                Object::Mesh(Index::new(index)),
+                lookup(MESH_PROPERTIES, property)?,
+            ),
+            "nodes" => (
+                Object::Node(Index::new(index)),
+                lookup(NODE_PROPERTIES, property)?,
+            ),
+            _ => return None,
+        };
+        Some(Resolved {
+            object,
+            property,
+            value_type,
+        })
+    }
+
+    /// Splits `"{index}/{property}"`.
+    fn split_index(rest: &str) -> Option<(u32, &str)> {
+        let (index, property) = rest.split_once('/')?;
+        // JSON pointers do not permit leading zeros in array indices.
+        if index.is_empty() || (index.len() > 1 && index.starts_with('0')) {
+            return None;
+        }
+        if !index.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Some((index.parse().ok()?, property))
+    }
+
+    fn lookup(properties: &[(&str, ValueType)], property: &str) -> Option<ValueType> {
+        properties
+            .iter()
+            .find(|(name, _)| *name == property)
+            .map(|(_, value_type)| *value_type)
+    }
+
+    fn lookup_material(property: &str) -> Option<ValueType> {
+        if let Some(value_type) = lookup(MATERIAL_PROPERTIES, property) {
+            return Some(value_type);
+        }
+        let (texture, transform) = property.split_once("/extensions/KHR_texture_transform/")?;
+        // Texture transforms apply to any texture info of the material.
+        let name = texture.rsplit('/').next()?;
+        if !name.ends_with("Texture") {
+            return None;
+        }
+        lookup(TEXTURE_TRANSFORM_PROPERTIES, transform)
+    }
+
+    fn animation_pointer_validate_hook<P, R>(
+        animation_pointer: &AnimationPointer,
+        root: &Root,
+        path: P,
+        report: &mut R,
+    ) where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        match animation_pointer.resolve() {
+            Some(resolved) if !resolved.object.exists(root) => {
+                report(&|| path().field("pointer"), Error::IndexOutOfBounds);
+            }
+            Some(_) => {}
+            None => report(&|| path().field("pointer"), Error::Invalid),
+        }
+    }
+
+    /// Returns `true` if `accessor` may provide the keyframe output values of
+    /// a property of the given type.
+    pub(crate) fn is_valid_output(value_type: ValueType, accessor: &Accessor) -> bool {
+        use accessor::ComponentType;
+        match (&accessor.type_, &accessor.component_type) {
+            (Checked::Valid(type_), Checked::Valid(component)) => {
+                *type_ == value_type.accessor_type()
+                    && match component.0 {
+                        ComponentType::F32 => true,
+                        ComponentType::I8
+                        | ComponentType::U8
+                        | ComponentType::I16
+                        | ComponentType::U16 => accessor.normalized,
+                        _ => false,
+                    }
+            }
+            _ => false,
+        }
+    }
+
+    impl Visit for AnimationPointer {
+        fn visit<V>(&mut self, visitor: &mut V) -> bool
+        where
+            V: Visitor,
+        {
+            // The pointer is left untouched when it cannot be resolved.
+            let resolved = match self.resolve() {
+                Some(resolved) => resolved,
+                None => return true,
+            };
+            let mut object = resolved.object;
+            let keep = match &mut object {
+                Object::Camera(index) => index.visit(visitor),
+                #[cfg(feature = "KHR_lights_punctual")]
+                Object::Light(index) => index.visit(visitor),
+                Object::Material(index) => index.visit(visitor),
+                Object::Mesh(index) => index.visit(visitor),
+                Object::Node(index) => index.visit(visitor),
+            };
+            if keep && object != resolved.object {
+                self.pointer = format!("{}/{}", object.pointer(), resolved.property);
+            }
+            keep
+        }
+    }
+}
@@ -1,6 +1,14 @@
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 
 /// Metadata about the glTF asset.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Asset {}
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
+pub struct Asset {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+}
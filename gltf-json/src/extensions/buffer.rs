@@ -1,20 +1,263 @@
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "extensions")]
 use serde_json::{Map, Value};
 
 /// A buffer points to binary data representing geometry, animations, or skins.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Buffer {
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[serde(
+        default,
+        rename = "EXT_meshopt_compression",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_meshopt_compression: Option<ext_meshopt_compression::Buffer>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
 }
 
 /// A view into a buffer generally representing a subset of the buffer.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct View {
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[serde(
+        default,
+        rename = "EXT_meshopt_compression",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_meshopt_compression: Option<ext_meshopt_compression::View>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
 }
+
+#[cfg(feature = "EXT_meshopt_compression")]
+pub mod ext_meshopt_compression {
+    use crate::validation::{Checked, Error, USize64};
+    use crate::{Extras, Index, Path, Root};
+    use gltf_derive::{Validate, Visit};
+    use serde::{de, ser};
+    use serde_derive::{Deserialize, Serialize};
+    use std::fmt;
+
+    /// All valid compression modes.
+    pub const VALID_MODES: &[&str] = &["ATTRIBUTES", "TRIANGLES", "INDICES"];
+
+    /// All valid filters.
+    pub const VALID_FILTERS: &[&str] = &["NONE", "OCTAHEDRAL", "QUATERNION", "EXPONENTIAL"];
+
+    /// Marks a buffer whose data is only required by loaders that do not
+    /// support the extension.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
+    pub struct Buffer {
+        /// When `true`, the buffer is a placeholder for the decompressed data
+        /// and may have no `uri`.
+        #[serde(default)]
+        pub fallback: bool,
+    }
+
+    /// Specifies how the compressed data is encoded.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Mode {
+        /// Vertex attribute data.
+        Attributes = 1,
+
+        /// Triangle list indices.
+        Triangles,
+
+        /// Index sequences of any other kind.
+        Indices,
+    }
+
+    /// Specifies the filter applied to the decompressed data.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Filter {
+        /// The data is used as it is.
+        None = 1,
+
+        /// Unit vectors stored as octahedral coordinates.
+        Octahedral,
+
+        /// Unit quaternions stored as three components and the index of the
+        /// largest component.
+        Quaternion,
+
+        /// Floating point values stored with a shared exponent and a 24 bit
+        /// mantissa.
+        Exponential,
+    }
+
+    /// Compressed data of a buffer view.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
+    #[gltf(validate_hook = "view_validate_hook")]
+    #[serde(rename_all = "camelCase")]
+    pub struct View {
+        /// The buffer holding the compressed data.
+        pub buffer: Index<crate::Buffer>,
+
+        /// Offset of the compressed data in the buffer in bytes.
+        #[serde(default)]
+        pub byte_offset: USize64,
+
+        /// The length of the compressed data in bytes.
+        pub byte_length: USize64,
+
+        /// The stride in bytes of the decompressed elements.
+        pub byte_stride: USize64,
+
+        /// The number of decompressed elements.
+        pub count: USize64,
+
+        /// The compression mode.
+        pub mode: Checked<Mode>,
+
+        /// The filter applied to the decompressed data.
+        #[serde(default, skip_serializing_if = "is_default_filter")]
+        pub filter: Checked<Filter>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    fn is_default_filter(filter: &Checked<Filter>) -> bool {
+        *filter == Checked::Valid(Filter::None)
+    }
+
+    fn view_validate_hook<P, R>(view: &View, _root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        let stride = view.byte_stride.0;
+        let mode = match view.mode {
+            Checked::Valid(mode) => mode,
+            Checked::Invalid => return,
+        };
+        let stride_is_valid = match mode {
+            Mode::Attributes => stride > 0 && stride <= 256 && stride % 4 == 0,
+            Mode::Triangles | Mode::Indices => stride == 2 || stride == 4,
+        };
+        if !stride_is_valid {
+            report(&|| path().field("byteStride"), Error::Invalid);
+        }
+        if mode == Mode::Triangles && view.count.0 % 3 != 0 {
+            report(&|| path().field("count"), Error::Invalid);
+        }
+        let filter_is_valid = match view.filter {
+            Checked::Valid(Filter::None) | Checked::Invalid => true,
+            Checked::Valid(_) if mode != Mode::Attributes => false,
+            Checked::Valid(Filter::Octahedral) => stride == 4 || stride == 8,
+            Checked::Valid(Filter::Quaternion) => stride == 8,
+            Checked::Valid(Filter::Exponential) => stride % 4 == 0,
+        };
+        if !filter_is_valid {
+            report(&|| path().field("filter"), Error::Invalid);
+        }
+    }
+
+    impl Default for Filter {
+        fn default() -> Self {
+            Filter::None
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Mode> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Mode>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_MODES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    use self::Mode::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "ATTRIBUTES" => Valid(Attributes),
+                        "TRIANGLES" => Valid(Triangles),
+                        "INDICES" => Valid(Indices),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Mode {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_str(match *self {
+                Mode::Attributes => "ATTRIBUTES",
+                Mode::Triangles => "TRIANGLES",
+                Mode::Indices => "INDICES",
+            })
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Filter> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Filter>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_FILTERS)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "NONE" => Valid(Filter::None),
+                        "OCTAHEDRAL" => Valid(Filter::Octahedral),
+                        "QUATERNION" => Valid(Filter::Quaternion),
+                        "EXPONENTIAL" => Valid(Filter::Exponential),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Filter {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_str(match *self {
+                Filter::None => "NONE",
+                Filter::Octahedral => "OCTAHEDRAL",
+                Filter::Quaternion => "QUATERNION",
+                Filter::Exponential => "EXPONENTIAL",
+            })
+        }
+    }
+}
@@ -1,11 +1,19 @@
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "extensions")]
 use serde_json::{Map, Value};
 
 /// Image data used to create a texture.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Image {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
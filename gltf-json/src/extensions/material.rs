@@ -1,12 +1,12 @@
 #[allow(unused_imports)] // different features use different imports
-use crate::{material::StrengthFactor, texture, validation::Validate, Extras};
-use gltf_derive::Validate;
+use crate::{material::StrengthFactor, texture, validation::Validate, visit::Visit, Extras};
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "extensions")]
 use serde_json::{Map, Value};
 
 /// The material appearance of a primitive.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Material {
     #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
     #[serde(
@@ -32,6 +32,14 @@ pub struct Material {
     )]
     pub transmission: Option<Transmission>,
 
+    #[cfg(feature = "KHR_materials_diffuse_transmission")]
+    #[serde(
+        default,
+        rename = "KHR_materials_diffuse_transmission",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub diffuse_transmission: Option<DiffuseTransmission>,
+
     #[cfg(feature = "KHR_materials_volume")]
     #[serde(
         default,
@@ -40,6 +48,14 @@ pub struct Material {
     )]
     pub volume: Option<Volume>,
 
+    #[cfg(feature = "KHR_materials_dispersion")]
+    #[serde(
+        default,
+        rename = "KHR_materials_dispersion",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dispersion: Option<Dispersion>,
+
     #[cfg(feature = "KHR_materials_specular")]
     #[serde(
         default,
@@ -80,6 +96,30 @@ pub struct Material {
     )]
     pub sheen: Option<Sheen>,
 
+    #[cfg(feature = "KHR_materials_iridescence")]
+    #[serde(
+        default,
+        rename = "KHR_materials_iridescence",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub iridescence: Option<Iridescence>,
+
+    #[cfg(feature = "KHR_materials_anisotropy")]
+    #[serde(
+        default,
+        rename = "KHR_materials_anisotropy",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub anisotropy: Option<Anisotropy>,
+
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
@@ -87,7 +127,7 @@ pub struct Material {
 
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct PbrMetallicRoughness {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -101,7 +141,7 @@ pub struct PbrMetallicRoughness {
 /// increased memory use. When both are available, specular-glossiness should be
 /// preferred.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default, rename_all = "camelCase")]
 pub struct PbrSpecularGlossiness {
     /// The material's diffuse factor.
@@ -151,7 +191,7 @@ pub struct PbrSpecularGlossiness {
 }
 
 /// Defines the normal texture of a material.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct NormalTexture {
     #[cfg(feature = "KHR_texture_transform")]
     #[serde(
@@ -166,7 +206,7 @@ pub struct NormalTexture {
 }
 
 /// Defines the occlusion texture of a material.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct OcclusionTexture {
     #[cfg(feature = "KHR_texture_transform")]
     #[serde(
@@ -195,6 +235,9 @@ impl Default for PbrDiffuseFactor {
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 impl Validate for PbrDiffuseFactor {}
 
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+impl Visit for PbrDiffuseFactor {}
+
 /// The specular factor of a material.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -210,9 +253,12 @@ impl Default for PbrSpecularFactor {
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 impl Validate for PbrSpecularFactor {}
 
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+impl Visit for PbrSpecularFactor {}
+
 /// Empty struct that should be present for primitives which should not be shaded with the PBR shading model.
 #[cfg(feature = "KHR_materials_unlit")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Unlit {}
 
 /// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
@@ -231,7 +277,10 @@ impl Default for TransmissionFactor {
 impl Validate for TransmissionFactor {}
 
 #[cfg(feature = "KHR_materials_transmission")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+impl Visit for TransmissionFactor {}
+
+#[cfg(feature = "KHR_materials_transmission")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default, rename_all = "camelCase")]
 pub struct Transmission {
     /// The base percentage of light that is transmitted through the surface.
@@ -257,6 +306,94 @@ pub struct Transmission {
     pub extras: Extras,
 }
 
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DiffuseTransmissionFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+impl Default for DiffuseTransmissionFactor {
+    fn default() -> Self {
+        DiffuseTransmissionFactor(0.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+impl Validate for DiffuseTransmissionFactor {
+    fn validate<P, R>(&self, _: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        validate_range(self.0, 0.0..=1.0, path, report);
+    }
+}
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+impl Visit for DiffuseTransmissionFactor {}
+
+/// An RGB color in linear space with a default value of `[1.0, 1.0, 1.0]`.
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DiffuseTransmissionColorFactor(pub [f32; 3]);
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+impl Default for DiffuseTransmissionColorFactor {
+    fn default() -> Self {
+        DiffuseTransmissionColorFactor([1.0, 1.0, 1.0])
+    }
+}
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+impl Validate for DiffuseTransmissionColorFactor {
+    fn validate<P, R>(&self, _: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        for (i, component) in self.0.iter().enumerate() {
+            validate_range(*component, 0.0..=1.0, || path().index(i), report);
+        }
+    }
+}
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+impl Visit for DiffuseTransmissionColorFactor {}
+
+/// Light transmitted diffusely through the surface, scattering it to the
+/// back side, as seen on thin materials such as leaves or paper.
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DiffuseTransmission {
+    /// The percentage of non-specularly reflected light that is transmitted
+    /// through the surface via the Lambertian diffuse transmission, as seen
+    /// on thin materials such as leaves or paper.
+    pub diffuse_transmission_factor: DiffuseTransmissionFactor,
+
+    /// The diffuse transmission texture.
+    ///
+    /// The A channel of this texture is multiplied by the
+    /// diffuseTransmissionFactor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diffuse_transmission_texture: Option<texture::Info>,
+
+    /// The color that modulates the transmitted light.
+    pub diffuse_transmission_color_factor: DiffuseTransmissionColorFactor,
+
+    /// The diffuse transmission color texture.
+    ///
+    /// The RGB channels of this texture are encoded in sRGB and multiplied
+    /// by the diffuseTransmissionColorFactor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diffuse_transmission_color_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
 /// A positive number with default value of 1.5
 #[cfg(feature = "KHR_materials_ior")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -273,7 +410,10 @@ impl Default for IndexOfRefraction {
 impl Validate for IndexOfRefraction {}
 
 #[cfg(feature = "KHR_materials_ior")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+impl Visit for IndexOfRefraction {}
+
+#[cfg(feature = "KHR_materials_ior")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default, rename_all = "camelCase")]
 pub struct Ior {
     /// The index of refraction.
@@ -305,7 +445,10 @@ impl Default for EmissiveStrengthFactor {
 impl Validate for EmissiveStrengthFactor {}
 
 #[cfg(feature = "KHR_materials_emissive_strength")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+impl Visit for EmissiveStrengthFactor {}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default, rename_all = "camelCase")]
 pub struct EmissiveStrength {
     /// The factor by which to scale the emissive factor or emissive texture.
@@ -327,6 +470,9 @@ impl Default for ThicknessFactor {
 #[cfg(feature = "KHR_materials_volume")]
 impl Validate for ThicknessFactor {}
 
+#[cfg(feature = "KHR_materials_volume")]
+impl Visit for ThicknessFactor {}
+
 /// A number in the inclusive range [0.0, +inf] with a default value of +inf.
 #[cfg(feature = "KHR_materials_volume")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -342,6 +488,9 @@ impl Default for AttenuationDistance {
 #[cfg(feature = "KHR_materials_volume")]
 impl Validate for AttenuationDistance {}
 
+#[cfg(feature = "KHR_materials_volume")]
+impl Visit for AttenuationDistance {}
+
 /// A colour in the inclusive range [[0.0; 3], [1.0; 3]] with a default value of [1.0; 3].
 #[cfg(feature = "KHR_materials_volume")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -358,7 +507,10 @@ impl Default for AttenuationColor {
 impl Validate for AttenuationColor {}
 
 #[cfg(feature = "KHR_materials_volume")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+impl Visit for AttenuationColor {}
+
+#[cfg(feature = "KHR_materials_volume")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default, rename_all = "camelCase")]
 pub struct Volume {
     /// The thickness of the volume beneath the surface. The value is
@@ -388,6 +540,48 @@ pub struct Volume {
     pub extras: Extras,
 }
 
+/// A number in the inclusive range [0.0, +inf] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_dispersion")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DispersionFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_dispersion")]
+impl Default for DispersionFactor {
+    fn default() -> Self {
+        DispersionFactor(0.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_dispersion")]
+impl Validate for DispersionFactor {
+    fn validate<P, R>(&self, _: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        validate_range(self.0, 0.0..=f32::INFINITY, path, report);
+    }
+}
+
+#[cfg(feature = "KHR_materials_dispersion")]
+impl Visit for DispersionFactor {}
+
+#[cfg(feature = "KHR_materials_dispersion")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Dispersion {
+    /// The strength of the dispersion effect, specified as 20/Abbe number.
+    ///
+    /// Dispersion spreads the index of refraction of the volume over the
+    /// wavelengths of light. A value of zero disables it.
+    pub dispersion: DispersionFactor,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
 /// A number in the inclusive range [0.0, +inf] with a default value of 1.0.
 #[cfg(feature = "KHR_materials_specular")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -403,6 +597,9 @@ impl Default for SpecularFactor {
 #[cfg(feature = "KHR_materials_specular")]
 impl Validate for SpecularFactor {}
 
+#[cfg(feature = "KHR_materials_specular")]
+impl Visit for SpecularFactor {}
+
 /// A colour in the inclusive range [[0.0; 3], [1.0; 3]] with a default value of [1.0; 3].
 #[cfg(feature = "KHR_materials_specular")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -419,7 +616,10 @@ impl Default for SpecularColorFactor {
 impl Validate for SpecularColorFactor {}
 
 #[cfg(feature = "KHR_materials_specular")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+impl Visit for SpecularColorFactor {}
+
+#[cfg(feature = "KHR_materials_specular")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default, rename_all = "camelCase")]
 pub struct Specular {
     /// The strength of the specular reflection.
@@ -461,6 +661,9 @@ impl Default for ClearcoatFactor {
 #[cfg(feature = "KHR_materials_clearcoat")]
 impl Validate for ClearcoatFactor {}
 
+#[cfg(feature = "KHR_materials_clearcoat")]
+impl Visit for ClearcoatFactor {}
+
 #[cfg(feature = "KHR_materials_clearcoat")]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 /// Default is `0.0`
@@ -477,7 +680,10 @@ impl Default for ClearcoatRoughnessFactor {
 impl Validate for ClearcoatRoughnessFactor {}
 
 #[cfg(feature = "KHR_materials_clearcoat")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+impl Visit for ClearcoatRoughnessFactor {}
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default, rename_all = "camelCase")]
 pub struct Clearcoat {
     /// The clearcoat layer intensity.
@@ -522,6 +728,9 @@ impl Default for SheenColorFactor {
 #[cfg(feature = "KHR_materials_sheen")]
 impl Validate for SheenColorFactor {}
 
+#[cfg(feature = "KHR_materials_sheen")]
+impl Visit for SheenColorFactor {}
+
 #[cfg(feature = "KHR_materials_sheen")]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 /// Default is `0.0`
@@ -538,7 +747,10 @@ impl Default for SheenRoughnessFactor {
 impl Validate for SheenRoughnessFactor {}
 
 #[cfg(feature = "KHR_materials_sheen")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+impl Visit for SheenRoughnessFactor {}
+
+#[cfg(feature = "KHR_materials_sheen")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default, rename_all = "camelCase")]
 pub struct Sheen {
     /// The sheen color in linear space.
@@ -562,3 +774,222 @@ pub struct Sheen {
     #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
     pub extras: Extras,
 }
+
+/// Reports an error unless `value` lies within `range`.
+#[cfg(any(
+    feature = "KHR_materials_iridescence",
+    feature = "KHR_materials_anisotropy",
+    feature = "KHR_materials_dispersion",
+    feature = "KHR_materials_diffuse_transmission"
+))]
+fn validate_range<P, R>(value: f32, range: std::ops::RangeInclusive<f32>, path: P, report: &mut R)
+where
+    P: Fn() -> crate::Path,
+    R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+{
+    if !range.contains(&value) {
+        report(&path, crate::validation::Error::Invalid);
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Default is `0.0`
+pub struct IridescenceFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Default for IridescenceFactor {
+    fn default() -> Self {
+        Self(0f32)
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceFactor {
+    fn validate<P, R>(&self, _: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        validate_range(self.0, 0.0..=1.0, path, report);
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Visit for IridescenceFactor {}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Default is `1.3`
+pub struct IridescenceIor(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Default for IridescenceIor {
+    fn default() -> Self {
+        Self(1.3)
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceIor {
+    fn validate<P, R>(&self, _: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        validate_range(self.0, 1.0..=f32::INFINITY, path, report);
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Visit for IridescenceIor {}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Default is `100.0`
+pub struct IridescenceThicknessMinimum(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Default for IridescenceThicknessMinimum {
+    fn default() -> Self {
+        Self(100.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceThicknessMinimum {
+    fn validate<P, R>(&self, _: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        validate_range(self.0, 0.0..=f32::INFINITY, path, report);
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Visit for IridescenceThicknessMinimum {}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Default is `400.0`
+pub struct IridescenceThicknessMaximum(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Default for IridescenceThicknessMaximum {
+    fn default() -> Self {
+        Self(400.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceThicknessMaximum {
+    fn validate<P, R>(&self, _: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        validate_range(self.0, 0.0..=f32::INFINITY, path, report);
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Visit for IridescenceThicknessMaximum {}
+
+/// A thin-film interference layer on top of the material, which shifts the
+/// hue of reflections depending on the viewing angle and the film thickness.
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Iridescence {
+    /// The iridescence intensity factor.
+    /// If iridescence_factor is zero, the whole iridescence layer is disabled.
+    pub iridescence_factor: IridescenceFactor,
+
+    /// The iridescence intensity texture.
+    /// The intensity is sampled from the R channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iridescence_texture: Option<texture::Info>,
+
+    /// The index of refraction of the thin-film layer.
+    pub iridescence_ior: IridescenceIor,
+
+    /// The minimum thickness of the thin-film layer in nanometers.
+    pub iridescence_thickness_minimum: IridescenceThicknessMinimum,
+
+    /// The maximum thickness of the thin-film layer in nanometers.
+    pub iridescence_thickness_maximum: IridescenceThicknessMaximum,
+
+    /// The thickness texture of the thin-film layer.
+    /// The G channel interpolates between the minimum and maximum thickness.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iridescence_thickness_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Default is `0.0`
+pub struct AnisotropyStrength(pub f32);
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+impl Default for AnisotropyStrength {
+    fn default() -> Self {
+        Self(0f32)
+    }
+}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+impl Validate for AnisotropyStrength {
+    fn validate<P, R>(&self, _: &crate::Root, path: P, report: &mut R)
+    where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        validate_range(self.0, 0.0..=1.0, path, report);
+    }
+}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+impl Visit for AnisotropyStrength {}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+/// Default is `0.0`
+pub struct AnisotropyRotation(pub f32);
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+impl Validate for AnisotropyRotation {}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+impl Visit for AnisotropyRotation {}
+
+/// Anisotropic specular reflections, which stretch highlights along a
+/// direction in the tangent plane, as seen on brushed metal.
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Anisotropy {
+    /// The anisotropy strength.
+    /// If anisotropy_strength is zero, reflections are isotropic.
+    pub anisotropy_strength: AnisotropyStrength,
+
+    /// The rotation of the anisotropy direction in radians, counter-clockwise
+    /// from the tangent.
+    pub anisotropy_rotation: AnisotropyRotation,
+
+    /// The anisotropy texture.
+    /// The RG channels hold the direction in tangent space and the B channel
+    /// the strength.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anisotropy_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
@@ -1,4 +1,4 @@
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "extensions")]
 use serde_json::{Map, Value};
@@ -7,16 +7,32 @@ use serde_json::{Map, Value};
 ///
 /// A node can contain one or more meshes and its transform places the meshes in
 /// the scene.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Mesh {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
 }
 
 /// Geometry to be rendered with the given material.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Primitive {
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    #[serde(
+        default,
+        rename = "KHR_draco_mesh_compression",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_draco_mesh_compression: Option<khr_draco_mesh_compression::DracoMeshCompression>,
+
     #[cfg(feature = "KHR_materials_variants")]
     #[serde(
         default,
@@ -30,7 +46,7 @@ pub struct Primitive {
 }
 
 #[cfg(feature = "KHR_materials_variants")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct KhrMaterialsVariants {
     pub mappings: Vec<Mapping>,
 }
@@ -41,3 +57,61 @@ pub struct Mapping {
     pub material: u32,
     pub variants: Vec<u32>,
 }
+
+#[cfg(feature = "KHR_materials_variants")]
+impl crate::visit::Visit for Mapping {
+    fn visit<V>(&mut self, visitor: &mut V) -> bool
+    where
+        V: crate::visit::Visitor,
+    {
+        use crate::extensions::scene::khr_materials_variants::Variant;
+        use crate::{Index, Material};
+
+        // Indices are stored as plain integers here.
+        self.variants.retain_mut(|variant| {
+            let mut index = Index::<Variant>::new(*variant);
+            let keep = visitor.visit_index(&mut index);
+            *variant = index.value() as u32;
+            keep
+        });
+        let mut material = Index::<Material>::new(self.material);
+        let keep = visitor.visit_index(&mut material);
+        self.material = material.value() as u32;
+        keep && !self.variants.is_empty()
+    }
+}
+
+#[cfg(feature = "KHR_draco_mesh_compression")]
+pub mod khr_draco_mesh_compression {
+    use crate::mesh::Semantic;
+    use crate::validation::Checked;
+    use crate::{buffer, Extras, Index};
+    use gltf_derive::{Validate, Visit};
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    /// Geometry of a primitive compressed with the Draco library.
+    ///
+    /// The accessors of the primitive describe the decompressed data and
+    /// need not refer to a buffer view.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DracoMeshCompression {
+        /// The buffer view containing the compressed data.
+        pub buffer_view: Index<buffer::View>,
+
+        /// Maps attribute semantic names to the unique ids of the
+        /// corresponding attributes in the compressed data.
+        pub attributes: BTreeMap<Checked<Semantic>, u32>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+}
@@ -48,6 +48,10 @@ pub const ENABLED_EXTENSIONS: &[&str] = &[
     "KHR_texture_transform",
     #[cfg(feature = "KHR_materials_transmission")]
     "KHR_materials_transmission",
+    #[cfg(feature = "KHR_materials_diffuse_transmission")]
+    "KHR_materials_diffuse_transmission",
+    #[cfg(feature = "KHR_materials_dispersion")]
+    "KHR_materials_dispersion",
     #[cfg(feature = "KHR_materials_ior")]
     "KHR_materials_ior",
     #[cfg(feature = "KHR_materials_emissive_strength")]
@@ -56,12 +60,30 @@ pub const ENABLED_EXTENSIONS: &[&str] = &[
     "KHR_materials_clearcoat",
     #[cfg(feature = "KHR_materials_sheen")]
     "KHR_materials_sheen",
+    #[cfg(feature = "KHR_materials_iridescence")]
+    "KHR_materials_iridescence",
+    #[cfg(feature = "KHR_materials_anisotropy")]
+    "KHR_materials_anisotropy",
+    #[cfg(feature = "KHR_mesh_quantization")]
+    "KHR_mesh_quantization",
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    "KHR_draco_mesh_compression",
+    #[cfg(feature = "EXT_meshopt_compression")]
+    "EXT_meshopt_compression",
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    "EXT_mesh_gpu_instancing",
+    #[cfg(feature = "KHR_animation_pointer")]
+    "KHR_animation_pointer",
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    "KHR_xmp_json_ld",
+    #[cfg(feature = "EXT_lights_image_based")]
+    "EXT_lights_image_based",
     // Allowlisted texture extensions. Processing is delegated to the user.
-    #[cfg(feature = "allow_empty_texture")]
+    #[cfg(any(feature = "allow_empty_texture", feature = "KHR_texture_basisu"))]
     "KHR_texture_basisu",
     #[cfg(feature = "EXT_texture_webp")]
     "EXT_texture_webp",
-    #[cfg(feature = "allow_empty_texture")]
+    #[cfg(any(feature = "allow_empty_texture", feature = "MSFT_texture_dds"))]
     "MSFT_texture_dds",
 ];
 
@@ -72,9 +94,22 @@ pub const SUPPORTED_EXTENSIONS: &[&str] = &[
     "KHR_materials_unlit",
     "KHR_texture_transform",
     "KHR_materials_transmission",
+    "KHR_materials_diffuse_transmission",
+    "KHR_materials_dispersion",
     "KHR_materials_ior",
     "KHR_materials_emissive_strength",
     "KHR_materials_clearcoat",
     "KHR_materials_sheen",
+    "KHR_materials_iridescence",
+    "KHR_materials_anisotropy",
+    "KHR_mesh_quantization",
+    "KHR_draco_mesh_compression",
+    "EXT_meshopt_compression",
+    "EXT_mesh_gpu_instancing",
+    "KHR_animation_pointer",
+    "KHR_xmp_json_ld",
+    "EXT_lights_image_based",
+    "KHR_texture_basisu",
     "EXT_texture_webp",
+    "MSFT_texture_dds",
 ];
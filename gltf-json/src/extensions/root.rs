@@ -1,11 +1,19 @@
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "extensions")]
 use serde_json::{Map, Value};
 
 /// The root object of a glTF 2.0 asset.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Root {
+    #[cfg(feature = "EXT_lights_image_based")]
+    #[serde(
+        default,
+        rename = "EXT_lights_image_based",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_lights_image_based: Option<ExtLightsImageBased>,
+
     #[cfg(feature = "KHR_lights_punctual")]
     #[serde(
         default,
@@ -22,13 +30,67 @@ pub struct Root {
     )]
     pub khr_materials_variants: Option<KhrMaterialsVariants>,
 
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<XmpPackets>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
 }
 
+/// The image-based lights of the `EXT_lights_image_based` extension, which
+/// scenes reference by index.
+#[cfg(feature = "EXT_lights_image_based")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
+pub struct ExtLightsImageBased {
+    /// Image-based lights referenced by the scenes.
+    pub lights: Vec<crate::extensions::scene::ext_lights_image_based::Light>,
+}
+
+#[cfg(feature = "EXT_lights_image_based")]
+impl crate::root::Get<crate::extensions::scene::ext_lights_image_based::Light> for crate::Root {
+    fn get(
+        &self,
+        id: crate::Index<crate::extensions::scene::ext_lights_image_based::Light>,
+    ) -> Option<&crate::extensions::scene::ext_lights_image_based::Light> {
+        self.extensions
+            .as_ref()?
+            .ext_lights_image_based
+            .as_ref()?
+            .lights
+            .get(id.value())
+    }
+}
+
+#[cfg(feature = "EXT_lights_image_based")]
+impl AsRef<[crate::extensions::scene::ext_lights_image_based::Light]> for crate::Root {
+    fn as_ref(&self) -> &[crate::extensions::scene::ext_lights_image_based::Light] {
+        self.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.ext_lights_image_based.as_ref())
+            .map(|ext_lights_image_based| ext_lights_image_based.lights.as_slice())
+            .unwrap_or(&[])
+    }
+}
+#[cfg(feature = "EXT_lights_image_based")]
+impl AsMut<Vec<crate::extensions::scene::ext_lights_image_based::Light>> for crate::Root {
+    fn as_mut(&mut self) -> &mut Vec<crate::extensions::scene::ext_lights_image_based::Light> {
+        &mut self
+            .extensions
+            .get_or_insert_with(Default::default)
+            .ext_lights_image_based
+            .get_or_insert_with(Default::default)
+            .lights
+    }
+}
+
 #[cfg(feature = "KHR_lights_punctual")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct KhrLightsPunctual {
     /// Lights at this node.
     pub lights: Vec<crate::extensions::scene::khr_lights_punctual::Light>,
@@ -75,7 +137,7 @@ impl AsMut<Vec<crate::extensions::scene::khr_lights_punctual::Light>> for crate:
 }
 
 #[cfg(feature = "KHR_materials_variants")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct KhrMaterialsVariants {
     pub variants: Vec<crate::extensions::scene::khr_materials_variants::Variant>,
 }
@@ -116,3 +178,93 @@ impl AsMut<Vec<crate::extensions::scene::khr_materials_variants::Variant>> for c
             .variants
     }
 }
+
+/// The metadata packets of the `KHR_xmp_json_ld` extension, which objects of
+/// the asset reference by index.
+#[cfg(feature = "KHR_xmp_json_ld")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
+pub struct XmpPackets {
+    /// Metadata packets referenced by the objects of the asset.
+    pub packets: Vec<khr_xmp_json_ld::Packet>,
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+impl crate::root::Get<khr_xmp_json_ld::Packet> for crate::Root {
+    fn get(&self, id: crate::Index<khr_xmp_json_ld::Packet>) -> Option<&khr_xmp_json_ld::Packet> {
+        self.extensions
+            .as_ref()?
+            .khr_xmp_json_ld
+            .as_ref()?
+            .packets
+            .get(id.value())
+    }
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+impl AsRef<[khr_xmp_json_ld::Packet]> for crate::Root {
+    fn as_ref(&self) -> &[khr_xmp_json_ld::Packet] {
+        self.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_xmp_json_ld.as_ref())
+            .map(|khr_xmp_json_ld| khr_xmp_json_ld.packets.as_slice())
+            .unwrap_or(&[])
+    }
+}
+#[cfg(feature = "KHR_xmp_json_ld")]
+impl AsMut<Vec<khr_xmp_json_ld::Packet>> for crate::Root {
+    fn as_mut(&mut self) -> &mut Vec<khr_xmp_json_ld::Packet> {
+        &mut self
+            .extensions
+            .get_or_insert_with(Default::default)
+            .khr_xmp_json_ld
+            .get_or_insert_with(Default::default)
+            .packets
+    }
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+pub mod khr_xmp_json_ld {
+    use crate::validation::{Error, Validate};
+    use crate::visit::Visit;
+    use crate::{Index, Path, Root};
+    use gltf_derive::{Validate, Visit};
+    use serde_derive::{Deserialize, Serialize};
+    use serde_json::{Map, Value};
+
+    /// A JSON-LD metadata packet following the XMP data model.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct Packet(pub Map<String, Value>);
+
+    /// References the metadata packet of an object.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
+    pub struct KhrXmpJsonLd {
+        /// The index of the packet in the root `KHR_xmp_json_ld` extension.
+        pub packet: Index<Packet>,
+    }
+
+    impl Packet {
+        /// Returns the JSON-LD context, which maps the namespace prefixes of
+        /// the packet to their URIs.
+        pub fn context(&self) -> Option<&Value> {
+            self.0.get("@context")
+        }
+    }
+
+    impl Validate for Packet {
+        fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            // spec: each packet must define its JSON-LD context.
+            match self.context() {
+                Some(Value::Object(_)) => {}
+                Some(_) => report(&|| path().field("@context"), Error::Invalid),
+                None => report(&|| path().field("@context"), Error::Missing),
+            }
+        }
+    }
+
+    impl Visit for Packet {}
+}
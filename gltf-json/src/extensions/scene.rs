@@ -1,4 +1,4 @@
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "extensions")]
 use serde_json::{Map, Value};
@@ -13,8 +13,16 @@ use serde_json::{Map, Value};
 /// identity. When a node is targeted for animation (referenced by an
 /// animation.channel.target), only TRS properties may be present; `matrix` will not
 /// be present.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Node {
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[serde(
+        default,
+        rename = "EXT_mesh_gpu_instancing",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_mesh_gpu_instancing: Option<ext_mesh_gpu_instancing::MeshGpuInstancing>,
+
     #[cfg(feature = "KHR_lights_punctual")]
     #[serde(
         default,
@@ -23,16 +31,234 @@ pub struct Node {
     )]
     pub khr_lights_punctual: Option<khr_lights_punctual::KhrLightsPunctual>,
 
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
 }
 
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+pub mod ext_mesh_gpu_instancing {
+    use crate::accessor::{self, Accessor};
+    use crate::validation::{Checked, Error};
+    use crate::{Extras, Index, Path, Root};
+    use gltf_derive::{Validate, Visit};
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    /// The attribute name of instance translations.
+    pub const TRANSLATION: &str = "TRANSLATION";
+
+    /// The attribute name of instance rotations.
+    pub const ROTATION: &str = "ROTATION";
+
+    /// The attribute name of instance scales.
+    pub const SCALE: &str = "SCALE";
+
+    /// Renders the mesh of a node once for every instance, with the
+    /// transform of each instance given by per-instance attributes.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
+    #[gltf(validate_hook = "mesh_gpu_instancing_validate_hook")]
+    pub struct MeshGpuInstancing {
+        /// Maps attribute names to the accessors containing one element per
+        /// instance. Application specific attributes start with an underscore.
+        pub attributes: BTreeMap<String, Index<Accessor>>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    fn mesh_gpu_instancing_validate_hook<P, R>(
+        instancing: &MeshGpuInstancing,
+        root: &Root,
+        path: P,
+        report: &mut R,
+    ) where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        use accessor::{ComponentType, Type};
+
+        let mut count = None;
+        for (name, index) in &instancing.attributes {
+            let attribute_path = || path().field("attributes").key(name);
+            let accessor = match root.get(*index) {
+                Some(accessor) => accessor,
+                None => continue,
+            };
+            let expected = match name.as_str() {
+                TRANSLATION | SCALE => Some((Type::Vec3, false)),
+                ROTATION => Some((Type::Vec4, true)),
+                name if name.starts_with('_') => None,
+                _ => {
+                    report(&attribute_path, Error::Invalid);
+                    continue;
+                }
+            };
+            if let Some((type_, signed)) = expected {
+                // Integer components are permitted with KHR_mesh_quantization,
+                // and must be signed and normalized for rotations.
+                let valid = match (&accessor.type_, &accessor.component_type) {
+                    (Checked::Valid(t), Checked::Valid(component)) if *t == type_ => {
+                        match component.0 {
+                            ComponentType::F32 => true,
+                            ComponentType::I8 | ComponentType::I16 => {
+                                !signed || accessor.normalized
+                            }
+                            ComponentType::U8 | ComponentType::U16 => !signed,
+                            _ => false,
+                        }
+                    }
+                    _ => false,
+                };
+                if !valid {
+                    report(&attribute_path, Error::Invalid);
+                }
+            }
+            // spec: all attribute accessors must have the same count.
+            match count {
+                None => count = Some(accessor.count),
+                Some(count) if count != accessor.count => {
+                    report(&|| attribute_path().field("count"), Error::Invalid);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(feature = "EXT_lights_image_based")]
+pub mod ext_lights_image_based {
+    use crate::validation::{Error, Validate};
+    use crate::visit::{Visit, Visitor};
+    use crate::{image, Extras, Index, Path, Root};
+    use gltf_derive::{Validate, Visit};
+    use serde_derive::{Deserialize, Serialize};
+
+    /// The number of spherical harmonics coefficients of the irradiance.
+    pub const IRRADIANCE_COEFFICIENTS: usize = 9;
+
+    /// References the image-based light of a scene.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
+    pub struct ExtLightsImageBased {
+        /// The index of the light in the root `EXT_lights_image_based`
+        /// extension.
+        pub light: Index<Light>,
+    }
+
+    /// An environment light defined by a prefiltered specular cubemap and
+    /// spherical harmonics irradiance coefficients.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Light {
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// Quaternion that rotates the environment, in XYZW order.
+        #[serde(default = "rotation_default")]
+        pub rotation: [f32; 4],
+
+        /// Brightness multiplier of the environment.
+        #[serde(default = "intensity_default")]
+        pub intensity: f32,
+
+        /// The nine RGB coefficients of the third order spherical harmonics
+        /// approximation of the irradiance.
+        pub irradiance_coefficients: Vec<[f32; 3]>,
+
+        /// The size in pixels of the first mip level of the specular cubemap.
+        pub specular_image_size: u32,
+
+        /// The images of the specular cubemap, from the first mip level, with
+        /// the six faces of each level in the order +X, -X, +Y, -Y, +Z, -Z.
+        pub specular_images: Vec<[Index<image::Image>; 6]>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    fn rotation_default() -> [f32; 4] {
+        [0.0, 0.0, 0.0, 1.0]
+    }
+
+    fn intensity_default() -> f32 {
+        1.0
+    }
+
+    impl Validate for Light {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            if self.irradiance_coefficients.len() != IRRADIANCE_COEFFICIENTS {
+                report(&|| path().field("irradianceCoefficients"), Error::Invalid);
+            }
+            if self.specular_image_size == 0 {
+                report(&|| path().field("specularImageSize"), Error::Invalid);
+            }
+            // spec: there can be no more mip levels than down to 1x1 pixels.
+            let mip_levels = 32 - self.specular_image_size.leading_zeros() as usize;
+            if self.specular_images.is_empty() || self.specular_images.len() > mip_levels {
+                report(&|| path().field("specularImages"), Error::Invalid);
+            }
+            for (level, faces) in self.specular_images.iter().enumerate() {
+                for (face, index) in faces.iter().enumerate() {
+                    index.validate(
+                        root,
+                        || path().field("specularImages").index(level).index(face),
+                        report,
+                    );
+                }
+            }
+        }
+    }
+
+    impl Visit for Light {
+        fn visit<V>(&mut self, visitor: &mut V) -> bool
+        where
+            V: Visitor,
+        {
+            // The light is incomplete without any of the faces of a level.
+            let mut keep = true;
+            for faces in &mut self.specular_images {
+                for index in faces {
+                    keep &= index.visit(visitor);
+                }
+            }
+            keep
+        }
+    }
+}
+
 #[cfg(feature = "KHR_lights_punctual")]
 pub mod khr_lights_punctual {
     use crate::validation::{Checked, Error};
     use crate::{Extras, Index, Path, Root};
-    use gltf_derive::Validate;
+    use gltf_derive::{Validate, Visit};
     use serde::{de, ser};
     use serde_derive::{Deserialize, Serialize};
     use std::fmt;
@@ -40,7 +266,7 @@ pub mod khr_lights_punctual {
     /// All valid light types.
     pub const VALID_TYPES: &[&str] = &["directional", "point", "spot"];
 
-    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
     pub struct KhrLightsPunctual {
         pub light: Index<Light>,
     }
@@ -76,7 +302,7 @@ pub mod khr_lights_punctual {
         Spot,
     }
 
-    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
     #[gltf(validate_hook = "light_validate_hook")]
     pub struct Light {
         /// Color of the light source.
@@ -138,7 +364,7 @@ pub mod khr_lights_punctual {
     }
 
     /// Spot light parameters.
-    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
     #[serde(rename_all = "camelCase")]
     pub struct Spot {
         /// Angle in radians from centre of spotlight where falloff begins.
@@ -203,9 +429,10 @@ pub mod khr_lights_punctual {
 pub mod khr_materials_variants {
     use crate::validation::{Error, Validate};
     use crate::{Path, Root};
+    use gltf_derive::Visit;
     use serde_derive::{Deserialize, Serialize};
 
-    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[derive(Clone, Debug, Deserialize, Serialize, Visit)]
     pub struct Variant {
         pub name: String,
     }
@@ -222,8 +449,24 @@ pub mod khr_materials_variants {
 }
 
 /// The root `Node`s of a scene.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Scene {
+    #[cfg(feature = "EXT_lights_image_based")]
+    #[serde(
+        default,
+        rename = "EXT_lights_image_based",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_lights_image_based: Option<ext_lights_image_based::ExtLightsImageBased>,
+
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
@@ -1,15 +1,19 @@
 #[cfg(any(feature = "KHR_texture_transform", feature = "EXT_texture_webp"))]
-use crate::{extras::Extras, validation::Validate};
-#[cfg(feature = "EXT_texture_webp")]
+use crate::{extras::Extras, validation::Validate, visit::Visit};
+#[cfg(any(
+    feature = "KHR_texture_basisu",
+    feature = "EXT_texture_webp",
+    feature = "MSFT_texture_dds"
+))]
 use crate::{image, Index};
 
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "extensions")]
 use serde_json::{Map, Value};
 
 /// Texture sampler properties for filtering and wrapping modes.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Sampler {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -17,12 +21,20 @@ pub struct Sampler {
 }
 
 /// A texture and its sampler.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Texture {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
 
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[serde(
+        default,
+        rename = "KHR_texture_basisu",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_basisu: Option<TextureBasisu>,
+
     #[cfg(feature = "EXT_texture_webp")]
     #[serde(
         default,
@@ -30,16 +42,39 @@ pub struct Texture {
         skip_serializing_if = "Option::is_none"
     )]
     pub texture_webp: Option<TextureWebp>,
+
+    #[cfg(feature = "MSFT_texture_dds")]
+    #[serde(
+        default,
+        rename = "MSFT_texture_dds",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_dds: Option<TextureDds>,
+}
+
+#[cfg(feature = "KHR_texture_basisu")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
+pub struct TextureBasisu {
+    /// The index of the KTX2 image with Basis Universal supercompression
+    /// used by the texture.
+    pub source: Index<image::Image>,
 }
 
 #[cfg(feature = "EXT_texture_webp")]
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct TextureWebp {
     /// The index of the webp image used by the texture.
     pub source: Index<image::Image>,
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg(feature = "MSFT_texture_dds")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
+pub struct TextureDds {
+    /// The index of the DDS image used by the texture.
+    pub source: Index<image::Image>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 /// Reference to a `Texture`.
 pub struct Info {
     #[cfg(feature = "KHR_texture_transform")]
@@ -63,7 +98,7 @@ pub struct Info {
 /// To support this use case, this extension adds `offset`, `rotation`, and `scale` properties to textureInfo structures.
 /// These properties would typically be implemented as an affine transform on the UV coordinates.
 #[cfg(feature = "KHR_texture_transform")]
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default, rename_all = "camelCase")]
 pub struct TextureTransform {
     // The offset of the UV coordinate origin as a factor of the texture dimensions.
@@ -100,6 +135,9 @@ impl Default for TextureTransformOffset {
 #[cfg(feature = "KHR_texture_transform")]
 impl Validate for TextureTransformOffset {}
 
+#[cfg(feature = "KHR_texture_transform")]
+impl Visit for TextureTransformOffset {}
+
 /// Rotate the UVs by this many radians counter-clockwise around the origin.
 /// This is equivalent to a similar rotation of the image clockwise.
 #[cfg(feature = "KHR_texture_transform")]
@@ -116,6 +154,9 @@ impl Default for TextureTransformRotation {
 #[cfg(feature = "KHR_texture_transform")]
 impl Validate for TextureTransformRotation {}
 
+#[cfg(feature = "KHR_texture_transform")]
+impl Visit for TextureTransformRotation {}
+
 /// The scale factor applied to the components of the UV coordinates.
 #[cfg(feature = "KHR_texture_transform")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -130,3 +171,6 @@ impl Default for TextureTransformScale {
 
 #[cfg(feature = "KHR_texture_transform")]
 impl Validate for TextureTransformScale {}
+
+#[cfg(feature = "KHR_texture_transform")]
+impl Visit for TextureTransformScale {}
@@ -1,4 +1,4 @@
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 
@@ -14,7 +14,7 @@ pub type Extras = Option<::std::boxed::Box<RawValue>>;
 pub type Extras = Void;
 
 /// Type representing no user-defined data.
-#[derive(Clone, Default, Serialize, Deserialize, Validate)]
+#[derive(Clone, Default, Serialize, Deserialize, Validate, Visit)]
 pub struct Void {
     #[serde(default, skip_serializing)]
     _allow_unknown_fields: (),
@@ -1,6 +1,7 @@
 use crate::validation::Validate;
+use crate::visit::Visit;
 use crate::{buffer, extensions, Extras, Index};
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 
 /// All valid MIME types.
@@ -9,10 +10,16 @@ pub const VALID_MIME_TYPES: &[&str] = &[
     "image/png",
     #[cfg(feature = "EXT_texture_webp")]
     "image/webp",
+    #[cfg(feature = "KHR_texture_basisu")]
+    "image/ktx2",
+    #[cfg(feature = "MSFT_texture_dds")]
+    "image/vnd-ms.dds",
+    #[cfg(feature = "EXT_lights_image_based")]
+    "image/vnd.radiance",
 ];
 
 /// Image data used to create a texture.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct Image {
     /// The index of the buffer view that contains the image. Use this instead of
     /// the image's uri property.
@@ -52,3 +59,5 @@ pub struct Image {
 pub struct MimeType(pub String);
 
 impl Validate for MimeType {}
+
+impl Visit for MimeType {}
@@ -47,6 +47,9 @@ pub mod texture;
 /// Contains functions that validate glTF JSON data against the specification.
 pub mod validation;
 
+/// Contains functions that walk the indices of glTF JSON data.
+pub mod visit;
+
 #[doc(inline)]
 pub use accessor::Accessor;
 #[doc(inline)]
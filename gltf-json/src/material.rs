@@ -1,6 +1,7 @@
 use crate::validation::{Checked, Validate};
+use crate::visit::Visit;
 use crate::{extensions, texture, Extras, Index};
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde::{de, ser};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -37,7 +38,7 @@ impl ser::Serialize for AlphaMode {
 }
 
 /// The material appearance of a primitive.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default)]
 pub struct Material {
     /// The alpha cutoff value of the material.
@@ -130,7 +131,7 @@ pub struct Material {
 
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default)]
 pub struct PbrMetallicRoughness {
     /// The material's base color factor.
@@ -176,7 +177,7 @@ pub struct PbrMetallicRoughness {
 }
 
 /// Defines the normal texture of a material.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct NormalTexture {
     /// The index of the texture.
     pub index: Index<texture::Texture>,
@@ -207,7 +208,7 @@ fn material_normal_texture_scale_default() -> f32 {
 }
 
 /// Defines the occlusion texture of a material.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct OcclusionTexture {
     /// The index of the texture.
     pub index: Index<texture::Texture>,
@@ -255,6 +256,8 @@ impl Default for AlphaCutoff {
 
 impl Validate for AlphaCutoff {}
 
+impl Visit for AlphaCutoff {}
+
 impl Default for AlphaMode {
     fn default() -> Self {
         AlphaMode::Opaque
@@ -294,6 +297,8 @@ impl<'de> de::Deserialize<'de> for Checked<AlphaMode> {
 
 impl Validate for EmissiveFactor {}
 
+impl Visit for EmissiveFactor {}
+
 impl Default for PbrBaseColorFactor {
     fn default() -> Self {
         PbrBaseColorFactor([1.0, 1.0, 1.0, 1.0])
@@ -302,6 +307,8 @@ impl Default for PbrBaseColorFactor {
 
 impl Validate for PbrBaseColorFactor {}
 
+impl Visit for PbrBaseColorFactor {}
+
 impl Default for StrengthFactor {
     fn default() -> Self {
         StrengthFactor(1.0)
@@ -309,3 +316,5 @@ impl Default for StrengthFactor {
 }
 
 impl Validate for StrengthFactor {}
+
+impl Visit for StrengthFactor {}
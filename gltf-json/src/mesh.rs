@@ -1,6 +1,6 @@
 use crate::validation::{Checked, Error};
 use crate::{accessor, extensions, material, Extras, Index};
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde::{de, ser};
 use serde_derive::{Deserialize, Serialize};
 use serde_json::from_value;
@@ -71,7 +71,7 @@ pub enum Mode {
 ///
 /// A node can contain one or more meshes and its transform places the meshes in
 /// the scene.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct Mesh {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -97,7 +97,7 @@ pub struct Mesh {
 }
 
 /// Geometry to be rendered with the given material.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 #[gltf(validate_hook = "primitive_validate_hook")]
 pub struct Primitive {
     /// Maps attribute semantic names to the `Accessor`s containing the
@@ -170,10 +170,100 @@ where
     } else {
         report(position_path, Error::Missing);
     }
+
+    let quantized = cfg!(feature = "KHR_mesh_quantization")
+        && root
+            .extensions_used
+            .iter()
+            .any(|name| name == "KHR_mesh_quantization");
+    for (semantic, index) in &primitive.attributes {
+        if let (Checked::Valid(semantic), Some(accessor)) = (semantic, root.get(*index)) {
+            if !attribute_type_is_allowed(semantic, accessor, false, quantized) {
+                report(
+                    &|| path().field("attributes").key(&semantic.to_string()),
+                    Error::Invalid,
+                );
+            }
+        }
+    }
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    if let Some(draco) = primitive
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.khr_draco_mesh_compression.as_ref())
+    {
+        // spec: every compressed attribute must also be listed by the primitive.
+        for semantic in draco.attributes.keys() {
+            if !primitive.attributes.contains_key(semantic) {
+                report(
+                    &|| {
+                        path()
+                            .field("extensions")
+                            .field("KHR_draco_mesh_compression")
+                            .field("attributes")
+                            .key(&semantic.to_string())
+                    },
+                    Error::Invalid,
+                );
+            }
+        }
+    }
+    for (target_index, target) in primitive.targets.iter().flatten().enumerate() {
+        let attributes = [
+            (Semantic::Positions, target.positions),
+            (Semantic::Normals, target.normals),
+            (Semantic::Tangents, target.tangents),
+        ];
+        for (semantic, index) in attributes {
+            if let Some(accessor) = index.and_then(|index| root.get(index)) {
+                if !attribute_type_is_allowed(&semantic, accessor, true, quantized) {
+                    report(
+                        &|| {
+                            path()
+                                .field("targets")
+                                .index(target_index)
+                                .key(&semantic.to_string())
+                        },
+                        Error::Invalid,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns whether the component type of `accessor` is permitted for vertex
+/// attributes of the given semantic.
+///
+/// `quantized` permits the additional component types introduced by the
+/// `KHR_mesh_quantization` extension.
+fn attribute_type_is_allowed(
+    semantic: &Semantic,
+    accessor: &accessor::Accessor,
+    target: bool,
+    quantized: bool,
+) -> bool {
+    use accessor::ComponentType::*;
+    let component_type = match accessor.component_type {
+        Checked::Valid(accessor::GenericComponentType(component_type)) => component_type,
+        Checked::Invalid => return true,
+    };
+    let normalized = accessor.normalized;
+    match (semantic, component_type) {
+        (_, F32) => !normalized,
+        (Semantic::Positions, I8 | I16) if target => quantized,
+        (Semantic::Positions, I8 | U8 | I16 | U16) if !target => quantized,
+        (Semantic::Normals | Semantic::Tangents, I8 | I16) => quantized && normalized,
+        (Semantic::TexCoords(_), U8 | U16) if !target => normalized || quantized,
+        (Semantic::TexCoords(_), I8 | I16) if !target => quantized,
+        (Semantic::Positions | Semantic::Normals | Semantic::Tangents, _) => false,
+        (Semantic::TexCoords(_), _) => false,
+        _ => true,
+    }
 }
 
 /// A dictionary mapping attributes to their deviations in the Morph Target.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct MorphTarget {
     /// XYZ vertex position displacements of type `[f32; 3]`.
     #[serde(rename = "POSITION")]
@@ -326,28 +416,28 @@ impl ser::Serialize for Semantic {
     }
 }
 
-impl ToString for Semantic {
-    fn to_string(&self) -> String {
+impl fmt::Display for Semantic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::Semantic::*;
         match *self {
-            Positions => "POSITION".into(),
-            Normals => "NORMAL".into(),
-            Tangents => "TANGENT".into(),
-            Colors(set) => format!("COLOR_{}", set),
-            TexCoords(set) => format!("TEXCOORD_{}", set),
-            Joints(set) => format!("JOINTS_{}", set),
-            Weights(set) => format!("WEIGHTS_{}", set),
+            Positions => write!(f, "POSITION"),
+            Normals => write!(f, "NORMAL"),
+            Tangents => write!(f, "TANGENT"),
+            Colors(set) => write!(f, "COLOR_{}", set),
+            TexCoords(set) => write!(f, "TEXCOORD_{}", set),
+            Joints(set) => write!(f, "JOINTS_{}", set),
+            Weights(set) => write!(f, "WEIGHTS_{}", set),
             #[cfg(feature = "extras")]
-            Extras(ref name) => format!("_{}", name),
+            Extras(ref name) => write!(f, "_{}", name),
         }
     }
 }
 
-impl ToString for Checked<Semantic> {
-    fn to_string(&self) -> String {
+impl fmt::Display for Checked<Semantic> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Checked::Valid(ref semantic) => semantic.to_string(),
-            Checked::Invalid => "<invalid semantic name>".into(),
+            Checked::Valid(ref semantic) => semantic.fmt(f),
+            Checked::Invalid => write!(f, "<invalid semantic name>"),
         }
     }
 }
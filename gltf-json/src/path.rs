@@ -89,6 +89,55 @@ impl Path {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Converts the path to a JSON pointer, as defined by RFC 6901.
+    ///
+    /// Any value provided by [`value_str`](Self::value_str) is omitted.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage
+    ///
+    /// ```rust
+    /// # use gltf_json::Path;
+    /// let path = Path::new().field("foo").index(0).key("a/b").value_str("baz");
+    /// assert_eq!("/foo/0/a~1b", path.pointer());
+    /// ```
+    pub fn pointer(&self) -> String {
+        let path = match self.0.find(" = \"") {
+            Some(end) => &self.0[..end],
+            None => &self.0,
+        };
+        let mut pointer = String::new();
+        let mut segment = String::new();
+        let mut chars = path.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '.' => continue,
+                '[' => {
+                    for c in chars.by_ref() {
+                        if c == ']' {
+                            break;
+                        }
+                        segment.push(c);
+                    }
+                    if segment.len() >= 2 && segment.starts_with('"') && segment.ends_with('"') {
+                        segment = segment[1..segment.len() - 1].to_string();
+                    }
+                }
+                c => {
+                    segment.push(c);
+                    while let Some(c) = chars.next_if(|&c| c != '.' && c != '[') {
+                        segment.push(c);
+                    }
+                }
+            }
+            pointer.push('/');
+            pointer.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+            segment.clear();
+        }
+        pointer
+    }
 }
 
 impl fmt::Display for Path {
@@ -2,6 +2,7 @@ use crate::buffer;
 use crate::extensions;
 use crate::texture;
 use crate::validation;
+use crate::visit;
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 use std::{self, fmt, io, marker};
@@ -12,6 +13,7 @@ use crate::{
     Skin, Texture, Value,
 };
 use validation::Validate;
+use visit::{Visit, Visitor};
 
 // TODO: As a breaking change, simplify by replacing uses of `Get<T>` with `AsRef<[T]>`.
 
@@ -157,18 +159,43 @@ where
     P: Fn() -> Path,
     R: FnMut(&dyn Fn() -> Path, crate::validation::Error),
 {
-    for (i, ext) in root.extensions_required.iter().enumerate() {
-        if !crate::extensions::ENABLED_EXTENSIONS.contains(&ext.as_str()) {
-            report(
-                &|| {
-                    path()
-                        .field("extensionsRequired")
-                        .index(i)
-                        .value_str(ext.as_str())
-                },
-                crate::validation::Error::Unsupported,
-            );
+    root.validate_extensions_required(
+        |name| crate::extensions::ENABLED_EXTENSIONS.contains(&name),
+        path,
+        report,
+    );
+}
+
+impl Visit for Root {
+    fn visit<V>(&mut self, visitor: &mut V) -> bool
+    where
+        V: Visitor,
+    {
+        // Objects of the root are never removed since that would invalidate
+        // the indices of the objects that follow.
+        fn visit_all<T: Visit, V: Visitor>(objects: &mut [T], visitor: &mut V) {
+            for object in objects {
+                object.visit(visitor);
+            }
         }
+
+        visit_all(&mut self.accessors, visitor);
+        visit_all(&mut self.animations, visitor);
+        self.asset.visit(visitor);
+        visit_all(&mut self.buffers, visitor);
+        visit_all(&mut self.buffer_views, visitor);
+        self.scene.visit(visitor);
+        self.extensions.visit(visitor);
+        visit_all(&mut self.cameras, visitor);
+        visit_all(&mut self.images, visitor);
+        visit_all(&mut self.materials, visitor);
+        visit_all(&mut self.meshes, visitor);
+        visit_all(&mut self.nodes, visitor);
+        visit_all(&mut self.samplers, visitor);
+        visit_all(&mut self.scenes, visitor);
+        visit_all(&mut self.skins, visitor);
+        visit_all(&mut self.textures, visitor);
+        true
     }
 }
 
@@ -201,6 +228,32 @@ impl Root {
         Index::push(self.as_mut(), value)
     }
 
+    /// Reports every required extension for which `enabled` returns `false`
+    /// as [`Unsupported`](validation::Error::Unsupported).
+    ///
+    /// [`Validate`] performs this check against
+    /// [`ENABLED_EXTENSIONS`](crate::extensions::ENABLED_EXTENSIONS).
+    pub fn validate_extensions_required<F, P, R>(&self, enabled: F, path: P, report: &mut R)
+    where
+        F: Fn(&str) -> bool,
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, crate::validation::Error),
+    {
+        for (i, ext) in self.extensions_required.iter().enumerate() {
+            if !enabled(ext.as_str()) {
+                report(
+                    &|| {
+                        path()
+                            .field("extensionsRequired")
+                            .index(i)
+                            .value_str(ext.as_str())
+                    },
+                    crate::validation::Error::Unsupported,
+                );
+            }
+        }
+    }
+
     /// Deserialize from a JSON string slice.
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(str_: &str) -> Result<Self, Error> {
@@ -457,7 +510,6 @@ mod tests {
 
     #[test]
     fn root_extensions() {
-        use crate::validation::Error;
         use crate::Path;
 
         let mut root = super::Root {
@@ -478,12 +530,12 @@ mod tests {
         #[cfg(not(feature = "KHR_lights_punctual"))]
         {
             assert_eq!(1, errors.len());
-            let (path, error) = errors.get(0).unwrap();
+            let (path, error) = errors.first().unwrap();
             assert_eq!(
                 path.as_str(),
                 "extensionsRequired[0] = \"KHR_lights_punctual\""
             );
-            assert_eq!(*error, Error::Unsupported);
+            assert_eq!(*error, crate::validation::Error::Unsupported);
         }
 
         root.extensions_required = vec!["KHR_mesh_quantization".to_owned()];
@@ -491,12 +543,21 @@ mod tests {
         root.validate(&root, Path::new, &mut |path, error| {
             errors.push((path(), error));
         });
-        assert_eq!(1, errors.len());
-        let (path, error) = errors.get(0).unwrap();
-        assert_eq!(
-            path.as_str(),
-            "extensionsRequired[0] = \"KHR_mesh_quantization\""
-        );
-        assert_eq!(*error, Error::Unsupported);
+
+        #[cfg(feature = "KHR_mesh_quantization")]
+        {
+            assert!(errors.is_empty());
+        }
+
+        #[cfg(not(feature = "KHR_mesh_quantization"))]
+        {
+            assert_eq!(1, errors.len());
+            let (path, error) = errors.first().unwrap();
+            assert_eq!(
+                path.as_str(),
+                "extensionsRequired[0] = \"KHR_mesh_quantization\""
+            );
+            assert_eq!(*error, crate::validation::Error::Unsupported);
+        }
     }
 }
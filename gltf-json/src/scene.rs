@@ -1,6 +1,7 @@
 use crate::validation::Validate;
+use crate::visit::Visit;
 use crate::{camera, extensions, mesh, scene, skin, Extras, Index};
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 
 /// A node in the node hierarchy.  When the node contains `skin`, all
@@ -13,7 +14,7 @@ use serde_derive::{Deserialize, Serialize};
 /// identity. When a node is targeted for animation (referenced by an
 /// animation.channel.target), only TRS properties may be present; `matrix` will not
 /// be present.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 pub struct Node {
     /// The index of the camera referenced by this node.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -79,7 +80,7 @@ pub struct Node {
 }
 
 /// The root `Node`s of a scene.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct Scene {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -112,3 +113,5 @@ impl Default for UnitQuaternion {
 }
 
 impl Validate for UnitQuaternion {}
+
+impl Visit for UnitQuaternion {}
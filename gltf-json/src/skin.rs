@@ -1,9 +1,9 @@
 use crate::{accessor, extensions, scene, Extras, Index};
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde_derive::{Deserialize, Serialize};
 
 /// Joints and matrices defining a skin.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 pub struct Skin {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1,6 +1,7 @@
 use crate::validation::{Checked, Validate};
+use crate::visit::{Visit, Visitor};
 use crate::{extensions, image, Extras, Index};
-use gltf_derive::Validate;
+use gltf_derive::{Validate, Visit};
 use serde::{de, ser};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -129,7 +130,7 @@ impl WrappingMode {
 }
 
 /// Texture sampler properties for filtering and wrapping modes.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate, Visit)]
 #[serde(default)]
 pub struct Sampler {
     /// Magnification filter.
@@ -234,10 +235,46 @@ impl Texture {
                 }
             }
         }
+        #[cfg(feature = "MSFT_texture_dds")]
+        {
+            if let Some(extensions) = &self.extensions {
+                if let Some(texture_dds) = &extensions.texture_dds {
+                    if !source_is_empty(&texture_dds.source) {
+                        source = texture_dds.source;
+                    }
+                }
+            }
+        }
+        #[cfg(feature = "KHR_texture_basisu")]
+        {
+            if let Some(extensions) = &self.extensions {
+                if let Some(texture_basisu) = &extensions.texture_basisu {
+                    // The KTX2 source takes precedence when a texture
+                    // provides several alternatives.
+                    if !source_is_empty(&texture_basisu.source) {
+                        source = texture_basisu.source;
+                    }
+                }
+            }
+        }
         source
     }
 }
 
+impl Visit for Texture {
+    fn visit<V>(&mut self, visitor: &mut V) -> bool
+    where
+        V: Visitor,
+    {
+        let mut keep = self.sampler.visit(visitor);
+        if !source_is_empty(&self.source) {
+            keep &= self.source.visit(visitor);
+        }
+        keep &= self.extensions.visit(visitor);
+        keep
+    }
+}
+
 impl Validate for Texture {
     fn validate<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
     where
@@ -258,7 +295,7 @@ impl Validate for Texture {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, Visit)]
 /// Reference to a `Texture`.
 pub struct Info {
     /// The index of the texture.
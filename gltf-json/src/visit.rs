@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use crate::validation::{Checked, USize64};
+use crate::Index;
+
+/// Trait for walking the indices contained within glTF JSON data.
+///
+/// This is the basis of operations that rewrite references between objects,
+/// such as removing or reordering the objects of a `Root`.
+pub trait Visit {
+    /// Calls `visitor` with every index contained within the object.
+    ///
+    /// Optional references and elements of collections that are rejected by
+    /// the visitor are removed. Returns `false` if a required reference was
+    /// rejected, in which case the object itself should be removed.
+    fn visit<V>(&mut self, _visitor: &mut V) -> bool
+    where
+        V: Visitor,
+    {
+        // nop
+        true
+    }
+}
+
+/// Inspects and rewrites the indices visited by [`Visit`].
+pub trait Visitor {
+    /// Visits a single index, returning `false` to reject the reference.
+    fn visit_index<T: 'static>(&mut self, index: &mut Index<T>) -> bool;
+}
+
+impl<T: 'static> Visit for Index<T> {
+    fn visit<V>(&mut self, visitor: &mut V) -> bool
+    where
+        V: Visitor,
+    {
+        visitor.visit_index(self)
+    }
+}
+
+impl<T: Visit> Visit for Option<T> {
+    fn visit<V>(&mut self, visitor: &mut V) -> bool
+    where
+        V: Visitor,
+    {
+        if let Some(value) = self.as_mut() {
+            if !value.visit(visitor) {
+                *self = None;
+            }
+        }
+        true
+    }
+}
+
+impl<T: Visit> Visit for Vec<T> {
+    fn visit<V>(&mut self, visitor: &mut V) -> bool
+    where
+        V: Visitor,
+    {
+        self.retain_mut(|value| value.visit(visitor));
+        true
+    }
+}
+
+impl<K: Ord, V: Visit> Visit for BTreeMap<K, V> {
+    fn visit<U>(&mut self, visitor: &mut U) -> bool
+    where
+        U: Visitor,
+    {
+        self.retain(|_, value| value.visit(visitor));
+        true
+    }
+}
+
+impl Visit for serde_json::Map<String, serde_json::Value> {}
+
+impl Visit for std::boxed::Box<serde_json::value::RawValue> {}
+
+// These types never contain indices.
+impl<T> Visit for Checked<T> {}
+impl Visit for USize64 {}
+impl Visit for bool {}
+impl Visit for u32 {}
+impl Visit for i32 {}
+impl Visit for f32 {}
+impl Visit for [f32; 3] {}
+impl Visit for [f32; 4] {}
+impl Visit for [f32; 16] {}
+impl Visit for () {}
+impl Visit for String {}
+impl Visit for serde_json::Value {}
@@ -44,3 +44,324 @@ fn test_non_sparse_accessor_without_buffer_view_validate() {
         [(Path("accessors[0].bufferView".into()), Error::Missing)]
     );
 }
+
+#[cfg(all(
+    feature = "KHR_materials_iridescence",
+    feature = "KHR_materials_anisotropy"
+))]
+#[test]
+fn test_material_extension_ranges_validate() {
+    let json: gltf_json::Root = serde_json::from_str(
+        r#"{
+            "asset": {"version": "2.0"},
+            "materials": [{
+                "extensions": {
+                    "KHR_materials_iridescence": {
+                        "iridescenceFactor": 1.5,
+                        "iridescenceIor": 0.5,
+                        "iridescenceThicknessMaximum": 200.0
+                    },
+                    "KHR_materials_anisotropy": {
+                        "anisotropyStrength": -0.5,
+                        "anisotropyRotation": 1.57
+                    }
+                }
+            }]
+        }"#,
+    )
+    .unwrap();
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path("materials[0].extensions.KHR_materials_iridescence.iridescenceFactor".into()),
+                Error::Invalid
+            ),
+            (
+                Path("materials[0].extensions.KHR_materials_iridescence.iridescenceIor".into()),
+                Error::Invalid
+            ),
+            (
+                Path("materials[0].extensions.KHR_materials_anisotropy.anisotropyStrength".into()),
+                Error::Invalid
+            ),
+        ]
+    );
+}
+
+#[cfg(all(
+    feature = "KHR_materials_dispersion",
+    feature = "KHR_materials_diffuse_transmission"
+))]
+#[test]
+fn test_transmission_extensions_validate() {
+    let json: gltf_json::Root = serde_json::from_str(
+        r#"{
+            "asset": {"version": "2.0"},
+            "materials": [{
+                "extensions": {
+                    "KHR_materials_dispersion": {"dispersion": -1.0},
+                    "KHR_materials_diffuse_transmission": {
+                        "diffuseTransmissionFactor": 0.5,
+                        "diffuseTransmissionColorFactor": [1.0, 2.0, 0.0]
+                    }
+                }
+            }]
+        }"#,
+    )
+    .unwrap();
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path(
+                    "materials[0].extensions.KHR_materials_diffuse_transmission.diffuseTransmissionColorFactor[1]"
+                        .into()
+                ),
+                Error::Invalid
+            ),
+            (
+                Path("materials[0].extensions.KHR_materials_dispersion.dispersion".into()),
+                Error::Invalid
+            ),
+        ]
+    );
+}
+
+#[cfg(feature = "KHR_animation_pointer")]
+#[test]
+fn test_animation_pointer_validate() {
+    let json: gltf_json::Root = serde_json::from_str(
+        r#"{
+            "asset": {"version": "2.0"},
+            "buffers": [{"byteLength": 48}],
+            "bufferViews": [{"buffer": 0, "byteLength": 48}],
+            "accessors": [
+                {"bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR"},
+                {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC4"}
+            ],
+            "materials": [{}],
+            "nodes": [{}],
+            "animations": [{
+                "samplers": [
+                    {"input": 0, "output": 1},
+                    {"input": 0, "output": 0}
+                ],
+                "channels": [
+                    {
+                        "sampler": 0,
+                        "target": {
+                            "path": "pointer",
+                            "extensions": {"KHR_animation_pointer": {
+                                "pointer": "/materials/0/pbrMetallicRoughness/baseColorFactor"
+                            }}
+                        }
+                    },
+                    {
+                        "sampler": 1,
+                        "target": {
+                            "path": "pointer",
+                            "extensions": {"KHR_animation_pointer": {
+                                "pointer": "/materials/0/pbrMetallicRoughness/baseColorFactor"
+                            }}
+                        }
+                    },
+                    {
+                        "sampler": 1,
+                        "target": {
+                            "path": "pointer",
+                            "extensions": {"KHR_animation_pointer": {
+                                "pointer": "/materials/1/pbrMetallicRoughness/baseColorTexture/extensions/KHR_texture_transform/rotation"
+                            }}
+                        }
+                    },
+                    {
+                        "sampler": 1,
+                        "target": {
+                            "node": 0,
+                            "path": "pointer",
+                            "extensions": {"KHR_animation_pointer": {
+                                "pointer": "/nodes/0/matrix"
+                            }}
+                        }
+                    },
+                    {"sampler": 1, "target": {"path": "pointer"}}
+                ]
+            }]
+        }"#,
+    )
+    .unwrap();
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path(
+                    "animations[0].channels[1].target.extensions.KHR_animation_pointer.pointer"
+                        .into()
+                ),
+                Error::Invalid
+            ),
+            (
+                Path(
+                    "animations[0].channels[2].target.extensions.KHR_animation_pointer.pointer"
+                        .into()
+                ),
+                Error::IndexOutOfBounds
+            ),
+            (
+                Path(
+                    "animations[0].channels[3].target.extensions.KHR_animation_pointer.pointer"
+                        .into()
+                ),
+                Error::Invalid
+            ),
+            (
+                Path("animations[0].channels[3].target.node".into()),
+                Error::Invalid
+            ),
+            (
+                Path("animations[0].channels[4].target.extensions.KHR_animation_pointer".into()),
+                Error::Missing
+            ),
+        ]
+    );
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+#[test]
+fn test_xmp_json_ld_validate() {
+    let json: gltf_json::Root = serde_json::from_str(
+        r#"{
+            "asset": {
+                "version": "2.0",
+                "extensions": {"KHR_xmp_json_ld": {"packet": 0}}
+            },
+            "extensions": {"KHR_xmp_json_ld": {"packets": [
+                {"@context": {"dc": "http://purl.org/dc/elements/1.1/"}, "dc:title": "Box"},
+                {"dc:creator": {"@list": ["Someone"]}}
+            ]}},
+            "nodes": [{"extensions": {"KHR_xmp_json_ld": {"packet": 2}}}]
+        }"#,
+    )
+    .unwrap();
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path("extensions.KHR_xmp_json_ld.packets[1].@context".into()),
+                Error::Missing
+            ),
+            (
+                Path("nodes[0].extensions.KHR_xmp_json_ld.packet".into()),
+                Error::IndexOutOfBounds
+            ),
+        ]
+    );
+}
+
+#[cfg(feature = "EXT_lights_image_based")]
+#[test]
+fn test_lights_image_based_validate() {
+    let json: gltf_json::Root = serde_json::from_str(
+        r#"{
+            "asset": {"version": "2.0"},
+            "images": [{"uri": "env.hdr"}],
+            "extensions": {"EXT_lights_image_based": {"lights": [{
+                "irradianceCoefficients": [[0.0, 0.0, 0.0]],
+                "specularImageSize": 2,
+                "specularImages": [[0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0, 1], [0, 0, 0, 0, 0, 0]]
+            }]}},
+            "scenes": [{"nodes": [], "extensions": {"EXT_lights_image_based": {"light": 1}}}]
+        }"#,
+    )
+    .unwrap();
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path("extensions.EXT_lights_image_based.lights[0].irradianceCoefficients".into()),
+                Error::Invalid
+            ),
+            (
+                Path("extensions.EXT_lights_image_based.lights[0].specularImages".into()),
+                Error::Invalid
+            ),
+            (
+                Path("extensions.EXT_lights_image_based.lights[0].specularImages[1][5]".into()),
+                Error::IndexOutOfBounds
+            ),
+            (
+                Path("scenes[0].extensions.EXT_lights_image_based.light".into()),
+                Error::IndexOutOfBounds
+            ),
+        ]
+    );
+}
+
+#[cfg(feature = "KHR_mesh_quantization")]
+#[test]
+fn test_mesh_quantization_validate() {
+    let source = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["KHR_mesh_quantization"],
+        "extensionsRequired": ["KHR_mesh_quantization"],
+        "buffers": [{"byteLength": 16}],
+        "bufferViews": [{"buffer": 0, "byteLength": 16, "byteStride": 16}],
+        "accessors": [
+            {"bufferView": 0, "byteOffset": 0, "componentType": 5122, "count": 1, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]},
+            {"bufferView": 0, "byteOffset": 8, "componentType": 5120, "normalized": true, "count": 1, "type": "VEC3"},
+            {"bufferView": 0, "byteOffset": 12, "componentType": 5123, "count": 1, "type": "VEC2"}
+        ],
+        "meshes": [{"primitives": [{"attributes": {"POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2}}]}]
+    }"#;
+    let mut json: gltf_json::Root = serde_json::from_str(source).unwrap();
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(errs, []);
+
+    // the same attributes are invalid without the extension
+    json.extensions_used.clear();
+    json.extensions_required.clear();
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [
+            (
+                Path("meshes[0].primitives[0].attributes[\"POSITION\"]".into()),
+                Error::Invalid
+            ),
+            (
+                Path("meshes[0].primitives[0].attributes[\"NORMAL\"]".into()),
+                Error::Invalid
+            ),
+            (
+                Path("meshes[0].primitives[0].attributes[\"TEXCOORD_0\"]".into()),
+                Error::Invalid
+            ),
+        ]
+    );
+}
@@ -157,6 +157,17 @@ impl<'a> Accessor<'a> {
         ext.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
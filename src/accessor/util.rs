@@ -132,7 +132,7 @@ impl<'a, T: Item> SparseIter<'a, T> {
             base,
             base_count,
             indices: indices.peekable(),
-            values: values,
+            values,
             counter: 0,
         }
     }
@@ -268,45 +268,17 @@ impl Item for f32 {
     }
 }
 
-impl<T: Item + Copy> Item for [T; 2] {
+impl<T: Item + Copy, const N: usize> Item for [T; N] {
     fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 2 * mem::size_of::<T>());
-        [
-            T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
-        ]
-    }
-    fn zero() -> Self {
-        [T::zero(); 2]
-    }
-}
-
-impl<T: Item + Copy> Item for [T; 3] {
-    fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 3 * mem::size_of::<T>());
-        [
-            T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
-            T::from_slice(&slice[2 * mem::size_of::<T>()..]),
-        ]
-    }
-    fn zero() -> Self {
-        [T::zero(); 3]
-    }
-}
-
-impl<T: Item + Copy> Item for [T; 4] {
-    fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 4 * mem::size_of::<T>());
-        [
-            T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
-            T::from_slice(&slice[2 * mem::size_of::<T>()..]),
-            T::from_slice(&slice[3 * mem::size_of::<T>()..]),
-        ]
+        assert!(slice.len() >= N * mem::size_of::<T>());
+        let mut items = [T::zero(); N];
+        for (index, item) in items.iter_mut().enumerate() {
+            *item = T::from_slice(&slice[index * mem::size_of::<T>()..]);
+        }
+        items
     }
     fn zero() -> Self {
-        [T::zero(); 4]
+        [T::zero(); N]
     }
 }
 
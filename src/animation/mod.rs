@@ -134,6 +134,25 @@ impl<'a> Animation<'a> {
         let ext = self.json.extensions.as_ref()?;
         ext.others.get(ext_name)
     }
+
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
+    /// Returns the metadata packet of this animation.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        let extensions = self.json.extensions.as_ref()?;
+        crate::khr_xmp_json_ld::Packet::resolve(self.document, extensions.khr_xmp_json_ld.as_ref())
+    }
 }
 
 impl<'a> Channel<'a> {
@@ -203,12 +222,22 @@ impl<'a> Target<'a> {
     }
 
     /// Returns the target node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the target has no node, which is the case when the property
+    /// is selected by a `KHR_animation_pointer` or when
+    /// [`LoadOptions::allow_empty_animation_target_node`] is set; use
+    /// [`try_node`](Self::try_node) for such documents.
+    ///
+    /// [`LoadOptions::allow_empty_animation_target_node`]: crate::LoadOptions::allow_empty_animation_target_node
     pub fn node(&self) -> scene::Node<'a> {
-        self.anim
-            .document
-            .nodes()
-            .nth(self.json.node.value())
-            .unwrap()
+        self.try_node().unwrap()
+    }
+
+    /// Returns the target node, or `None` if the target has no node.
+    pub fn try_node(&self) -> Option<scene::Node<'a>> {
+        self.anim.document.nodes().nth(self.json.node.value())
     }
 
     /// Returns the node's property to modify or the 'weights' of the morph
@@ -216,6 +245,16 @@ impl<'a> Target<'a> {
     pub fn property(&self) -> Property {
         self.json.path.unwrap()
     }
+
+    /// Returns the property selected by the `KHR_animation_pointer` extension.
+    ///
+    /// Returns `None` if the target has no pointer or the pointer does not
+    /// resolve to an animatable property of an object of the document.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    pub fn pointer(&self) -> Option<crate::khr_animation_pointer::Pointer<'a>> {
+        crate::khr_animation_pointer::Pointer::new(self.anim.document, self.json.pointer()?)
+    }
 }
 
 impl<'a> Sampler<'a> {
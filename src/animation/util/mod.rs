@@ -71,6 +71,33 @@ pub enum ReadOutputs<'a> {
 
     /// Morph target animations.
     MorphTargetWeights(MorphTargetWeights<'a>),
+
+    /// Animations of a property selected by a `KHR_animation_pointer`.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    Pointer(Pointer<'a>),
+}
+
+/// Animation output sampler values of a `KHR_animation_pointer` channel, typed
+/// after the output accessor.
+///
+/// Scalar and four-component outputs may be normalized integers and reuse the
+/// casting adapters of morph target weights and rotations respectively.
+#[cfg(feature = "KHR_animation_pointer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+#[derive(Clone, Debug)]
+pub enum Pointer<'a> {
+    /// Values of a `float` or `float[]` property.
+    Scalars(MorphTargetWeights<'a>),
+
+    /// Values of a `float2` property of type `[f32; 2]`.
+    Vec2(accessor::Iter<'a, [f32; 2]>),
+
+    /// Values of a `float3` property of type `[f32; 3]`.
+    Vec3(accessor::Iter<'a, [f32; 3]>),
+
+    /// Values of a `float4` property, such as a color or a quaternion.
+    Vec4(Rotations<'a>),
 }
 
 impl<'a> Rotations<'a> {
@@ -146,6 +173,10 @@ where
     }
 
     /// Visits the output samples of a channel.
+    ///
+    /// Channels targeting a `KHR_animation_pointer` are read according to the
+    /// type of the output accessor; `None` is returned for accessor types that
+    /// cannot animate a property, or for two and three-component integers.
     pub fn read_outputs(&self) -> Option<ReadOutputs<'s>> {
         use crate::animation::Property;
         use accessor::{DataType, Iter};
@@ -183,6 +214,42 @@ where
                     .map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::F32(x))),
                 _ => unreachable!(),
             },
+            #[cfg(feature = "KHR_animation_pointer")]
+            Property::Pointer => {
+                use accessor::Dimensions;
+                let get_buffer_data = self.get_buffer_data.clone();
+                let pointer =
+                    match (output.dimensions(), output.data_type()) {
+                        (Dimensions::Scalar, DataType::I8) => Iter::new(output, get_buffer_data)
+                            .map(|x| Pointer::Scalars(MorphTargetWeights::I8(x))),
+                        (Dimensions::Scalar, DataType::U8) => Iter::new(output, get_buffer_data)
+                            .map(|x| Pointer::Scalars(MorphTargetWeights::U8(x))),
+                        (Dimensions::Scalar, DataType::I16) => Iter::new(output, get_buffer_data)
+                            .map(|x| Pointer::Scalars(MorphTargetWeights::I16(x))),
+                        (Dimensions::Scalar, DataType::U16) => Iter::new(output, get_buffer_data)
+                            .map(|x| Pointer::Scalars(MorphTargetWeights::U16(x))),
+                        (Dimensions::Scalar, DataType::F32) => Iter::new(output, get_buffer_data)
+                            .map(|x| Pointer::Scalars(MorphTargetWeights::F32(x))),
+                        (Dimensions::Vec2, DataType::F32) => {
+                            Iter::new(output, get_buffer_data).map(Pointer::Vec2)
+                        }
+                        (Dimensions::Vec3, DataType::F32) => {
+                            Iter::new(output, get_buffer_data).map(Pointer::Vec3)
+                        }
+                        (Dimensions::Vec4, DataType::I8) => Iter::new(output, get_buffer_data)
+                            .map(|x| Pointer::Vec4(Rotations::I8(x))),
+                        (Dimensions::Vec4, DataType::U8) => Iter::new(output, get_buffer_data)
+                            .map(|x| Pointer::Vec4(Rotations::U8(x))),
+                        (Dimensions::Vec4, DataType::I16) => Iter::new(output, get_buffer_data)
+                            .map(|x| Pointer::Vec4(Rotations::I16(x))),
+                        (Dimensions::Vec4, DataType::U16) => Iter::new(output, get_buffer_data)
+                            .map(|x| Pointer::Vec4(Rotations::U16(x))),
+                        (Dimensions::Vec4, DataType::F32) => Iter::new(output, get_buffer_data)
+                            .map(|x| Pointer::Vec4(Rotations::F32(x))),
+                        _ => None,
+                    };
+                pointer.map(ReadOutputs::Pointer)
+            }
         }
     }
 }
@@ -96,6 +96,21 @@ impl<'a> Buffer<'a> {
         self.json.byte_length.0 as usize
     }
 
+    /// Returns `true` if the buffer only holds data for loaders that do not
+    /// support the `EXT_meshopt_compression` extension.
+    ///
+    /// The contents of such buffers are replaced by decompressed data on
+    /// import.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+    pub fn is_meshopt_fallback(&self) -> bool {
+        self.json
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.ext_meshopt_compression.as_ref())
+            .map_or(false, |extension| extension.fallback)
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
@@ -119,6 +134,17 @@ impl<'a> Buffer<'a> {
         ext.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -144,10 +170,12 @@ impl<'a> View<'a> {
 
     /// Returns the parent `Buffer`.
     pub fn buffer(&self) -> Buffer<'a> {
-        self.document
-            .buffers()
-            .nth(self.json.buffer.value())
-            .unwrap()
+        self.try_buffer().unwrap()
+    }
+
+    /// Returns the parent `Buffer`, or `None` if the index is out of bounds.
+    pub(crate) fn try_buffer(&self) -> Option<Buffer<'a>> {
+        self.document.buffers().nth(self.json.buffer.value())
     }
 
     /// Returns the length of the buffer view in bytes.
@@ -186,6 +214,19 @@ impl<'a> View<'a> {
         self.json.target.map(|target| target.unwrap())
     }
 
+    /// Returns the compressed data of the buffer view as defined by the
+    /// `EXT_meshopt_compression` extension.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+    pub fn meshopt_compression(&self) -> Option<crate::ext_meshopt_compression::Compression<'a>> {
+        let extension = self.json.extensions.as_ref()?;
+        let compression = extension.ext_meshopt_compression.as_ref()?;
+        Some(crate::ext_meshopt_compression::Compression::new(
+            self.document,
+            compression,
+        ))
+    }
+
     /// Returns extension data unknown to this crate version.
     #[cfg(feature = "extensions")]
     #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
@@ -202,6 +243,17 @@ impl<'a> View<'a> {
         ext.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
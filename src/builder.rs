@@ -0,0 +1,376 @@
+use std::mem;
+
+use crate::{Document, Error, Result};
+use json::accessor::{ComponentType, GenericComponentType, Type};
+use json::buffer::Target;
+use json::validation::Error as ValidationError;
+use json::validation::{Checked, USize64};
+use json::{Index, Path, Value};
+
+/// A component type of accessor data.
+pub trait Component: Copy + PartialOrd {
+    /// The corresponding accessor component type.
+    const COMPONENT_TYPE: ComponentType;
+
+    /// Appends the little endian representation of the component to `buffer`.
+    fn write(self, buffer: &mut Vec<u8>);
+
+    /// Converts the component into a JSON value for use as `min` or `max`.
+    fn to_value(self) -> Value;
+}
+
+/// A component type that may be used for primitive indices.
+pub trait IndexComponent: Component + Element {}
+
+/// A scalar, vector, or matrix element of accessor data.
+pub trait Element: Copy {
+    /// The type of the components of the element.
+    type Component: Component;
+
+    /// The corresponding accessor type.
+    const TYPE: Type;
+
+    /// Calls `f` with each component of the element in order.
+    fn for_each_component<F>(&self, f: F)
+    where
+        F: FnMut(Self::Component);
+}
+
+macro_rules! impl_component {
+    ($ty:ty, $component_type:ident) => {
+        impl Component for $ty {
+            const COMPONENT_TYPE: ComponentType = ComponentType::$component_type;
+
+            fn write(self, buffer: &mut Vec<u8>) {
+                buffer.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn to_value(self) -> Value {
+                Value::from(self)
+            }
+        }
+
+        impl Element for $ty {
+            type Component = $ty;
+            const TYPE: Type = Type::Scalar;
+
+            fn for_each_component<F>(&self, mut f: F)
+            where
+                F: FnMut(Self::Component),
+            {
+                f(*self)
+            }
+        }
+    };
+}
+
+impl_component!(i8, I8);
+impl_component!(u8, U8);
+impl_component!(i16, I16);
+impl_component!(u16, U16);
+impl_component!(u32, U32);
+impl_component!(f32, F32);
+
+impl IndexComponent for u8 {}
+impl IndexComponent for u16 {}
+impl IndexComponent for u32 {}
+
+macro_rules! impl_vector {
+    ($n:expr, $ty:ident) => {
+        impl<T: Component> Element for [T; $n] {
+            type Component = T;
+            const TYPE: Type = Type::$ty;
+
+            fn for_each_component<F>(&self, f: F)
+            where
+                F: FnMut(Self::Component),
+            {
+                self.iter().copied().for_each(f)
+            }
+        }
+    };
+}
+
+impl_vector!(2, Vec2);
+impl_vector!(3, Vec3);
+impl_vector!(4, Vec4);
+
+macro_rules! impl_matrix {
+    ($n:expr, $ty:ident) => {
+        impl Element for [[f32; $n]; $n] {
+            type Component = f32;
+            const TYPE: Type = Type::$ty;
+
+            fn for_each_component<F>(&self, f: F)
+            where
+                F: FnMut(Self::Component),
+            {
+                self.iter().flatten().copied().for_each(f)
+            }
+        }
+    };
+}
+
+impl_matrix!(2, Mat2);
+impl_matrix!(3, Mat3);
+impl_matrix!(4, Mat4);
+
+/// Helps to construct a glTF document along with its binary data.
+///
+/// Element data is written into a single managed buffer. Each call to
+/// [`attribute`](Self::attribute), [`indices`](Self::indices), or
+/// [`accessor`](Self::accessor) creates a buffer view and an accessor with the
+/// correct component type, type, `min`, and `max` values. All other objects
+/// are added with [`push`](Self::push).
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::builder::Builder;
+/// use gltf::json;
+/// use json::validation::Checked::Valid;
+///
+/// let mut builder = Builder::new();
+/// let positions = builder.attribute(&[[0.0, 0.5, 0.0], [-0.5, -0.5, 0.0], [0.5, -0.5, 0.0f32]]);
+/// let indices = builder.indices(&[0u16, 1, 2]);
+/// let primitive = json::mesh::Primitive {
+///     attributes: [(Valid(json::mesh::Semantic::Positions), positions)].into(),
+///     extensions: None,
+///     extras: Default::default(),
+///     indices: Some(indices),
+///     material: None,
+///     mode: Valid(json::mesh::Mode::Triangles),
+///     targets: None,
+/// };
+/// let mesh = builder.push(json::Mesh {
+///     extensions: None,
+///     extras: Default::default(),
+///     name: None,
+///     primitives: vec![primitive],
+///     weights: None,
+/// });
+/// let node = builder.push(json::Node {
+///     mesh: Some(mesh),
+///     ..Default::default()
+/// });
+/// builder.push(json::Scene {
+///     extensions: None,
+///     extras: Default::default(),
+///     name: None,
+///     nodes: vec![node],
+/// });
+///
+/// let (document, buffer) = builder.build()?;
+/// assert_eq!(document.accessors().count(), 2);
+/// assert_eq!(buffer.len(), 44);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+    /// The document under construction.
+    root: json::Root,
+
+    /// The managed buffer, created when element data is first written.
+    buffer: Option<Index<json::Buffer>>,
+
+    /// The contents of the managed buffer.
+    data: Vec<u8>,
+}
+
+impl Builder {
+    /// Creates a builder for an empty document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a builder that adds to an existing document.
+    ///
+    /// Element data is written to a new buffer appended to the document.
+    pub fn from_json(root: json::Root) -> Self {
+        Self {
+            root,
+            buffer: None,
+            data: Vec::new(),
+        }
+    }
+
+    /// Returns the document under construction.
+    pub fn as_json(&self) -> &json::Root {
+        &self.root
+    }
+
+    /// Returns the document under construction for modification.
+    pub fn as_json_mut(&mut self) -> &mut json::Root {
+        &mut self.root
+    }
+
+    /// Adds an object to the document and returns its index.
+    pub fn push<T>(&mut self, value: T) -> Index<T>
+    where
+        json::Root: AsMut<Vec<T>>,
+    {
+        self.root.push(value)
+    }
+
+    /// Writes vertex attribute data, bound to `ARRAY_BUFFER`.
+    ///
+    /// Elements whose size is not a multiple of four bytes are padded, as
+    /// required for vertex attributes.
+    pub fn attribute<T: Element>(&mut self, data: &[T]) -> Index<json::Accessor> {
+        self.write(data, Some(Target::ArrayBuffer), false)
+    }
+
+    /// Writes normalized integer vertex attribute data, bound to
+    /// `ARRAY_BUFFER`.
+    pub fn normalized_attribute<T: Element>(&mut self, data: &[T]) -> Index<json::Accessor> {
+        self.write(data, Some(Target::ArrayBuffer), true)
+    }
+
+    /// Writes primitive indices, bound to `ELEMENT_ARRAY_BUFFER`.
+    pub fn indices<T: IndexComponent>(&mut self, data: &[T]) -> Index<json::Accessor> {
+        self.write(data, Some(Target::ElementArrayBuffer), false)
+    }
+
+    /// Writes data that is not bound to a target, such as animation
+    /// keyframes or inverse bind matrices.
+    pub fn accessor<T: Element>(&mut self, data: &[T]) -> Index<json::Accessor> {
+        self.write(data, None, false)
+    }
+
+    fn write<T: Element>(
+        &mut self,
+        data: &[T],
+        target: Option<Target>,
+        normalized: bool,
+    ) -> Index<json::Accessor> {
+        let buffer = match self.buffer {
+            Some(buffer) => buffer,
+            None => {
+                let buffer = self.root.push(json::Buffer {
+                    byte_length: USize64(0),
+                    #[cfg(feature = "names")]
+                    name: None,
+                    uri: None,
+                    extensions: None,
+                    extras: Default::default(),
+                });
+                self.buffer = Some(buffer);
+                buffer
+            }
+        };
+
+        while self.data.len() % 4 != 0 {
+            self.data.push(0);
+        }
+        let offset = self.data.len();
+        let size = mem::size_of::<T>();
+        let stride = match target {
+            Some(Target::ArrayBuffer) => (size + 3) & !3,
+            _ => size,
+        };
+
+        let multiplicity = T::TYPE.multiplicity();
+        let mut min: Vec<T::Component> = Vec::with_capacity(multiplicity);
+        let mut max: Vec<T::Component> = Vec::with_capacity(multiplicity);
+        for element in data {
+            let mut component_index = 0;
+            element.for_each_component(|component| {
+                component.write(&mut self.data);
+                if let Some(min) = min.get_mut(component_index) {
+                    if component < *min {
+                        *min = component;
+                    }
+                } else {
+                    min.push(component);
+                }
+                if let Some(max) = max.get_mut(component_index) {
+                    if component > *max {
+                        *max = component;
+                    }
+                } else {
+                    max.push(component);
+                }
+                component_index += 1;
+            });
+            for _ in size..stride {
+                self.data.push(0);
+            }
+        }
+
+        let view = self.root.push(json::buffer::View {
+            buffer,
+            byte_length: USize64::from(self.data.len() - offset),
+            byte_offset: Some(USize64::from(offset)),
+            byte_stride: if stride != size {
+                Some(json::buffer::Stride(stride))
+            } else {
+                None
+            },
+            #[cfg(feature = "names")]
+            name: None,
+            target: target.map(Checked::Valid),
+            extensions: None,
+            extras: Default::default(),
+        });
+        let to_value = |values: Vec<T::Component>| {
+            if values.is_empty() {
+                None
+            } else {
+                Some(Value::from(
+                    values
+                        .into_iter()
+                        .map(Component::to_value)
+                        .collect::<Vec<_>>(),
+                ))
+            }
+        };
+        self.root.push(json::Accessor {
+            buffer_view: Some(view),
+            byte_offset: None,
+            count: USize64::from(data.len()),
+            component_type: Checked::Valid(GenericComponentType(T::Component::COMPONENT_TYPE)),
+            extensions: None,
+            extras: Default::default(),
+            type_: Checked::Valid(T::TYPE),
+            min: to_value(min),
+            max: to_value(max),
+            #[cfg(feature = "names")]
+            name: None,
+            normalized,
+            sparse: None,
+        })
+    }
+
+    /// Validates the document and returns it along with the contents of the
+    /// managed buffer.
+    ///
+    /// The buffer is padded to a multiple of four bytes. Accessors without
+    /// any elements, such as those written from empty slices, are rejected.
+    pub fn build(mut self) -> Result<(Document, Vec<u8>)> {
+        let empty: Vec<_> = self
+            .root
+            .accessors
+            .iter()
+            .enumerate()
+            .filter(|(_, accessor)| accessor.count.0 == 0)
+            .map(|(index, _)| {
+                let path = Path::new().field("accessors").index(index).field("count");
+                (path, ValidationError::Invalid)
+            })
+            .collect();
+        if !empty.is_empty() {
+            return Err(Error::Validation(empty));
+        }
+        while self.data.len() % 4 != 0 {
+            self.data.push(0);
+        }
+        if let Some(buffer) = self.buffer {
+            self.root.buffers[buffer.value()].byte_length = USize64::from(self.data.len());
+        }
+        let document = Document::from_json(self.root)?;
+        Ok((document, self.data))
+    }
+}
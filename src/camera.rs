@@ -76,7 +76,7 @@ impl<'a> Camera<'a> {
     }
 
     /// Returns the camera's projection.
-    pub fn projection(&self) -> Projection {
+    pub fn projection(&self) -> Projection<'_> {
         match self.json.type_.unwrap() {
             json::camera::Type::Orthographic => {
                 let json = self.json.orthographic.as_ref().unwrap();
@@ -105,6 +105,17 @@ impl<'a> Camera<'a> {
         ext.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops;
+
+use super::{collapse, Kind};
+use crate::accessor::util::{Item, Iter};
+use crate::accessor::Accessor;
+#[cfg(feature = "import")]
+use crate::import::{DefaultResolver, ResourceResolver};
+use crate::Document;
+use json::serialize::to_value;
+
+/// The raw bytes of a single accessor element.
+#[derive(Clone, Copy)]
+struct Element<const N: usize>([u8; N]);
+
+impl<const N: usize> Item for Element<N> {
+    fn from_slice(slice: &[u8]) -> Self {
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(&slice[..N]);
+        Element(bytes)
+    }
+
+    fn zero() -> Self {
+        Element([0; N])
+    }
+}
+
+/// Appends the elements of `accessor` to `output`, with sparse substitution
+/// applied.
+fn read_elements<'a, 's, F, const N: usize>(
+    accessor: Accessor<'a>,
+    get_buffer_data: F,
+    output: &mut Vec<u8>,
+) -> Option<()>
+where
+    F: Clone + Fn(crate::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    for element in Iter::<Element<N>>::new(accessor, get_buffer_data)? {
+        output.extend_from_slice(&element.0);
+    }
+    Some(())
+}
+
+/// Returns the data type, dimensions, and contents of an accessor, or `None`
+/// if its contents cannot be read.
+fn accessor_key<'a, 's, F>(accessor: Accessor<'a>, get_buffer_data: F) -> Option<Vec<u8>>
+where
+    F: Clone + Fn(crate::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let mut key = Vec::new();
+    key.extend_from_slice(&accessor.data_type().as_gl_enum().to_le_bytes());
+    key.push(accessor.dimensions() as u8);
+    key.push(accessor.normalized() as u8);
+    key.extend_from_slice(&(accessor.count() as u64).to_le_bytes());
+    if accessor.count() == 0 {
+        return Some(key);
+    }
+    let f = get_buffer_data;
+    match accessor.size() {
+        1 => read_elements::<_, 1>(accessor, f, &mut key)?,
+        2 => read_elements::<_, 2>(accessor, f, &mut key)?,
+        3 => read_elements::<_, 3>(accessor, f, &mut key)?,
+        4 => read_elements::<_, 4>(accessor, f, &mut key)?,
+        6 => read_elements::<_, 6>(accessor, f, &mut key)?,
+        8 => read_elements::<_, 8>(accessor, f, &mut key)?,
+        9 => read_elements::<_, 9>(accessor, f, &mut key)?,
+        12 => read_elements::<_, 12>(accessor, f, &mut key)?,
+        16 => read_elements::<_, 16>(accessor, f, &mut key)?,
+        18 => read_elements::<_, 18>(accessor, f, &mut key)?,
+        24 => read_elements::<_, 24>(accessor, f, &mut key)?,
+        32 => read_elements::<_, 32>(accessor, f, &mut key)?,
+        36 => read_elements::<_, 36>(accessor, f, &mut key)?,
+        64 => read_elements::<_, 64>(accessor, f, &mut key)?,
+        _ => return None,
+    }
+    Some(key)
+}
+
+/// Returns the JSON of an object without its name, which does not affect
+/// its content.
+fn json_key(mut value: json::Value) -> String {
+    if let Some(object) = value.as_object_mut() {
+        object.remove("name");
+    }
+    value.to_string()
+}
+
+/// The contents an image is compared by.
+#[derive(PartialEq, Eq, Hash)]
+enum ImageKey {
+    /// The encoded image data.
+    Bytes(Vec<u8>),
+
+    /// The URI of an image whose data was not resolved.
+    Uri(String),
+}
+
+/// Maps every key to the position of its first occurrence.
+///
+/// Objects without a key are never considered duplicates.
+fn canonical<K, I>(keys: I) -> Vec<usize>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = Option<K>>,
+{
+    let mut first = HashMap::new();
+    keys.into_iter()
+        .enumerate()
+        .map(|(index, key)| match key {
+            Some(key) => *first.entry(key).or_insert(index),
+            None => index,
+        })
+        .collect()
+}
+
+/// Collapses identical accessors, images, samplers, textures, and materials
+/// into one and rewrites all references to them.
+///
+/// Accessors are compared by their element data, read with sparse
+/// substitution applied, along with their data type, dimensions and
+/// normalization. Images are compared by their encoded bytes, read from their
+/// buffer view or decoded from a `data:` URI, and other images are compared by
+/// URI; see [`dedup_with_resolver`] to compare them by their bytes too.
+/// Samplers, textures, and materials are compared by their JSON after the
+/// objects they reference have been deduplicated. Names are not taken into
+/// account.
+///
+/// `buffers` must contain the data of every buffer in `root`. Buffer views
+/// and data are left in place; use [`prune_with_data`] afterwards to remove
+/// those that are no longer referenced.
+///
+/// [`prune_with_data`]: super::prune_with_data
+pub fn dedup<B>(root: &mut json::Root, buffers: &[B])
+where
+    B: ops::Deref<Target = [u8]>,
+{
+    let uris: Vec<Option<Vec<u8>>> = root
+        .images
+        .iter()
+        .map(|image| {
+            let uri = image.uri.as_deref()?;
+            decode_data_uri(uri)
+        })
+        .collect();
+    dedup_impl(root, buffers, uris);
+}
+
+#[cfg(feature = "import")]
+fn decode_data_uri(uri: &str) -> Option<Vec<u8>> {
+    if uri.starts_with("data:") {
+        DefaultResolver::new(None).resolve(uri).ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(not(feature = "import"))]
+fn decode_data_uri(_uri: &str) -> Option<Vec<u8>> {
+    None
+}
+
+/// Collapses identical accessors, images, samplers, textures, and materials
+/// into one, loading the images that refer to a URI with the given
+/// `resolver` so that they are compared by their bytes.
+///
+/// See [`dedup`] for how objects are compared.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub fn dedup_with_resolver<B>(
+    root: &mut json::Root,
+    buffers: &[B],
+    resolver: &dyn ResourceResolver,
+) -> crate::Result<()>
+where
+    B: ops::Deref<Target = [u8]>,
+{
+    let mut uris = Vec::with_capacity(root.images.len());
+    for image in &root.images {
+        let data = match image.uri.as_deref() {
+            Some(uri) => Some(resolver.resolve(uri)?),
+            None => None,
+        };
+        uris.push(data);
+    }
+    dedup_impl(root, buffers, uris);
+    Ok(())
+}
+
+/// Deduplicates `root`, given the resolved data of the images that refer to
+/// a URI.
+fn dedup_impl<B>(root: &mut json::Root, buffers: &[B], mut uris: Vec<Option<Vec<u8>>>)
+where
+    B: ops::Deref<Target = [u8]>,
+{
+    let (accessors, images) = {
+        let document = Document::from_json_without_validation(root.clone());
+        let get_buffer_data = |buffer: crate::Buffer| buffers.get(buffer.index()).map(|x| &**x);
+        let accessors = canonical(
+            document
+                .accessors()
+                .map(|accessor| accessor_key(accessor, get_buffer_data)),
+        );
+        let images = canonical(document.images().map(|image| match image.source() {
+            crate::image::Source::Uri { uri, .. } => Some(match uris[image.index()].take() {
+                Some(data) => ImageKey::Bytes(data),
+                None => ImageKey::Uri(uri.to_owned()),
+            }),
+            crate::image::Source::View { view, .. } => {
+                let data = get_buffer_data(view.buffer())?;
+                let bytes = data.get(view.offset()..view.offset() + view.length())?;
+                Some(ImageKey::Bytes(bytes.to_vec()))
+            }
+        }));
+        (accessors, images)
+    };
+
+    collapse(root, Kind::Accessor, &accessors);
+    collapse(root, Kind::Image, &images);
+    let samplers = canonical(root.samplers.iter().map(|x| to_value(x).ok().map(json_key)));
+    collapse(root, Kind::Sampler, &samplers);
+    let textures = canonical(root.textures.iter().map(|x| to_value(x).ok().map(json_key)));
+    collapse(root, Kind::Texture, &textures);
+    let materials = canonical(
+        root.materials
+            .iter()
+            .map(|x| to_value(x).ok().map(json_key)),
+    );
+    collapse(root, Kind::Material, &materials);
+}
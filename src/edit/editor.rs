@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+use super::{remove, Kind};
+use crate::{Document, Error, Result};
+use json::validation::Error as ValidationError;
+use json::{Index, Path};
+
+/// Returns an error for an index that does not refer to an existing object.
+fn out_of_bounds<T>(kind: Kind, index: Index<T>) -> Error {
+    let path = kind.path(index.value());
+    Error::Validation(vec![(path, ValidationError::IndexOutOfBounds)])
+}
+
+/// Modifies a glTF document while keeping the references between its objects
+/// consistent.
+///
+/// Objects are removed along with every reference to them. Optional
+/// references are cleared, and objects that require a removed object, such
+/// as animation channels targeting a removed node, are removed as well. The
+/// indices of the remaining objects are rewritten accordingly.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::edit::Editor;
+/// use gltf::json;
+///
+/// let (document, _, _) = gltf::import("examples/Box.gltf")?;
+/// let mut editor = Editor::new(document);
+/// let node = editor.add_node(json::Node::default(), None)?;
+/// editor.remove_mesh(json::Index::new(0))?;
+/// let document = editor.build()?;
+/// assert_eq!(document.meshes().count(), 0);
+/// assert_eq!(document.default_scene().unwrap().nodes().count(), 2);
+/// assert_eq!(node.value(), 2);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Editor {
+    /// The document being edited.
+    document: Document,
+}
+
+impl Editor {
+    /// Creates an editor for an existing document.
+    pub fn new(document: Document) -> Self {
+        Self { document }
+    }
+
+    /// Creates an editor for unvalidated JSON data.
+    pub fn from_json(root: json::Root) -> Self {
+        Self::new(Document::from_json_without_validation(root))
+    }
+
+    /// Returns the document being edited.
+    pub fn as_json(&self) -> &json::Root {
+        self.document.as_json()
+    }
+
+    fn root(&mut self) -> &mut json::Root {
+        &mut self.document.0
+    }
+
+    /// Adds an object that is not referenced by any other and returns its
+    /// index.
+    pub fn push<T>(&mut self, value: T) -> Index<T>
+    where
+        json::Root: AsMut<Vec<T>>,
+    {
+        self.root().push(value)
+    }
+
+    /// Adds a node as a child of `parent` and returns its index.
+    ///
+    /// When `parent` is `None`, the node becomes a root node of the default
+    /// scene, or of the first scene if there is no default scene. It is an
+    /// error for `parent` to be `None` in a document without scenes.
+    pub fn add_node(
+        &mut self,
+        node: json::Node,
+        parent: Option<Index<json::Node>>,
+    ) -> Result<Index<json::Node>> {
+        match parent {
+            Some(parent) => self.check_node(parent)?,
+            None => self.check_scene()?,
+        }
+        let index = self.root().push(node);
+        self.attach(index, parent);
+        Ok(index)
+    }
+
+    /// Moves a node, along with its descendants, to become a child of
+    /// `parent`.
+    ///
+    /// When `parent` is `None`, the node becomes a root node of the default
+    /// scene, or of the first scene if there is no default scene. Moving a
+    /// node beneath itself or one of its descendants is an error, as is
+    /// moving it to the root of a document without scenes.
+    pub fn reparent(
+        &mut self,
+        node: Index<json::Node>,
+        parent: Option<Index<json::Node>>,
+    ) -> Result<()> {
+        self.check_node(node)?;
+        match parent {
+            Some(parent) => {
+                self.check_node(parent)?;
+                if self.descendants(node).contains(&parent.value()) {
+                    let path = Path::new()
+                        .field("nodes")
+                        .index(parent.value())
+                        .field("children");
+                    return Err(Error::Validation(vec![(path, ValidationError::Invalid)]));
+                }
+            }
+            None => self.check_scene()?,
+        }
+        self.detach(node);
+        self.attach(node, parent);
+        Ok(())
+    }
+
+    /// Removes a node along with all of its descendants.
+    ///
+    /// Removing a node that is, or has a descendant that is, a joint of a
+    /// skin is an error, since the joints of a skin must correspond to the
+    /// vertex joint indices of the meshes it deforms.
+    pub fn remove_node(&mut self, node: Index<json::Node>) -> Result<()> {
+        self.check_node(node)?;
+        let mut flags = vec![true; self.as_json().nodes.len()];
+        for index in self.descendants(node) {
+            flags[index] = false;
+        }
+        let mut errors = Vec::new();
+        for (skin_index, skin) in self.as_json().skins.iter().enumerate() {
+            for (joint_index, joint) in skin.joints.iter().enumerate() {
+                if !flags.get(joint.value()).copied().unwrap_or(true) {
+                    let path = Path::new()
+                        .field("skins")
+                        .index(skin_index)
+                        .field("joints")
+                        .index(joint_index);
+                    errors.push((path, ValidationError::Invalid));
+                }
+            }
+        }
+        if !errors.is_empty() {
+            return Err(Error::Validation(errors));
+        }
+        let mut keep = HashMap::new();
+        keep.insert(Kind::Node, flags);
+        remove(self.root(), &keep);
+        self.clear_empty_children();
+        Ok(())
+    }
+
+    /// Replaces the material of a mesh primitive.
+    ///
+    /// `None` selects the default material.
+    pub fn set_material(
+        &mut self,
+        mesh: Index<json::Mesh>,
+        primitive: usize,
+        material: Option<Index<json::Material>>,
+    ) -> Result<()> {
+        if let Some(material) = material {
+            if self.as_json().get(material).is_none() {
+                return Err(out_of_bounds(Kind::Material, material));
+            }
+        }
+        let primitives = match self.root().meshes.get_mut(mesh.value()) {
+            Some(mesh) => &mut mesh.primitives,
+            None => return Err(out_of_bounds(Kind::Mesh, mesh)),
+        };
+        match primitives.get_mut(primitive) {
+            Some(primitive) => {
+                primitive.material = material;
+                Ok(())
+            }
+            None => {
+                let path = Path::new()
+                    .field("meshes")
+                    .index(mesh.value())
+                    .field("primitives")
+                    .index(primitive);
+                Err(Error::Validation(vec![(
+                    path,
+                    ValidationError::IndexOutOfBounds,
+                )]))
+            }
+        }
+    }
+
+    /// Removes a mesh from the document and from every node that
+    /// instantiates it.
+    pub fn remove_mesh(&mut self, mesh: Index<json::Mesh>) -> Result<()> {
+        if self.as_json().get(mesh).is_none() {
+            return Err(out_of_bounds(Kind::Mesh, mesh));
+        }
+        let mut flags = vec![true; self.as_json().meshes.len()];
+        flags[mesh.value()] = false;
+        let mut keep = HashMap::new();
+        keep.insert(Kind::Mesh, flags);
+        remove(self.root(), &keep);
+        Ok(())
+    }
+
+    /// Validates the document in its current state.
+    pub fn validate(&self) -> Result<()> {
+        self.document.validate()
+    }
+
+    /// Validates and returns the edited document.
+    pub fn build(self) -> Result<Document> {
+        self.document.validate()?;
+        Ok(self.document)
+    }
+
+    fn check_node(&self, node: Index<json::Node>) -> Result<()> {
+        match self.as_json().get(node) {
+            Some(_) => Ok(()),
+            None => Err(out_of_bounds(Kind::Node, node)),
+        }
+    }
+
+    /// Checks that there is a scene for root nodes to be added to.
+    fn check_scene(&self) -> Result<()> {
+        let root = self.as_json();
+        let error = match root.scene {
+            Some(scene) if root.get(scene).is_none() => ValidationError::IndexOutOfBounds,
+            None if root.scenes.is_empty() => ValidationError::Missing,
+            _ => return Ok(()),
+        };
+        let path = Path::new().field("scene");
+        Err(Error::Validation(vec![(path, error)]))
+    }
+
+    /// Returns the indices of a node and all of its descendants.
+    fn descendants(&self, node: Index<json::Node>) -> Vec<usize> {
+        let nodes = &self.as_json().nodes;
+        let mut found = Vec::new();
+        let mut stack = vec![node.value()];
+        while let Some(index) = stack.pop() {
+            if found.contains(&index) {
+                continue;
+            }
+            found.push(index);
+            if let Some(children) = nodes.get(index).and_then(|node| node.children.as_ref()) {
+                stack.extend(children.iter().map(|child| child.value()));
+            }
+        }
+        found
+    }
+
+    /// Removes a node from the children of every node and from the root
+    /// nodes of every scene.
+    fn detach(&mut self, node: Index<json::Node>) {
+        let root = self.root();
+        for parent in &mut root.nodes {
+            if let Some(children) = parent.children.as_mut() {
+                children.retain(|child| *child != node);
+            }
+        }
+        for scene in &mut root.scenes {
+            scene.nodes.retain(|child| *child != node);
+        }
+        self.clear_empty_children();
+    }
+
+    fn attach(&mut self, node: Index<json::Node>, parent: Option<Index<json::Node>>) {
+        let root = self.root();
+        match parent {
+            Some(parent) => root.nodes[parent.value()]
+                .children
+                .get_or_insert_with(Vec::new)
+                .push(node),
+            None => {
+                let scene = root.scene.map_or(0, |scene| scene.value());
+                if let Some(scene) = root.scenes.get_mut(scene) {
+                    scene.nodes.push(node);
+                }
+            }
+        }
+    }
+
+    /// Replaces empty lists of children, which are not allowed, with `None`.
+    fn clear_empty_children(&mut self) {
+        for node in &mut self.root().nodes {
+            if node.children.as_ref().map_or(false, Vec::is_empty) {
+                node.children = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use super::{ranges, remove, Kind, Remap};
+use json::visit::Visit;
+
+#[cfg(feature = "import")]
+use crate::buffer;
+#[cfg(feature = "import")]
+use json::validation::USize64;
+
+/// Appends every object of `other` to `root`, offsetting the indices of
+/// `other` accordingly.
+///
+/// When `concatenate` is `true`, the first buffer of `other` is folded into
+/// the first buffer of `root` and `other`'s views of it are moved by
+/// `byte_offset`.
+fn append(root: &mut json::Root, mut other: json::Root, concatenate: bool, byte_offset: u64) {
+    if concatenate {
+        for range in ranges(&other) {
+            if range.buffer(&other) == 0 {
+                let (offset, _) = range.bounds(&other);
+                range.set(&mut other, 0, offset + byte_offset as usize);
+            }
+        }
+    }
+
+    let mut remap = Remap::default();
+    for &kind in Kind::ALL {
+        let base = kind.len(root) as u32;
+        let len = kind.len(&other) as u32;
+        let map = if kind == Kind::Buffer && concatenate {
+            (0..len)
+                .map(|index| Some(if index == 0 { 0 } else { base + index - 1 }))
+                .collect()
+        } else {
+            (0..len).map(|index| Some(base + index)).collect()
+        };
+        remap.insert(kind, map);
+    }
+    other.visit(&mut remap);
+    if concatenate {
+        other.buffers.remove(0);
+    }
+
+    for &kind in Kind::ALL {
+        kind.append(root, &mut other);
+    }
+    if root.scene.is_none() {
+        root.scene = other.scene;
+    }
+    for extension in other.extensions_used {
+        if !root.extensions_used.contains(&extension) {
+            root.extensions_used.push(extension);
+        }
+    }
+    for extension in other.extensions_required {
+        if !root.extensions_required.contains(&extension) {
+            root.extensions_required.push(extension);
+        }
+    }
+    #[cfg(feature = "extensions")]
+    if let Some(extensions) = other.extensions {
+        let others = &mut root.extensions.get_or_insert_with(Default::default).others;
+        for (key, value) in extensions.others {
+            others.entry(key).or_insert(value);
+        }
+    }
+}
+
+/// Merges the objects of `other` into `root`.
+///
+/// The objects of `other` are appended after those of `root` and all of their
+/// indices are offset accordingly. The extensions used and required by both
+/// documents are combined. The asset information and the default scene of
+/// `root` take precedence.
+///
+/// Buffers are appended as they are; see [`merge_with_data`] to also combine
+/// their data. Use [`merge_scenes`] afterwards to combine the scenes of both
+/// documents into one.
+pub fn merge(root: &mut json::Root, other: json::Root) {
+    append(root, other, false, 0);
+}
+
+/// Merges the objects of `other` and their buffer data into `root`.
+///
+/// `buffers` and `other_buffers` must contain the data of every buffer in
+/// `root` and `other` respectively. If the first buffer of both documents has
+/// no URI, as is the case for binary glTF, the second is concatenated onto
+/// the first so that the result may still be written as binary glTF. Any
+/// other buffers are appended.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub fn merge_with_data(
+    root: &mut json::Root,
+    buffers: &mut Vec<buffer::Data>,
+    other: json::Root,
+    other_buffers: Vec<buffer::Data>,
+) {
+    let concatenate = matches!(
+        (root.buffers.first(), other.buffers.first()),
+        (Some(first), Some(other_first)) if first.uri.is_none() && other_first.uri.is_none()
+    );
+    let mut other_buffers = other_buffers.into_iter();
+    let mut byte_offset = 0;
+    if concatenate {
+        let other_data = other_buffers.next().map_or_else(Vec::new, |data| data.0);
+        let byte_length = other.buffers[0].byte_length.0;
+        let first = &mut root.buffers[0];
+        byte_offset = (first.byte_length.0 + 3) & !3;
+        first.byte_length = USize64(byte_offset + byte_length);
+        buffers[0].0.resize(byte_offset as usize, 0);
+        buffers[0].0.extend_from_slice(&other_data);
+    }
+    buffers.extend(other_buffers);
+    append(root, other, concatenate, byte_offset);
+}
+
+/// Combines every scene of `root` into a single scene.
+///
+/// The root nodes of all scenes are collected into the first scene, which
+/// becomes the default scene. The other scenes are removed.
+pub fn merge_scenes(root: &mut json::Root) {
+    if root.scenes.is_empty() {
+        return;
+    }
+    let mut nodes = Vec::new();
+    for scene in &mut root.scenes {
+        for node in scene.nodes.drain(..) {
+            if !nodes.contains(&node) {
+                nodes.push(node);
+            }
+        }
+    }
+    root.scenes[0].nodes = nodes;
+    let mut keep = HashMap::new();
+    let mut flags = vec![false; root.scenes.len()];
+    flags[0] = true;
+    keep.insert(Kind::Scene, flags);
+    remove(root, &keep);
+    root.scene = Some(json::Index::new(0));
+}
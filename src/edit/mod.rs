@@ -0,0 +1,525 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use json::validation::USize64;
+use json::visit::{Visit, Visitor};
+use json::{Index, Path};
+
+#[cfg(feature = "utils")]
+mod dedup;
+mod editor;
+mod merge;
+pub(crate) mod prune;
+#[cfg(feature = "import")]
+mod repack;
+#[cfg(feature = "KHR_materials_variants")]
+mod variant;
+
+#[cfg(feature = "utils")]
+#[doc(inline)]
+pub use self::dedup::dedup;
+#[cfg(all(feature = "utils", feature = "import"))]
+#[doc(inline)]
+pub use self::dedup::dedup_with_resolver;
+#[doc(inline)]
+pub use self::editor::Editor;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::merge::merge_with_data;
+#[doc(inline)]
+pub use self::merge::{merge, merge_scenes};
+#[doc(inline)]
+pub use self::prune::prune;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::prune::prune_with_data;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::repack::repack;
+#[cfg(feature = "KHR_materials_variants")]
+#[doc(inline)]
+pub use self::variant::apply_variant;
+
+/// Identifies one of the top-level collections of objects in a `json::Root`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum Kind {
+    Accessor,
+    Animation,
+    Buffer,
+    View,
+    Camera,
+    Image,
+    Material,
+    Mesh,
+    Node,
+    Sampler,
+    Scene,
+    Skin,
+    Texture,
+    #[cfg(feature = "KHR_lights_punctual")]
+    Light,
+    #[cfg(feature = "EXT_lights_image_based")]
+    ImageBasedLight,
+    #[cfg(feature = "KHR_materials_variants")]
+    Variant,
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    Packet,
+}
+
+/// Evaluates `$body` with `$ty` aliased to the object type of `$kind`.
+macro_rules! with_kind {
+    ($kind:expr, $ty:ident => $body:expr) => {
+        match $kind {
+            Kind::Accessor => {
+                type $ty = json::Accessor;
+                $body
+            }
+            Kind::Animation => {
+                type $ty = json::Animation;
+                $body
+            }
+            Kind::Buffer => {
+                type $ty = json::Buffer;
+                $body
+            }
+            Kind::View => {
+                type $ty = json::buffer::View;
+                $body
+            }
+            Kind::Camera => {
+                type $ty = json::Camera;
+                $body
+            }
+            Kind::Image => {
+                type $ty = json::Image;
+                $body
+            }
+            Kind::Material => {
+                type $ty = json::Material;
+                $body
+            }
+            Kind::Mesh => {
+                type $ty = json::Mesh;
+                $body
+            }
+            Kind::Node => {
+                type $ty = json::Node;
+                $body
+            }
+            Kind::Sampler => {
+                type $ty = json::texture::Sampler;
+                $body
+            }
+            Kind::Scene => {
+                type $ty = json::Scene;
+                $body
+            }
+            Kind::Skin => {
+                type $ty = json::Skin;
+                $body
+            }
+            Kind::Texture => {
+                type $ty = json::Texture;
+                $body
+            }
+            #[cfg(feature = "KHR_lights_punctual")]
+            Kind::Light => {
+                type $ty = json::extensions::scene::khr_lights_punctual::Light;
+                $body
+            }
+            #[cfg(feature = "EXT_lights_image_based")]
+            Kind::ImageBasedLight => {
+                type $ty = json::extensions::scene::ext_lights_image_based::Light;
+                $body
+            }
+            #[cfg(feature = "KHR_materials_variants")]
+            Kind::Variant => {
+                type $ty = json::extensions::scene::khr_materials_variants::Variant;
+                $body
+            }
+            #[cfg(feature = "KHR_xmp_json_ld")]
+            Kind::Packet => {
+                type $ty = json::extensions::root::khr_xmp_json_ld::Packet;
+                $body
+            }
+        }
+    };
+}
+
+impl Kind {
+    /// Every kind of object.
+    pub(crate) const ALL: &'static [Kind] = &[
+        Kind::Accessor,
+        Kind::Animation,
+        Kind::Buffer,
+        Kind::View,
+        Kind::Camera,
+        Kind::Image,
+        Kind::Material,
+        Kind::Mesh,
+        Kind::Node,
+        Kind::Sampler,
+        Kind::Scene,
+        Kind::Skin,
+        Kind::Texture,
+        #[cfg(feature = "KHR_lights_punctual")]
+        Kind::Light,
+        #[cfg(feature = "EXT_lights_image_based")]
+        Kind::ImageBasedLight,
+        #[cfg(feature = "KHR_materials_variants")]
+        Kind::Variant,
+        #[cfg(feature = "KHR_xmp_json_ld")]
+        Kind::Packet,
+    ];
+
+    /// Returns the kind of objects of type `T`, if any.
+    pub(crate) fn of<T: 'static>() -> Option<Kind> {
+        let id = TypeId::of::<T>();
+        Kind::ALL
+            .iter()
+            .copied()
+            .find(|kind| with_kind!(*kind, U => TypeId::of::<U>() == id))
+    }
+
+    /// Returns the path of the object of this kind at `index`.
+    pub(crate) fn path(self, index: usize) -> Path {
+        let path = Path::new();
+        let path = match self {
+            Kind::Accessor => path.field("accessors"),
+            Kind::Animation => path.field("animations"),
+            Kind::Buffer => path.field("buffers"),
+            Kind::View => path.field("bufferViews"),
+            Kind::Camera => path.field("cameras"),
+            Kind::Image => path.field("images"),
+            Kind::Material => path.field("materials"),
+            Kind::Mesh => path.field("meshes"),
+            Kind::Node => path.field("nodes"),
+            Kind::Sampler => path.field("samplers"),
+            Kind::Scene => path.field("scenes"),
+            Kind::Skin => path.field("skins"),
+            Kind::Texture => path.field("textures"),
+            #[cfg(feature = "KHR_lights_punctual")]
+            Kind::Light => path
+                .field("extensions")
+                .field("KHR_lights_punctual")
+                .field("lights"),
+            #[cfg(feature = "EXT_lights_image_based")]
+            Kind::ImageBasedLight => path
+                .field("extensions")
+                .field("EXT_lights_image_based")
+                .field("lights"),
+            #[cfg(feature = "KHR_materials_variants")]
+            Kind::Variant => path
+                .field("extensions")
+                .field("KHR_materials_variants")
+                .field("variants"),
+            #[cfg(feature = "KHR_xmp_json_ld")]
+            Kind::Packet => path
+                .field("extensions")
+                .field("KHR_xmp_json_ld")
+                .field("packets"),
+        };
+        path.index(index)
+    }
+
+    /// Returns the number of objects of this kind in `root`.
+    pub(crate) fn len(self, root: &json::Root) -> usize {
+        with_kind!(self, T => AsRef::<[T]>::as_ref(root).len())
+    }
+
+    /// Calls `visitor` with the indices contained within one object.
+    ///
+    /// Returns `false` if a required reference of the object was rejected.
+    pub(crate) fn visit<V: Visitor>(
+        self,
+        root: &mut json::Root,
+        index: usize,
+        visitor: &mut V,
+    ) -> bool {
+        with_kind!(self, T => AsMut::<Vec<T>>::as_mut(root)[index].visit(visitor))
+    }
+
+    /// Removes the objects of this kind whose entry in `keep` is `false`.
+    fn retain(self, root: &mut json::Root, keep: &[bool]) {
+        if self.len(root) == 0 {
+            return;
+        }
+        with_kind!(self, T => {
+            let mut flags = keep.iter().copied();
+            AsMut::<Vec<T>>::as_mut(root).retain(|_| flags.next().unwrap_or(true));
+        })
+    }
+
+    /// Moves the objects of this kind from `other` to the end of `root`.
+    fn append(self, root: &mut json::Root, other: &mut json::Root) {
+        if self.len(other) == 0 {
+            return;
+        }
+        with_kind!(self, T => {
+            let objects = AsMut::<Vec<T>>::as_mut(other);
+            AsMut::<Vec<T>>::as_mut(root).append(objects);
+        })
+    }
+}
+
+/// Rewrites indices from their old to their new positions.
+///
+/// Indices of kinds without a mapping are left unchanged. Indices that map to
+/// `None` are rejected.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Remap {
+    maps: HashMap<Kind, Vec<Option<u32>>>,
+}
+
+impl Remap {
+    /// Sets the new position of every object of the given kind.
+    pub(crate) fn insert(&mut self, kind: Kind, map: Vec<Option<u32>>) {
+        self.maps.insert(kind, map);
+    }
+}
+
+impl Visitor for Remap {
+    fn visit_index<T: 'static>(&mut self, index: &mut Index<T>) -> bool {
+        let map = match Kind::of::<T>().and_then(|kind| self.maps.get(&kind)) {
+            Some(map) => map,
+            None => return true,
+        };
+        match map.get(index.value()).copied().flatten() {
+            Some(value) => {
+                *index = Index::new(value);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Collects the indices visited, grouped by kind.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Collect {
+    pub(crate) found: Vec<(Kind, usize)>,
+}
+
+impl Visitor for Collect {
+    fn visit_index<T: 'static>(&mut self, index: &mut Index<T>) -> bool {
+        if let Some(kind) = Kind::of::<T>() {
+            self.found.push((kind, index.value()));
+        }
+        true
+    }
+}
+
+/// Rejects the indices of removed objects, leaving every index unchanged.
+struct Removed<'a> {
+    keep: &'a HashMap<Kind, Vec<bool>>,
+}
+
+impl Visitor for Removed<'_> {
+    fn visit_index<T: 'static>(&mut self, index: &mut Index<T>) -> bool {
+        Kind::of::<T>()
+            .and_then(|kind| self.keep.get(&kind))
+            .and_then(|flags| flags.get(index.value()).copied())
+            .unwrap_or(true)
+    }
+}
+
+/// Removes every object whose entry in `keep` is `false` and rewrites the
+/// remaining indices.
+///
+/// Objects with a required reference to a removed object, such as a texture
+/// whose image is removed, are removed as well. Other kinds missing from
+/// `keep` are left untouched.
+pub(crate) fn remove(root: &mut json::Root, keep: &HashMap<Kind, Vec<bool>>) {
+    let mut keep = keep.clone();
+    loop {
+        let mut cascade = Vec::new();
+        for &kind in Kind::ALL {
+            for index in 0..kind.len(root) {
+                let kept = keep.get(&kind).map_or(true, |flags| flags[index]);
+                if kept && !kind.visit(root, index, &mut Removed { keep: &keep }) {
+                    cascade.push((kind, index));
+                }
+            }
+        }
+        if cascade.is_empty() {
+            break;
+        }
+        for (kind, index) in cascade {
+            keep.entry(kind)
+                .or_insert_with(|| vec![true; kind.len(root)])[index] = false;
+        }
+    }
+
+    let mut remap = Remap::default();
+    for (&kind, flags) in &keep {
+        let mut next = 0;
+        let map = flags
+            .iter()
+            .map(|&flag| {
+                if flag {
+                    next += 1;
+                    Some(next - 1)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        remap.insert(kind, map);
+    }
+    root.visit(&mut remap);
+    for (&kind, flags) in &keep {
+        kind.retain(root, flags);
+    }
+}
+
+#[cfg(feature = "utils")]
+/// Replaces every object of the given kind with the equal object at the
+/// position in `canonical` and removes the objects no longer referenced.
+///
+/// `canonical[i]` must be less than or equal to `i`, with objects that are
+/// kept mapping to themselves.
+pub(crate) fn collapse(root: &mut json::Root, kind: Kind, canonical: &[usize]) {
+    let mut map: Vec<Option<u32>> = Vec::with_capacity(canonical.len());
+    let mut next = 0;
+    for (index, &target) in canonical.iter().enumerate() {
+        if target == index {
+            map.push(Some(next));
+            next += 1;
+        } else {
+            let value = map[target];
+            map.push(value);
+        }
+    }
+    let mut remap = Remap::default();
+    remap.insert(kind, map);
+    root.visit(&mut remap);
+    let keep: Vec<bool> = canonical
+        .iter()
+        .enumerate()
+        .map(|(index, &target)| target == index)
+        .collect();
+    kind.retain(root, &keep);
+}
+
+/// A byte range of a buffer referenced by a buffer view.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Range {
+    /// The index of the buffer view.
+    view: usize,
+
+    /// Whether the range holds the compressed data of the view, as defined
+    /// by the `EXT_meshopt_compression` extension.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    compressed: bool,
+}
+
+impl Range {
+    /// Returns `true` if the range holds compressed data.
+    #[cfg(feature = "import")]
+    pub(crate) fn is_compressed(self) -> bool {
+        #[cfg(feature = "EXT_meshopt_compression")]
+        return self.compressed;
+        #[cfg(not(feature = "EXT_meshopt_compression"))]
+        false
+    }
+
+    /// Returns the index of the buffer.
+    pub(crate) fn buffer(self, root: &json::Root) -> usize {
+        let view = &root.buffer_views[self.view];
+        #[cfg(feature = "EXT_meshopt_compression")]
+        if self.compressed {
+            return meshopt_compression(view).unwrap().buffer.value();
+        }
+        view.buffer.value()
+    }
+
+    /// Returns the offset and length of the range in bytes.
+    pub(crate) fn bounds(self, root: &json::Root) -> (usize, usize) {
+        let view = &root.buffer_views[self.view];
+        #[cfg(feature = "EXT_meshopt_compression")]
+        if self.compressed {
+            let compression = meshopt_compression(view).unwrap();
+            return (
+                compression.byte_offset.0 as usize,
+                compression.byte_length.0 as usize,
+            );
+        }
+        (
+            view.byte_offset.map_or(0, |offset| offset.0 as usize),
+            view.byte_length.0 as usize,
+        )
+    }
+
+    /// Moves the range to the given buffer and offset.
+    pub(crate) fn set(self, root: &mut json::Root, buffer: usize, offset: usize) {
+        let view = &mut root.buffer_views[self.view];
+        #[cfg(feature = "EXT_meshopt_compression")]
+        if self.compressed {
+            let compression = view
+                .extensions
+                .as_mut()
+                .and_then(|extensions| extensions.ext_meshopt_compression.as_mut())
+                .unwrap();
+            compression.buffer = Index::new(buffer as u32);
+            compression.byte_offset = USize64::from(offset);
+            return;
+        }
+        view.buffer = Index::new(buffer as u32);
+        view.byte_offset = Some(USize64::from(offset));
+    }
+}
+
+/// Returns every byte range referenced by the buffer views of `root`, in the
+/// order of the views.
+pub(crate) fn ranges(root: &json::Root) -> Vec<Range> {
+    let mut ranges = Vec::new();
+    for index in 0..root.buffer_views.len() {
+        ranges.push(Range {
+            view: index,
+            #[cfg(feature = "EXT_meshopt_compression")]
+            compressed: false,
+        });
+        #[cfg(feature = "EXT_meshopt_compression")]
+        if meshopt_compression(&root.buffer_views[index]).is_some() {
+            ranges.push(Range {
+                view: index,
+                compressed: true,
+            });
+        }
+    }
+    ranges
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+fn meshopt_compression(
+    view: &json::buffer::View,
+) -> Option<&json::extensions::buffer::ext_meshopt_compression::View> {
+    view.extensions.as_ref()?.ext_meshopt_compression.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remove_cascades_to_required_references() {
+        let mut root: json::Root = serde_json::from_str(
+            r#"{
+                "asset": {"version": "2.0"},
+                "images": [{"uri": "a.png"}, {"uri": "b.png"}],
+                "textures": [{"source": 1}, {"source": 0}],
+                "materials": [{"pbrMetallicRoughness": {"baseColorTexture": {"index": 1}}}]
+            }"#,
+        )
+        .unwrap();
+        let mut keep = HashMap::new();
+        keep.insert(Kind::Image, vec![false, true]);
+        remove(&mut root, &keep);
+
+        assert_eq!(root.images.len(), 1);
+        assert_eq!(root.textures.len(), 1);
+        assert_eq!(root.textures[0].source.value(), 0);
+        let pbr = &root.materials[0].pbr_metallic_roughness;
+        assert!(pbr.base_color_texture.is_none());
+    }
+}
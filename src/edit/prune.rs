@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "import")]
+use super::{ranges, Range};
+use super::{remove, Collect, Kind};
+
+#[cfg(feature = "import")]
+use crate::buffer;
+#[cfg(feature = "import")]
+use json::validation::USize64;
+
+/// Marks every object reachable from the scenes and animations of `root`.
+///
+/// When the document has no scenes, all nodes are considered reachable.
+/// Material variants and metadata packets are always kept.
+pub(crate) fn reachable(root: &mut json::Root) -> HashMap<Kind, Vec<bool>> {
+    let mut keep: HashMap<Kind, Vec<bool>> = Kind::ALL
+        .iter()
+        .map(|&kind| (kind, vec![false; kind.len(root)]))
+        .collect();
+
+    let mut stack = Vec::new();
+    let mut roots = vec![
+        Kind::Scene,
+        Kind::Animation,
+        #[cfg(feature = "KHR_materials_variants")]
+        Kind::Variant,
+        #[cfg(feature = "KHR_xmp_json_ld")]
+        Kind::Packet,
+    ];
+    if root.scenes.is_empty() {
+        roots.push(Kind::Node);
+    }
+    for kind in roots {
+        for index in 0..kind.len(root) {
+            stack.push((kind, index));
+        }
+    }
+
+    while let Some((kind, index)) = stack.pop() {
+        match keep.get_mut(&kind).and_then(|flags| flags.get_mut(index)) {
+            Some(flag) if !*flag => *flag = true,
+            _ => continue,
+        }
+        let mut collect = Collect::default();
+        kind.visit(root, index, &mut collect);
+        stack.extend(collect.found);
+    }
+
+    keep
+}
+
+/// Removes every object that is not reachable from a scene or an animation.
+///
+/// The indices of the remaining objects are rewritten to account for the
+/// objects removed before them. Nodes are only removed when the document has
+/// at least one scene, since a document without scenes is considered a
+/// library of nodes.
+///
+/// Buffer data is not modified; see [`prune_with_data`] to also drop the
+/// unreferenced parts of the buffers.
+pub fn prune(root: &mut json::Root) {
+    let keep = reachable(root);
+    remove(root, &keep);
+}
+
+/// Removes every object that is not reachable from a scene or an animation,
+/// along with the buffer data that is no longer referenced.
+///
+/// `buffers` must contain the data of every buffer in `root`, as returned by
+/// [`import_buffers`](crate::import_buffers). The data of removed buffers is
+/// removed and byte ranges not covered by any remaining buffer view are cut
+/// out of the others, with the compressed data of the
+/// `EXT_meshopt_compression` extension counting as covered. Buffer views keep
+/// their offset modulo four so that accessor alignment is preserved.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub fn prune_with_data(root: &mut json::Root, buffers: &mut Vec<buffer::Data>) {
+    let keep = reachable(root);
+    let mut flags = keep[&Kind::Buffer].iter().copied();
+    buffers.retain(|_| flags.next().unwrap_or(true));
+    remove(root, &keep);
+    compact(root, buffers);
+}
+
+/// Cuts the byte ranges not covered by any buffer view out of `buffers`.
+///
+/// Buffer views keep their offset modulo four so that accessor alignment is
+/// preserved.
+#[cfg(feature = "import")]
+pub(crate) fn compact(root: &mut json::Root, buffers: &mut [buffer::Data]) {
+    for (index, data) in buffers.iter_mut().enumerate() {
+        let mut ranges: Vec<Range> = ranges(root)
+            .into_iter()
+            .filter(|range| range.buffer(root) == index)
+            .collect();
+        ranges.sort_by_key(|&range| range.bounds(root).0);
+
+        let mut output = Vec::with_capacity(data.len());
+        // The end of the current run of overlapping ranges in the old buffer
+        // and the distance the run was moved by.
+        let mut run: Option<usize> = None;
+        let mut delta = 0;
+        for range in ranges {
+            let (offset, length) = range.bounds(root);
+            let start = offset.min(data.len());
+            let end = (start + length).min(data.len());
+            match run {
+                Some(run_end) if start <= run_end => {
+                    if end > run_end {
+                        output.extend_from_slice(&data[run_end..end]);
+                        run = Some(end);
+                    }
+                }
+                _ => {
+                    while output.len() % 4 != start % 4 {
+                        output.push(0);
+                    }
+                    delta = start - output.len();
+                    output.extend_from_slice(&data[start..end]);
+                    run = Some(end);
+                }
+            }
+            range.set(root, index, start - delta);
+        }
+
+        root.buffers[index].byte_length = USize64::from(output.len());
+        while output.len() % 4 != 0 {
+            output.push(0);
+        }
+        data.0 = output;
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use super::{ranges, remove, Kind, Range};
+use crate::buffer;
+use json::buffer::Target;
+use json::validation::{Checked, USize64};
+
+/// Returns the alignment required for the start of every buffer view.
+///
+/// A view must be aligned to the component size of the accessors that use it,
+/// and to four bytes if it holds vertex attributes.
+fn view_alignments(root: &json::Root) -> Vec<usize> {
+    let mut alignments: Vec<usize> = root
+        .buffer_views
+        .iter()
+        .map(|view| {
+            let vertex = view.byte_stride.is_some()
+                || view.target == Some(Checked::Valid(Target::ArrayBuffer));
+            if vertex {
+                4
+            } else {
+                1
+            }
+        })
+        .collect();
+    let mut require = |view: json::Index<json::buffer::View>, size: usize| {
+        if let Some(alignment) = alignments.get_mut(view.value()) {
+            *alignment = (*alignment).max(size);
+        }
+    };
+    for accessor in &root.accessors {
+        let size = match accessor.component_type {
+            Checked::Valid(component_type) => component_type.0.size(),
+            Checked::Invalid => 4,
+        };
+        if let Some(view) = accessor.buffer_view {
+            require(view, size);
+        }
+        if let Some(sparse) = accessor.sparse.as_ref() {
+            require(sparse.values.buffer_view, size);
+            let index_size = match sparse.indices.component_type {
+                Checked::Valid(component_type) => component_type.0.size(),
+                Checked::Invalid => 4,
+            };
+            require(sparse.indices.buffer_view, index_size);
+        }
+    }
+    alignments
+}
+
+/// Rewrites the contents of every buffer so that its buffer views are stored
+/// contiguously, in order, without gaps other than alignment padding.
+///
+/// Each view is aligned to the component size of the accessors that use it,
+/// and views of vertex attributes are aligned to four bytes. Buffer data not
+/// covered by any view is dropped, and data shared by several views is
+/// written once for each view. Buffers without any views are left as they
+/// are.
+///
+/// When `merge` is `true`, the views of all buffers are written to the first
+/// buffer and the other buffers are removed.
+///
+/// `buffers` must contain the data of every buffer in `root`, as returned by
+/// [`import_buffers`](crate::import_buffers).
+pub fn repack(root: &mut json::Root, buffers: &mut Vec<buffer::Data>, merge: bool) {
+    if merge && root.buffers.len() > 1 {
+        let mut outputs = vec![Vec::new()];
+        write_views(root, buffers, &mut outputs, |_, _| 0);
+        let mut keep = HashMap::new();
+        let mut flags = vec![false; root.buffers.len()];
+        flags[0] = true;
+        keep.insert(Kind::Buffer, flags);
+        remove(root, &keep);
+        buffers.truncate(1);
+        finish(root, buffers, outputs);
+    } else {
+        let mut outputs = vec![Vec::new(); root.buffers.len()];
+        write_views(root, buffers, &mut outputs, |root, range| {
+            range.buffer(root)
+        });
+        finish(root, buffers, outputs);
+    }
+}
+
+/// Appends the data of every view to the output chosen by `output_index`
+/// and moves the views accordingly.
+///
+/// Compressed data of the `EXT_meshopt_compression` extension is written
+/// after the view it belongs to and aligned to four bytes.
+fn write_views<F>(
+    root: &mut json::Root,
+    buffers: &[buffer::Data],
+    outputs: &mut [Vec<u8>],
+    output_index: F,
+) where
+    F: Fn(&json::Root, Range) -> usize,
+{
+    let alignments = view_alignments(root);
+    for range in ranges(root) {
+        let alignment = if range.is_compressed() {
+            4
+        } else {
+            alignments[range.view]
+        };
+        let buffer = range.buffer(root);
+        let (start, length) = range.bounds(root);
+        let data = buffers.get(buffer).map_or(&[][..], |data| &data[..]);
+        let end = start + length;
+        let bytes = data
+            .get(start.min(data.len())..end.min(data.len()))
+            .unwrap_or(&[]);
+
+        let index = output_index(root, range);
+        let output = &mut outputs[index];
+        while output.len() % alignment != 0 {
+            output.push(0);
+        }
+        let offset = output.len();
+        output.extend_from_slice(bytes);
+        output.resize(offset + length, 0);
+        range.set(root, index, offset);
+    }
+}
+
+/// Replaces the buffer data with the non-empty outputs.
+fn finish(root: &mut json::Root, buffers: &mut [buffer::Data], outputs: Vec<Vec<u8>>) {
+    for (index, mut output) in outputs.into_iter().enumerate() {
+        if output.is_empty() {
+            continue;
+        }
+        root.buffers[index].byte_length = USize64::from(output.len());
+        while output.len() % 4 != 0 {
+            output.push(0);
+        }
+        if let Some(data) = buffers.get_mut(index) {
+            data.0 = output;
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::khr_materials_variants::VariantRef;
+use crate::{Error, Result};
+use json::validation::Error as ValidationError;
+use json::{Index, Path};
+
+const EXTENSION: &str = "KHR_materials_variants";
+
+/// Assigns the material mapped to a variant to every primitive of `root`.
+///
+/// Primitives without a mapping for the variant keep their material. When
+/// `strip` is `true` the `KHR_materials_variants` extension is removed from
+/// the document, its primitives and the list of used extensions. Materials
+/// that are no longer referenced afterwards can be removed with
+/// [`prune`](super::prune).
+///
+/// Returns an error if the document has no such variant.
+///
+/// ```
+/// # fn run(root: &mut gltf::json::Root) -> gltf::Result<()> {
+/// gltf::edit::apply_variant(root, "midnight", true)?;
+/// gltf::edit::prune(root);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+pub fn apply_variant<'s>(
+    root: &mut json::Root,
+    variant: impl Into<VariantRef<'s>>,
+    strip: bool,
+) -> Result<()> {
+    let variant = variant.into();
+    let index = match variant.resolve(root) {
+        Some(index) => index as u32,
+        None => {
+            let path = Path::new()
+                .field("extensions")
+                .key(EXTENSION)
+                .field("variants");
+            let (path, error) = match variant {
+                VariantRef::Index(index) => (path.index(index), ValidationError::IndexOutOfBounds),
+                VariantRef::Name(name) => (path.value_str(name), ValidationError::Missing),
+            };
+            return Err(Error::Validation(vec![(path, error)]));
+        }
+    };
+
+    for mesh in &mut root.meshes {
+        for primitive in &mut mesh.primitives {
+            let extensions = match primitive.extensions.as_mut() {
+                Some(extensions) => extensions,
+                None => continue,
+            };
+            if let Some(variants) = extensions.khr_materials_variants.as_ref() {
+                let mapping = variants
+                    .mappings
+                    .iter()
+                    .find(|mapping| mapping.variants.contains(&index));
+                if let Some(mapping) = mapping {
+                    primitive.material = Some(Index::new(mapping.material));
+                }
+            }
+            if strip {
+                extensions.khr_materials_variants = None;
+            }
+        }
+    }
+
+    if strip {
+        if let Some(extensions) = root.extensions.as_mut() {
+            extensions.khr_materials_variants = None;
+        }
+        root.extensions_used.retain(|name| name != EXTENSION);
+        root.extensions_required.retain(|name| name != EXTENSION);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,526 @@
+use crate::{binary, buffer, image};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::Path;
+use std::{fs, io, mem, ops};
+
+use crate::edit::{self, Collect, Kind};
+use crate::import::{import_buffers_with_resolver, DefaultResolver, ResourceResolver};
+use crate::{Document, Error, Gltf, Result};
+use image_crate::ImageFormat;
+use json::validation::USize64;
+use json::visit::Visit;
+
+/// Encoded image data that is yet to be placed in the exported asset.
+struct EncodedImage {
+    /// The encoded image bytes.
+    pub data: Vec<u8>,
+
+    /// The image data MIME type.
+    pub mime_type: String,
+}
+
+fn align_to_multiple_of_four(n: &mut usize) {
+    *n = (*n + 3) & !3;
+}
+
+/// Returns the file extension conventionally used for the given MIME type.
+fn file_extension(mime_type: &str) -> &str {
+    match mime_type {
+        "image/jpeg" => "jpg",
+        "image/png" => "png",
+        "image/webp" => "webp",
+        "image/ktx2" => "ktx2",
+        "image/vnd-ms.dds" => "dds",
+        "image/vnd.radiance" => "hdr",
+        _ => "bin",
+    }
+}
+
+/// Returns `true` if the buffer only holds data for loaders that do not
+/// support the `EXT_meshopt_compression` extension.
+///
+/// Such buffers are left without data in the exported asset.
+fn is_meshopt_fallback(buffer: &json::Buffer) -> bool {
+    #[cfg(feature = "EXT_meshopt_compression")]
+    return buffer
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.ext_meshopt_compression.as_ref())
+        .map_or(false, |extension| extension.fallback);
+    #[cfg(not(feature = "EXT_meshopt_compression"))]
+    {
+        let _ = buffer;
+        false
+    }
+}
+
+/// Returns the first `byte_length` bytes of the given buffer data.
+fn buffer_contents(index: usize, byte_length: usize, data: &[u8]) -> Result<&[u8]> {
+    if data.len() < byte_length {
+        return Err(Error::BufferLength {
+            buffer: index,
+            expected: byte_length,
+            actual: data.len(),
+        });
+    }
+    Ok(&data[..byte_length])
+}
+
+/// Writes a document as standard glTF to `path`, storing every buffer and
+/// every image in `images` as a separate file in the same directory.
+///
+/// Buffers and images are named after the stem of `path` with distinct
+/// prefixes, so that an image of an unknown type never overwrites a buffer.
+fn write_separate<B>(
+    path: &Path,
+    mut root: json::Root,
+    buffers: &[B],
+    images: Vec<Option<EncodedImage>>,
+) -> Result<()>
+where
+    B: ops::Deref<Target = [u8]>,
+{
+    let base = path.parent().unwrap_or_else(|| Path::new("./"));
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("buffer");
+
+    for (index, buffer) in root.buffers.iter_mut().enumerate() {
+        if is_meshopt_fallback(buffer) {
+            continue;
+        }
+        let data = buffers.get(index).map_or(&[][..], |data| &**data);
+        let contents = buffer_contents(index, buffer.byte_length.0 as usize, data)?;
+        let uri = format!("{}_buffer{}.bin", stem, index);
+        fs::write(base.join(&uri), contents)?;
+        buffer.uri = Some(uri);
+    }
+
+    for (index, encoded) in images.into_iter().enumerate() {
+        if let Some(encoded) = encoded {
+            let image = &mut root.images[index];
+            let extension = file_extension(&encoded.mime_type);
+            let uri = format!("{}_image{}.{}", stem, index, extension);
+            fs::write(base.join(&uri), &encoded.data)?;
+            image.buffer_view = None;
+            image.mime_type = Some(json::image::MimeType(encoded.mime_type));
+            image.uri = Some(uri);
+        }
+    }
+
+    let file = fs::File::create(path)?;
+    root.to_writer_pretty(io::BufWriter::new(file))?;
+    Ok(())
+}
+
+/// Encodes a document as binary glTF, moving every buffer and every image in
+/// `images` into the `BIN` chunk.
+///
+/// Fallback buffers of the `EXT_meshopt_compression` extension are kept as
+/// separate buffers without data, after the buffer of the `BIN` chunk.
+fn to_binary<B>(
+    mut root: json::Root,
+    buffers: &[B],
+    images: Vec<Option<EncodedImage>>,
+) -> Result<Vec<u8>>
+where
+    B: ops::Deref<Target = [u8]>,
+{
+    let mut blob = Vec::new();
+    // The new index and the offset of every buffer.
+    let mut placements = Vec::with_capacity(root.buffers.len());
+    let mut fallbacks = Vec::new();
+    for (index, buffer) in root.buffers.iter().enumerate() {
+        if is_meshopt_fallback(buffer) {
+            fallbacks.push(index);
+            placements.push((fallbacks.len(), 0));
+            continue;
+        }
+        let data = buffers.get(index).map_or(&[][..], |data| &**data);
+        let contents = buffer_contents(index, buffer.byte_length.0 as usize, data)?;
+        placements.push((0, blob.len()));
+        blob.extend_from_slice(contents);
+        while blob.len() % 4 != 0 {
+            blob.push(0);
+        }
+    }
+
+    for range in edit::ranges(&root) {
+        let (buffer, offset) = placements[range.buffer(&root)];
+        let (start, _) = range.bounds(&root);
+        range.set(&mut root, buffer, offset + start);
+    }
+
+    for (index, encoded) in images.into_iter().enumerate() {
+        if let Some(encoded) = encoded {
+            let view = json::buffer::View {
+                buffer: json::Index::new(0),
+                byte_length: USize64::from(encoded.data.len()),
+                byte_offset: Some(USize64::from(blob.len())),
+                byte_stride: None,
+                #[cfg(feature = "names")]
+                name: None,
+                target: None,
+                extensions: None,
+                extras: Default::default(),
+            };
+            blob.extend_from_slice(&encoded.data);
+            while blob.len() % 4 != 0 {
+                blob.push(0);
+            }
+            let view = root.push(view);
+            let image = &mut root.images[index];
+            image.buffer_view = Some(view);
+            image.mime_type = Some(json::image::MimeType(encoded.mime_type));
+            image.uri = None;
+        }
+    }
+
+    let mut fallbacks = fallbacks.into_iter().peekable();
+    let mut merged = Vec::new();
+    let mut kept = Vec::new();
+    for (index, buffer) in root.buffers.drain(..).enumerate() {
+        if fallbacks.next_if_eq(&index).is_some() {
+            kept.push(buffer);
+        } else {
+            merged.push(buffer);
+        }
+    }
+    if !(blob.is_empty() && root.buffer_views.is_empty() && kept.is_empty()) {
+        let mut buffer = match merged.into_iter().next() {
+            Some(buffer) => buffer,
+            None => json::Buffer {
+                byte_length: USize64(0),
+                #[cfg(feature = "names")]
+                name: None,
+                uri: None,
+                extensions: None,
+                extras: Default::default(),
+            },
+        };
+        buffer.byte_length = USize64::from(blob.len());
+        buffer.uri = None;
+        root.buffers.push(buffer);
+        root.buffers.extend(kept);
+    }
+
+    let json = root.to_vec()?;
+    let mut length = mem::size_of::<binary::Header>() + 8 + json.len();
+    align_to_multiple_of_four(&mut length);
+    if !blob.is_empty() {
+        length += 8 + blob.len();
+    }
+    let glb = binary::Glb {
+        header: binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            // N.B., the size of binary glTF file is limited to range of `u32`.
+            length: u32::try_from(length).map_err(|_| Error::GlbLength { length })?,
+        },
+        json: Cow::Owned(json),
+        bin: if blob.is_empty() {
+            None
+        } else {
+            Some(Cow::Owned(blob))
+        },
+    };
+    glb.to_vec()
+}
+
+/// Export glTF 2.0 to the file system.
+///
+/// This is the counterpart of [`import`](crate::import). If `path` has the
+/// `.glb` extension then binary glTF is written with all buffers merged into
+/// the `BIN` chunk. Otherwise standard glTF is written along with one
+/// `{stem}_buffer{index}.bin` file per buffer and one `{stem}_image{index}`
+/// file per image, placed in the same directory as `path`.
+///
+/// `buffers` must be provided in document order, as returned by `import`.
+/// Images that refer to a URI are read from `base`, the directory relative
+/// URIs are resolved against, and copied without decoding to a separate file,
+/// or embedded in the `BIN` chunk in the case of binary glTF. Images that
+/// refer to a buffer view are left as they are.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// # use std::path::Path;
+/// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+/// # let dir = std::env::temp_dir();
+/// # let path = dir.join("gltf-export-doctest.glb");
+/// gltf::export(&path, &document, &buffers, Some(Path::new("examples")))?;
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn export<P>(
+    path: P,
+    document: &Document,
+    buffers: &[buffer::Data],
+    base: Option<&Path>,
+) -> Result<()>
+where
+    P: AsRef<Path>,
+{
+    export_with_resolver(path, document, buffers, &DefaultResolver::new(base))
+}
+
+/// Export glTF 2.0 to the file system, loading the images that refer to a
+/// URI with the given `resolver`.
+///
+/// See [`export`] for the layout of the output.
+pub fn export_with_resolver<P>(
+    path: P,
+    document: &Document,
+    buffers: &[buffer::Data],
+    resolver: &dyn ResourceResolver,
+) -> Result<()>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let images = read_uri_images(document, resolver, buffers)?;
+    let root = document.as_json().clone();
+    let binary = path
+        .extension()
+        .map_or(false, |ext| ext.eq_ignore_ascii_case("glb"));
+    if binary {
+        let glb = to_binary(root, buffers, images)?;
+        fs::write(path, glb)?;
+        Ok(())
+    } else {
+        write_separate(path, root, buffers, images)
+    }
+}
+
+/// Returns the MIME type of an image referred to by URI.
+fn uri_mime_type(uri: &str, mime_type: Option<&str>, encoded_image: &[u8]) -> Result<String> {
+    if let Some(mime_type) = mime_type {
+        return Ok(mime_type.to_string());
+    }
+    if let Some(rest) = uri.strip_prefix("data:") {
+        match rest.split(";base64,").next() {
+            Some(media_type) if media_type.starts_with("image/") => {
+                return Ok(media_type.to_string())
+            }
+            _ => {}
+        }
+    }
+    let mime_type = match uri.rsplit('.').next() {
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("webp") => "image/webp",
+        Some("ktx2") => "image/ktx2",
+        Some("dds") => "image/vnd-ms.dds",
+        _ => match image_crate::guess_format(encoded_image) {
+            Ok(ImageFormat::Png) => "image/png",
+            Ok(ImageFormat::Jpeg) => "image/jpeg",
+            Ok(ImageFormat::WebP) => "image/webp",
+            _ => return Err(Error::UnsupportedImageEncoding),
+        },
+    };
+    Ok(mime_type.to_string())
+}
+
+/// Reads the encoded data of an image without decoding it.
+fn read_encoded_image(
+    image: crate::Image<'_>,
+    resolver: &dyn ResourceResolver,
+    buffer_data: &[buffer::Data],
+) -> Result<EncodedImage> {
+    match image.source() {
+        image::Source::View { view, mime_type } => {
+            let buffer = view.buffer().index();
+            let parent_buffer_data = buffer_data.get(buffer).map_or(&[][..], |data| &data[..]);
+            let begin = view.offset();
+            let end = begin + view.length();
+            let data = parent_buffer_data
+                .get(begin..end)
+                .ok_or(Error::BufferLength {
+                    buffer,
+                    expected: end,
+                    actual: parent_buffer_data.len(),
+                })?;
+            Ok(EncodedImage {
+                data: data.to_vec(),
+                mime_type: mime_type.to_string(),
+            })
+        }
+        image::Source::Uri { uri, mime_type } => {
+            let data = resolver.resolve(uri)?;
+            let mime_type = uri_mime_type(uri, mime_type, &data)?;
+            Ok(EncodedImage { data, mime_type })
+        }
+    }
+}
+
+/// Reads the encoded data of every image that refers to a URI.
+fn read_uri_images(
+    document: &Document,
+    resolver: &dyn ResourceResolver,
+    buffer_data: &[buffer::Data],
+) -> Result<Vec<Option<EncodedImage>>> {
+    let mut images = Vec::new();
+    for image in document.images() {
+        let encoded = match image.source() {
+            image::Source::View { .. } => None,
+            image::Source::Uri { .. } => Some(read_encoded_image(image, resolver, buffer_data)?),
+        };
+        images.push(encoded);
+    }
+    Ok(images)
+}
+
+fn pack_impl(
+    Gltf { document, mut blob }: Gltf,
+    resolver: &dyn ResourceResolver,
+) -> Result<Vec<u8>> {
+    let mut buffers = Vec::new();
+    for buffer in document.buffers() {
+        #[cfg(feature = "EXT_meshopt_compression")]
+        if buffer.is_meshopt_fallback() {
+            buffers.push(buffer::Data(Vec::new()));
+            continue;
+        }
+        let data = buffer::Data::from_source_with_resolver(buffer.source(), resolver, &mut blob)?;
+        buffers.push(data);
+    }
+    let images = read_uri_images(&document, resolver, &buffers)?;
+    to_binary(document.into_json(), &buffers, images)
+}
+
+/// Pack glTF 2.0 and all of its external resources into a single binary glTF
+/// file.
+///
+/// All buffers are merged into the `BIN` chunk, except for the fallback
+/// buffers of the `EXT_meshopt_compression` extension, which are left without
+/// data. Images that refer to a URI are stored in the `BIN` chunk as they are,
+/// without decoding, and are referred to by buffer view and MIME type instead.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// # let path = std::env::temp_dir().join("gltf-pack-doctest.glb");
+/// gltf::pack("examples/Box.gltf", &path)?;
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn pack<P, Q>(source: P, destination: Q) -> Result<()>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let source = source.as_ref();
+    let base = source.parent().unwrap_or_else(|| Path::new("./"));
+    let glb = pack_impl(Gltf::open(source)?, &DefaultResolver::new(Some(base)))?;
+    fs::write(destination, glb)?;
+    Ok(())
+}
+
+/// Pack glTF 2.0 from a slice into binary glTF, loading external references
+/// with the given `resolver`.
+///
+/// ### Note
+///
+/// This function is intended for advanced users.
+/// A typical user should call [`pack`] instead.
+pub fn pack_slice<S>(slice: S, resolver: &dyn ResourceResolver) -> Result<Vec<u8>>
+where
+    S: AsRef<[u8]>,
+{
+    pack_impl(Gltf::from_slice(slice.as_ref())?, resolver)
+}
+
+/// Unpack binary glTF 2.0 into standard glTF with separate files.
+///
+/// The `BIN` chunk and any other buffers are written as `.bin` files, and
+/// every image is written to a file of its own, next to `destination`. The
+/// buffer views that held embedded image data are removed, along with their
+/// bytes, unless they are referred to by other objects.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// # let dir = std::env::temp_dir().join("gltf-unpack-doctest");
+/// # std::fs::create_dir_all(&dir)?;
+/// # let path = dir.join("Box.gltf");
+/// gltf::unpack("examples/Box.glb", &path)?;
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn unpack<P, Q>(source: P, destination: Q) -> Result<()>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let source = source.as_ref();
+    let base = source.parent().unwrap_or_else(|| Path::new("./"));
+    let resolver = DefaultResolver::new(Some(base));
+    let Gltf { document, blob } = Gltf::open(source)?;
+    let mut buffers = import_buffers_with_resolver(&document, &resolver, blob)?;
+    let mut images = Vec::new();
+    for image in document.images() {
+        images.push(Some(read_encoded_image(image, &resolver, &buffers)?));
+    }
+    let mut root = document.into_json();
+    remove_image_views(&mut root, &mut buffers);
+    write_separate(destination.as_ref(), root, &buffers, images)
+}
+
+/// Detaches every image from its buffer view and removes the views no longer
+/// referred to, along with the buffers and the buffer data they covered.
+fn remove_image_views(root: &mut json::Root, buffers: &mut Vec<buffer::Data>) {
+    let mut views = vec![true; root.buffer_views.len()];
+    for image in root.images.iter_mut() {
+        if let Some(view) = image.buffer_view.take() {
+            views[view.value()] = false;
+        }
+    }
+    for (kind, index) in references(root) {
+        if kind == Kind::View {
+            views[index] = true;
+        }
+    }
+    if views.iter().all(|&flag| flag) {
+        return;
+    }
+
+    // Buffers that are only referred to by the removed views are removed too.
+    let mut keep_buffers = vec![true; root.buffers.len()];
+    for (view, _) in root
+        .buffer_views
+        .iter()
+        .zip(&views)
+        .filter(|(_, &flag)| !flag)
+    {
+        keep_buffers[view.buffer.value()] = false;
+    }
+    let mut keep = HashMap::new();
+    keep.insert(Kind::View, views);
+    edit::remove(root, &keep);
+    for (kind, index) in references(root) {
+        if kind == Kind::Buffer {
+            keep_buffers[index] = true;
+        }
+    }
+    let mut flags = keep_buffers.iter().copied();
+    buffers.retain(|_| flags.next().unwrap_or(true));
+    let mut keep = HashMap::new();
+    keep.insert(Kind::Buffer, keep_buffers);
+    edit::remove(root, &keep);
+    edit::prune::compact(root, buffers);
+}
+
+/// Returns every index referred to in `root`.
+fn references(root: &mut json::Root) -> Vec<(Kind, usize)> {
+    let mut collect = Collect::default();
+    root.visit(&mut collect);
+    collect.found
+}
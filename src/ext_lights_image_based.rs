@@ -0,0 +1,125 @@
+use crate::{Document, Image};
+
+pub use json::extensions::scene::ext_lights_image_based::IRRADIANCE_COEFFICIENTS;
+
+/// An environment light defined by a prefiltered specular cubemap and
+/// spherical harmonics irradiance coefficients.
+#[derive(Clone, Debug)]
+pub struct Light<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::ext_lights_image_based::Light,
+}
+
+/// An `Iterator` that visits the mip levels of a specular cubemap, yielding
+/// the six faces of each level in the order +X, -X, +Y, -Y, +Z, -Z.
+#[derive(Clone, Debug)]
+pub struct SpecularImages<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal mip level iterator.
+    iter: std::slice::Iter<'a, [json::Index<json::Image>; 6]>,
+}
+
+impl<'a> Light<'a> {
+    /// Constructs a `Light`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::scene::ext_lights_image_based::Light,
+    ) -> Self {
+        Self {
+            document,
+            index,
+            json,
+        }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns the quaternion that rotates the environment, in XYZW order.
+    ///
+    /// The default value is the identity `[0.0, 0.0, 0.0, 1.0]`.
+    pub fn rotation(&self) -> [f32; 4] {
+        self.json.rotation
+    }
+
+    /// Returns the brightness multiplier of the environment.
+    ///
+    /// The default value is `1.0`.
+    pub fn intensity(&self) -> f32 {
+        self.json.intensity
+    }
+
+    /// Returns the RGB coefficients of the third order spherical harmonics
+    /// approximation of the irradiance, of which there are
+    /// [`IRRADIANCE_COEFFICIENTS`] in a valid document.
+    pub fn irradiance_coefficients(&self) -> &'a [[f32; 3]] {
+        &self.json.irradiance_coefficients
+    }
+
+    /// Returns the size in pixels of the faces of the largest mip level of
+    /// the specular cubemap.
+    pub fn specular_image_size(&self) -> u32 {
+        self.json.specular_image_size
+    }
+
+    /// Returns the size in pixels of the faces of the given mip level of the
+    /// specular cubemap.
+    pub fn specular_image_size_at(&self, level: usize) -> u32 {
+        self.json
+            .specular_image_size
+            .checked_shr(level as u32)
+            .unwrap_or(0)
+            .max(1)
+    }
+
+    /// Returns the number of mip levels of the specular cubemap.
+    pub fn mip_count(&self) -> usize {
+        self.json.specular_images.len()
+    }
+
+    /// Returns an `Iterator` that visits the mip levels of the specular
+    /// cubemap, starting from the largest.
+    pub fn specular_images(&self) -> SpecularImages<'a> {
+        SpecularImages {
+            document: self.document,
+            iter: self.json.specular_images.iter(),
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> ExactSizeIterator for SpecularImages<'a> {}
+impl<'a> Iterator for SpecularImages<'a> {
+    type Item = [Image<'a>; 6];
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .next()
+            .map(|faces| faces.map(|index| document.images().nth(index.value()).unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
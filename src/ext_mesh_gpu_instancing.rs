@@ -0,0 +1,161 @@
+use crate::{Accessor, Document};
+
+#[cfg(feature = "utils")]
+use crate::mesh::util::{quantized, ReadQuantized};
+#[cfg(feature = "utils")]
+use crate::Buffer;
+
+pub use json::extensions::scene::ext_mesh_gpu_instancing::{ROTATION, SCALE, TRANSLATION};
+
+/// XYZ instance translations of type `[f32; 3]`.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+pub type ReadTranslations<'a> = quantized::CastingIter<'a, 3>;
+
+/// XYZW instance rotation quaternions of type `[f32; 4]`.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+pub type ReadRotations<'a> = quantized::CastingIter<'a, 4>;
+
+/// XYZ instance scales of type `[f32; 3]`.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+pub type ReadScales<'a> = quantized::CastingIter<'a, 3>;
+
+/// The per-instance attributes of a node whose mesh is instanced.
+#[derive(Clone, Debug)]
+pub struct Instancing<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::ext_mesh_gpu_instancing::MeshGpuInstancing,
+}
+
+/// An `Iterator` that visits the per-instance attributes of a node.
+#[derive(Clone, Debug)]
+pub struct Attributes<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal attribute iterator.
+    iter: std::collections::btree_map::Iter<'a, String, json::Index<json::Accessor>>,
+}
+
+/// Instance attribute reader.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+#[derive(Clone, Debug)]
+pub struct Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    instancing: Instancing<'a>,
+    get_buffer_data: F,
+}
+
+impl<'a> Instancing<'a> {
+    /// Constructs an `Instancing`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::scene::ext_mesh_gpu_instancing::MeshGpuInstancing,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the number of instances, which is the count shared by the
+    /// accessors of all attributes.
+    pub fn count(&self) -> usize {
+        self.attributes()
+            .next()
+            .map_or(0, |(_, accessor)| accessor.count())
+    }
+
+    /// Returns the accessor of the attribute with the given name.
+    pub fn get(&self, name: &str) -> Option<Accessor<'a>> {
+        self.json
+            .attributes
+            .get(name)
+            .map(|index| self.document.accessors().nth(index.value()).unwrap())
+    }
+
+    /// Returns an `Iterator` that visits the names of the attributes and
+    /// their accessors.
+    pub fn attributes(&self) -> Attributes<'a> {
+        Attributes {
+            document: self.document,
+            iter: self.json.attributes.iter(),
+        }
+    }
+
+    /// Constructs the instance attribute reader.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader<'s, F>(&self, get_buffer_data: F) -> Reader<'a, 's, F>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Reader {
+            instancing: self.clone(),
+            get_buffer_data,
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> ExactSizeIterator for Attributes<'a> {}
+impl<'a> Iterator for Attributes<'a> {
+    type Item = (&'a str, Accessor<'a>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next().map(|(name, index)| {
+            let accessor = document.accessors().nth(index.value()).unwrap();
+            (name.as_str(), accessor)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(feature = "utils")]
+impl<'a, 's, F> Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    /// Visits the instance translations.
+    pub fn read_translations(&self) -> Option<ReadTranslations<'s>> {
+        self.read_f32(TRANSLATION)
+    }
+
+    /// Visits the instance rotations.
+    pub fn read_rotations(&self) -> Option<ReadRotations<'s>> {
+        self.read_f32(ROTATION)
+    }
+
+    /// Visits the instance scales.
+    pub fn read_scales(&self) -> Option<ReadScales<'s>> {
+        self.read_f32(SCALE)
+    }
+
+    /// Visits the values of an attribute of `N` components, such as an
+    /// application specific attribute, converted into `f32`.
+    ///
+    /// Returns `None` if the attribute is missing, does not have `N`
+    /// components or has unsigned 32-bit integer components.
+    pub fn read_f32<const N: usize>(&self, name: &str) -> Option<quantized::CastingIter<'s, N>> {
+        self.read_quantized(name).map(ReadQuantized::into_f32)
+    }
+
+    /// Visits the values of an attribute of `N` components with their
+    /// original component type.
+    pub fn read_quantized<const N: usize>(&self, name: &str) -> Option<ReadQuantized<'s, N>> {
+        let accessor = self.instancing.get(name)?;
+        quantized::read(accessor, self.get_buffer_data.clone())
+    }
+}
@@ -0,0 +1,567 @@
+use std::fmt;
+
+use crate::{buffer, Document};
+
+pub use json::extensions::buffer::ext_meshopt_compression::{Filter, Mode};
+
+/// The header byte of vertex attribute data.
+const VERTEX_HEADER: u8 = 0xa0;
+
+/// The header byte of triangle list indices.
+const TRIANGLE_HEADER: u8 = 0xe0;
+
+/// The header byte of index sequences.
+const SEQUENCE_HEADER: u8 = 0xd0;
+
+/// The number of bytes in a group of vertex attribute bytes.
+const BYTE_GROUP_SIZE: usize = 16;
+
+/// The largest number of bytes read when decoding a group of vertex
+/// attribute bytes.
+const BYTE_GROUP_DECODE_LIMIT: usize = 24;
+
+/// The smallest size of the tail of vertex attribute data.
+const VERTEX_TAIL_MIN_SIZE: usize = 32;
+
+/// The size of the table of auxiliary triangle codes.
+const TRIANGLE_TABLE_SIZE: usize = 16;
+
+/// The size of the tail of index sequences.
+const SEQUENCE_TAIL_SIZE: usize = 4;
+
+/// An error that occurred while decoding compressed data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The data does not start with the header of the compression mode.
+    Header(u8),
+
+    /// The data was encoded with an unsupported version of the codec.
+    Version(u8),
+
+    /// The byte stride or element count is not supported by the
+    /// compression mode or filter.
+    Parameters,
+
+    /// The data ends before all elements have been decoded.
+    UnexpectedEnd,
+
+    /// The data continues after all elements have been decoded.
+    TrailingData,
+
+    /// The decompressed data does not fit in its buffer view.
+    Length,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Header(header) => write!(f, "unexpected header byte {:#04x}", header),
+            Error::Version(version) => write!(f, "unsupported codec version {}", version),
+            Error::Parameters => write!(f, "unsupported byte stride or count"),
+            Error::UnexpectedEnd => write!(f, "unexpected end of compressed data"),
+            Error::TrailingData => write!(f, "trailing bytes after compressed data"),
+            Error::Length => write!(f, "decompressed data exceeds buffer view"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Compressed data of a buffer view.
+#[derive(Clone, Debug)]
+pub struct Compression<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::buffer::ext_meshopt_compression::View,
+}
+
+impl<'a> Compression<'a> {
+    /// Constructs a `Compression`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::buffer::ext_meshopt_compression::View,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the buffer holding the compressed data.
+    pub fn buffer(&self) -> buffer::Buffer<'a> {
+        self.try_buffer().unwrap()
+    }
+
+    /// Returns the buffer holding the compressed data, or `None` if the index
+    /// is out of bounds.
+    pub(crate) fn try_buffer(&self) -> Option<buffer::Buffer<'a>> {
+        self.document.buffers().nth(self.json.buffer.value())
+    }
+
+    /// Returns the offset of the compressed data in its buffer in bytes.
+    pub fn offset(&self) -> usize {
+        self.json.byte_offset.0 as usize
+    }
+
+    /// Returns the length of the compressed data in bytes.
+    pub fn length(&self) -> usize {
+        self.json.byte_length.0 as usize
+    }
+
+    /// Returns the stride in bytes of the decompressed elements.
+    pub fn stride(&self) -> usize {
+        self.json.byte_stride.0 as usize
+    }
+
+    /// Returns the number of decompressed elements.
+    pub fn count(&self) -> usize {
+        self.json.count.0 as usize
+    }
+
+    /// Returns the compression mode.
+    pub fn mode(&self) -> Mode {
+        self.json.mode.unwrap()
+    }
+
+    /// Returns the filter applied to the decompressed data.
+    pub fn filter(&self) -> Filter {
+        self.json.filter.unwrap()
+    }
+
+    /// Decodes the compressed data, which must be exactly the bytes in the
+    /// range given by [`offset`](Self::offset) and [`length`](Self::length),
+    /// and applies the filter.
+    pub fn decode(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let (count, stride) = (self.count(), self.stride());
+        let mut output = match self.mode() {
+            Mode::Attributes => decode_vertex_buffer(data, count, stride)?,
+            Mode::Triangles => decode_index_buffer(data, count, stride)?,
+            Mode::Indices => decode_index_sequence(data, count, stride)?,
+        };
+        match self.filter() {
+            Filter::None => {}
+            Filter::Octahedral => decode_filter_octahedral(&mut output, stride)?,
+            Filter::Quaternion => decode_filter_quaternion(&mut output, stride)?,
+            Filter::Exponential => decode_filter_exponential(&mut output, stride)?,
+        }
+        Ok(output)
+    }
+}
+
+/// Reads bytes from compressed data.
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        let byte = *self.data.get(self.position).ok_or(Error::UnexpectedEnd)?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        if self.remaining() < length {
+            return Err(Error::UnexpectedEnd);
+        }
+        let bytes = &self.data[self.position..self.position + length];
+        self.position += length;
+        Ok(bytes)
+    }
+
+    /// Reads an unsigned integer encoded in up to five bytes of seven bits.
+    fn vbyte(&mut self) -> Result<u32, Error> {
+        let lead = self.byte()?;
+        if lead < 0x80 {
+            return Ok(u32::from(lead));
+        }
+        let mut result = u32::from(lead & 0x7f);
+        let mut shift = 7;
+        for _ in 0..4 {
+            let group = self.byte()?;
+            result |= u32::from(group & 0x7f) << shift;
+            shift += 7;
+            if group < 0x80 {
+                break;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Reads an index encoded as a zigzag delta from `last`.
+    fn index(&mut self, last: u32) -> Result<u32, Error> {
+        let v = self.vbyte()?;
+        Ok(last.wrapping_add(unzigzag(v)))
+    }
+}
+
+fn unzigzag(v: u32) -> u32 {
+    (v >> 1) ^ (v & 1).wrapping_neg()
+}
+
+/// Returns the version encoded in the header byte of compressed data.
+fn version(data: &[u8], header: u8, max_version: u8) -> Result<u8, Error> {
+    let first = *data.first().ok_or(Error::UnexpectedEnd)?;
+    if first & 0xf0 != header {
+        return Err(Error::Header(first));
+    }
+    let version = first & 0x0f;
+    if version > max_version {
+        return Err(Error::Version(version));
+    }
+    Ok(version)
+}
+
+/// Writes an index of the given size in bytes.
+fn write_index(output: &mut [u8], position: usize, index_size: usize, index: u32) {
+    let start = position * index_size;
+    if index_size == 2 {
+        output[start..start + 2].copy_from_slice(&(index as u16).to_le_bytes());
+    } else {
+        output[start..start + 4].copy_from_slice(&index.to_le_bytes());
+    }
+}
+
+/// Decodes `count` vertices of `stride` bytes each from data compressed
+/// with the `ATTRIBUTES` mode.
+pub fn decode_vertex_buffer(data: &[u8], count: usize, stride: usize) -> Result<Vec<u8>, Error> {
+    if stride == 0 || stride > 256 || stride % 4 != 0 {
+        return Err(Error::Parameters);
+    }
+    version(data, VERTEX_HEADER, 0)?;
+    let tail_size = stride.max(VERTEX_TAIL_MIN_SIZE);
+    if data.len() < 1 + tail_size {
+        return Err(Error::UnexpectedEnd);
+    }
+
+    let mut last_vertex = data[data.len() - stride..].to_vec();
+    let block_size = ((8192 / stride) & !(BYTE_GROUP_SIZE - 1)).min(256);
+    let mut reader = Reader {
+        data: &data[..data.len() - tail_size],
+        position: 1,
+    };
+    let mut output = vec![0; count * stride];
+    let mut deltas = [0u8; 256];
+    for block in output.chunks_mut(block_size * stride) {
+        let vertices = block.len() / stride;
+        let aligned = (vertices + BYTE_GROUP_SIZE - 1) & !(BYTE_GROUP_SIZE - 1);
+        for k in 0..stride {
+            decode_bytes(&mut reader, &mut deltas[..aligned], tail_size)?;
+            let mut previous = last_vertex[k];
+            for (i, delta) in deltas[..vertices].iter().enumerate() {
+                let unzigzagged = (delta >> 1) ^ (delta & 1).wrapping_neg();
+                previous = previous.wrapping_add(unzigzagged);
+                block[i * stride + k] = previous;
+            }
+        }
+        last_vertex.copy_from_slice(&block[(vertices - 1) * stride..]);
+    }
+    if reader.remaining() != 0 {
+        return Err(Error::TrailingData);
+    }
+    Ok(output)
+}
+
+/// Decodes one byte of every vertex in a block.
+///
+/// `tail_size` is the number of bytes following the data of `reader`, which
+/// may be read by a group in the reference implementation.
+fn decode_bytes(reader: &mut Reader, output: &mut [u8], tail_size: usize) -> Result<(), Error> {
+    let groups = output.len() / BYTE_GROUP_SIZE;
+    let header = reader.bytes((groups + 3) / 4)?;
+    for (group, output) in output.chunks_mut(BYTE_GROUP_SIZE).enumerate() {
+        if reader.remaining() + tail_size < BYTE_GROUP_DECODE_LIMIT {
+            return Err(Error::UnexpectedEnd);
+        }
+        match (header[group / 4] >> ((group % 4) * 2)) & 3 {
+            0 => output.fill(0),
+            1 => decode_bytes_group(reader, output, 2)?,
+            2 => decode_bytes_group(reader, output, 4)?,
+            _ => output.copy_from_slice(reader.bytes(BYTE_GROUP_SIZE)?),
+        }
+    }
+    Ok(())
+}
+
+/// Decodes a group of bytes packed with `bits` bits each, where the largest
+/// value is an escape for a byte stored after the packed values.
+fn decode_bytes_group(reader: &mut Reader, output: &mut [u8], bits: u32) -> Result<(), Error> {
+    let packed = reader.bytes(BYTE_GROUP_SIZE * bits as usize / 8)?;
+    let escape = (1u8 << bits) - 1;
+    let per_byte = 8 / bits as usize;
+    for (i, value) in output.iter_mut().enumerate() {
+        let shift = 8 - bits * (i % per_byte + 1) as u32;
+        let encoded = (packed[i / per_byte] >> shift) & escape;
+        *value = if encoded == escape {
+            reader.byte()?
+        } else {
+            encoded
+        };
+    }
+    Ok(())
+}
+
+/// The 16 most recently used vertices or edges of a triangle list.
+struct Fifo<T> {
+    entries: [T; 16],
+    offset: usize,
+}
+
+impl<T: Copy> Fifo<T> {
+    fn new(value: T) -> Self {
+        Self {
+            entries: [value; 16],
+            offset: 0,
+        }
+    }
+
+    /// Returns the entry `age` positions before the next to be written.
+    fn get(&self, age: usize) -> T {
+        self.entries[self.offset.wrapping_sub(age) & 15]
+    }
+
+    /// Writes an entry, which is only kept if `advance` is `true`.
+    fn push(&mut self, value: T, advance: bool) {
+        self.entries[self.offset] = value;
+        self.offset = (self.offset + advance as usize) & 15;
+    }
+}
+
+/// Decodes `count` indices of `index_size` bytes each from data compressed
+/// with the `TRIANGLES` mode.
+pub fn decode_index_buffer(data: &[u8], count: usize, index_size: usize) -> Result<Vec<u8>, Error> {
+    if count % 3 != 0 || !(index_size == 2 || index_size == 4) {
+        return Err(Error::Parameters);
+    }
+    let version = version(data, TRIANGLE_HEADER, 1)?;
+    let triangles = count / 3;
+    if data.len() < 1 + triangles + TRIANGLE_TABLE_SIZE {
+        return Err(Error::UnexpectedEnd);
+    }
+
+    let codes = &data[1..1 + triangles];
+    let table_start = data.len() - TRIANGLE_TABLE_SIZE;
+    let table = &data[table_start..];
+    let mut reader = Reader {
+        data: &data[..table_start],
+        position: 1 + triangles,
+    };
+
+    let mut edges = Fifo::new([u32::MAX; 2]);
+    let mut vertices = Fifo::new(u32::MAX);
+    let mut next = 0u32;
+    let mut last = 0u32;
+    let fec_max = if version >= 1 { 13 } else { 15 };
+
+    let mut output = vec![0; count * index_size];
+    for (triangle, &code) in codes.iter().enumerate() {
+        let [a, b, c] = if code < 0xf0 {
+            let [a, b] = edges.get(1 + (code >> 4) as usize);
+            let fec = (code & 15) as usize;
+            let c = if fec < fec_max {
+                let c = if fec == 0 {
+                    next
+                } else {
+                    vertices.get(1 + fec)
+                };
+                next += (fec == 0) as u32;
+                vertices.push(c, fec == 0);
+                c
+            } else {
+                last = if fec == 15 {
+                    reader.index(last)?
+                } else {
+                    // 13 and 14 encode a difference of -1 and 1.
+                    last.wrapping_add((fec as u32).wrapping_sub(fec as u32 ^ 3))
+                };
+                vertices.push(last, true);
+                last
+            };
+            edges.push([c, b], true);
+            edges.push([a, c], true);
+            [a, b, c]
+        } else {
+            let (fea, aux) = if code < 0xfe {
+                (0, table[(code & 15) as usize])
+            } else {
+                let aux = reader.byte()?;
+                if aux == 0 {
+                    // A zero not taken from the table restarts the numbering.
+                    next = 0;
+                }
+                (if code == 0xfe { 0 } else { 15 }, aux)
+            };
+            let feb = (aux >> 4) as usize;
+            let fec = (aux & 15) as usize;
+
+            let mut a = 0;
+            if fea == 0 {
+                a = next;
+                next += 1;
+            }
+            let mut b = if feb == 0 { next } else { vertices.get(feb) };
+            next += (feb == 0) as u32;
+            let mut c = if fec == 0 { next } else { vertices.get(fec) };
+            next += (fec == 0) as u32;
+            if fea == 15 {
+                last = reader.index(last)?;
+                a = last;
+            }
+            if feb == 15 {
+                last = reader.index(last)?;
+                b = last;
+            }
+            if fec == 15 {
+                last = reader.index(last)?;
+                c = last;
+            }
+
+            vertices.push(a, true);
+            vertices.push(b, feb == 0 || feb == 15);
+            vertices.push(c, fec == 0 || fec == 15);
+            edges.push([b, a], true);
+            edges.push([c, b], true);
+            edges.push([a, c], true);
+            [a, b, c]
+        };
+        write_index(&mut output, triangle * 3, index_size, a);
+        write_index(&mut output, triangle * 3 + 1, index_size, b);
+        write_index(&mut output, triangle * 3 + 2, index_size, c);
+    }
+    if reader.remaining() != 0 {
+        return Err(Error::TrailingData);
+    }
+    Ok(output)
+}
+
+/// Decodes `count` indices of `index_size` bytes each from data compressed
+/// with the `INDICES` mode.
+pub fn decode_index_sequence(
+    data: &[u8],
+    count: usize,
+    index_size: usize,
+) -> Result<Vec<u8>, Error> {
+    if !(index_size == 2 || index_size == 4) {
+        return Err(Error::Parameters);
+    }
+    version(data, SEQUENCE_HEADER, 1)?;
+    if data.len() < 1 + count + SEQUENCE_TAIL_SIZE {
+        return Err(Error::UnexpectedEnd);
+    }
+
+    let mut reader = Reader {
+        data: &data[..data.len() - SEQUENCE_TAIL_SIZE],
+        position: 1,
+    };
+    let mut last = [0u32; 2];
+    let mut output = vec![0; count * index_size];
+    for position in 0..count {
+        let v = reader.vbyte()?;
+        let baseline = (v & 1) as usize;
+        let index = last[baseline].wrapping_add(unzigzag(v >> 1));
+        last[baseline] = index;
+        write_index(&mut output, position, index_size, index);
+    }
+    if reader.remaining() != 0 {
+        return Err(Error::TrailingData);
+    }
+    Ok(output)
+}
+
+/// Rounds to the nearest integer, away from zero on ties.
+fn round(x: f32) -> i32 {
+    (x + if x >= 0.0 { 0.5 } else { -0.5 }) as i32
+}
+
+/// Reconstructs unit vectors from octahedral coordinates.
+///
+/// Each element holds four signed normalized 8 bit (`stride` 4) or 16 bit
+/// (`stride` 8) integers. The first three are replaced by the normalized
+/// vector and the fourth is left as it is.
+pub fn decode_filter_octahedral(data: &mut [u8], stride: usize) -> Result<(), Error> {
+    fn decode(v: [i32; 3], max: f32) -> [i32; 3] {
+        let (mut x, mut y) = (v[0] as f32, v[1] as f32);
+        let z = v[2] as f32 - x.abs() - y.abs();
+        let t = z.min(0.0);
+        x += if x >= 0.0 { t } else { -t };
+        y += if y >= 0.0 { t } else { -t };
+        let scale = max / (x * x + y * y + z * z).sqrt();
+        [round(x * scale), round(y * scale), round(z * scale)]
+    }
+
+    match stride {
+        4 => {
+            for element in data.chunks_exact_mut(4) {
+                let v = [0, 1, 2].map(|i| i32::from(element[i] as i8));
+                for (byte, value) in element.iter_mut().zip(decode(v, 127.0)) {
+                    *byte = value as i8 as u8;
+                }
+            }
+        }
+        8 => {
+            for element in data.chunks_exact_mut(8) {
+                let v = [0, 1, 2].map(|i| i32::from(read_i16(element, i)));
+                for (i, value) in decode(v, 32767.0).into_iter().enumerate() {
+                    write_i16(element, i, value as i16);
+                }
+            }
+        }
+        _ => return Err(Error::Parameters),
+    }
+    Ok(())
+}
+
+/// Reconstructs unit quaternions from their three smallest components.
+///
+/// Each element holds four signed 16 bit integers: the three smallest
+/// components, and the index of the largest component in the two least
+/// significant bits of the fourth.
+pub fn decode_filter_quaternion(data: &mut [u8], stride: usize) -> Result<(), Error> {
+    if stride != 8 {
+        return Err(Error::Parameters);
+    }
+    let scale = 1.0 / 2f32.sqrt();
+    for element in data.chunks_exact_mut(8) {
+        let header = read_i16(element, 3);
+        let ss = scale / f32::from(header | 3);
+        let x = f32::from(read_i16(element, 0)) * ss;
+        let y = f32::from(read_i16(element, 1)) * ss;
+        let z = f32::from(read_i16(element, 2)) * ss;
+        let w = (1.0 - x * x - y * y - z * z).max(0.0).sqrt();
+
+        let largest = (header & 3) as usize;
+        write_i16(element, (largest + 1) & 3, round(x * 32767.0) as i16);
+        write_i16(element, (largest + 2) & 3, round(y * 32767.0) as i16);
+        write_i16(element, (largest + 3) & 3, round(z * 32767.0) as i16);
+        write_i16(element, largest, round(w * 32767.0) as i16);
+    }
+    Ok(())
+}
+
+/// Reconstructs 32 bit floating point values from a signed 24 bit mantissa
+/// and a signed 8 bit exponent.
+pub fn decode_filter_exponential(data: &mut [u8], stride: usize) -> Result<(), Error> {
+    if stride % 4 != 0 {
+        return Err(Error::Parameters);
+    }
+    for value in data.chunks_exact_mut(4) {
+        let v = u32::from_le_bytes([value[0], value[1], value[2], value[3]]);
+        let mantissa = ((v << 8) as i32) >> 8;
+        let exponent = (v as i32) >> 24;
+        let power = f32::from_bits(((exponent + 127) as u32) << 23);
+        value.copy_from_slice(&(power * mantissa as f32).to_le_bytes());
+    }
+    Ok(())
+}
+
+fn read_i16(element: &[u8], i: usize) -> i16 {
+    i16::from_le_bytes([element[i * 2], element[i * 2 + 1]])
+}
+
+fn write_i16(element: &mut [u8], i: usize, value: i16) {
+    element[i * 2..i * 2 + 2].copy_from_slice(&value.to_le_bytes());
+}
@@ -0,0 +1,307 @@
+//! Extensions unknown to this crate are kept as JSON values in the
+//! `extensions` map of each object. An [`Extension`] type describes how to
+//! deserialize and validate such a value, and a [`Registry`] lists the
+//! extensions an application understands along with the kinds of objects
+//! they may be attached to.
+//!
+//! ```
+//! use gltf::extension::{Extension, Kind, Registry};
+//! use gltf::json::validation::{Error, Validate};
+//! use gltf::json::{Path, Root};
+//!
+//! #[derive(serde_derive::Deserialize)]
+//! struct Tag {
+//!     label: String,
+//! }
+//!
+//! impl Validate for Tag {
+//!     fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+//!     where
+//!         P: Fn() -> Path,
+//!         R: FnMut(&dyn Fn() -> Path, Error),
+//!     {
+//!         if self.label.is_empty() {
+//!             report(&|| path().field("label"), Error::Invalid);
+//!         }
+//!     }
+//! }
+//!
+//! impl Extension for Tag {
+//!     const NAME: &'static str = "EXT_example_tag";
+//! }
+//!
+//! # fn run() -> gltf::Result<()> {
+//! let mut registry = Registry::new();
+//! registry.register::<Tag>(Kind::Node);
+//!
+//! let gltf = gltf::Gltf::from_slice_without_validation(br#"{
+//!     "asset": {"version": "2.0"},
+//!     "extensionsRequired": ["EXT_example_tag"],
+//!     "extensionsUsed": ["EXT_example_tag"],
+//!     "nodes": [{"extensions": {"EXT_example_tag": {"label": "door"}}}]
+//! }"#)?;
+//! registry.validate(&gltf)?;
+//!
+//! let node = gltf.nodes().next().unwrap();
+//! assert_eq!(node.extension::<Tag>().unwrap().label, "door");
+//! # Ok(())
+//! # }
+//! # run().unwrap();
+//! ```
+
+use crate::{Document, LoadOptions, Result};
+use json::validation::{Error as ValidationError, Validate};
+use json::{Path, Value};
+use serde::de::DeserializeOwned;
+use serde_json::Map;
+
+/// An extension defined by the application.
+pub trait Extension: DeserializeOwned + Validate {
+    /// The name of the extension, for example `"EXT_example_tag"`.
+    const NAME: &'static str;
+}
+
+/// The kinds of objects that registered extensions may be attached to.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Kind {
+    /// The root object of the document.
+    Root,
+
+    /// Accessors.
+    Accessor,
+
+    /// Animations.
+    Animation,
+
+    /// Buffers.
+    Buffer,
+
+    /// Buffer views.
+    View,
+
+    /// Cameras.
+    Camera,
+
+    /// Images.
+    Image,
+
+    /// Materials.
+    Material,
+
+    /// Meshes.
+    Mesh,
+
+    /// Mesh primitives.
+    Primitive,
+
+    /// Nodes.
+    Node,
+
+    /// Texture samplers.
+    Sampler,
+
+    /// Scenes.
+    Scene,
+
+    /// Skins.
+    Skin,
+
+    /// Textures.
+    Texture,
+}
+
+type ValidateFn =
+    fn(&Value, &json::Root, &dyn Fn() -> Path, &mut dyn FnMut(&dyn Fn() -> Path, ValidationError));
+
+/// A registered extension.
+#[derive(Clone, Copy)]
+struct Entry {
+    name: &'static str,
+    kind: Kind,
+    validate: ValidateFn,
+}
+
+/// The set of extensions understood by the application.
+#[derive(Clone, Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+/// Deserializes the value of an extension.
+pub(crate) fn deserialize<T: Extension>(value: &Value) -> Option<T> {
+    T::deserialize(value).ok()
+}
+
+/// Validates the value of an extension of type `T`.
+fn validate<T: Extension>(
+    value: &Value,
+    root: &json::Root,
+    path: &dyn Fn() -> Path,
+    mut report: &mut dyn FnMut(&dyn Fn() -> Path, ValidationError),
+) {
+    match deserialize::<T>(value) {
+        Some(extension) => extension.validate(root, path, &mut report),
+        None => report(path, ValidationError::Invalid),
+    }
+}
+
+impl Kind {
+    /// Returns the path and extension map of every object of this kind that
+    /// has extensions.
+    fn extensions(self, root: &json::Root) -> Vec<(Path, &Map<String, Value>)> {
+        fn each<'r, T, F>(
+            field: &str,
+            objects: &'r [T],
+            others: F,
+        ) -> Vec<(Path, &'r Map<String, Value>)>
+        where
+            F: Fn(&'r T) -> Option<&'r Map<String, Value>>,
+        {
+            objects
+                .iter()
+                .enumerate()
+                .filter_map(|(index, object)| {
+                    let others = others(object)?;
+                    Some((Path::new().field(field).index(index), others))
+                })
+                .collect()
+        }
+
+        match self {
+            Kind::Root => root
+                .extensions
+                .as_ref()
+                .map(|extensions| (Path::new(), &extensions.others))
+                .into_iter()
+                .collect(),
+            Kind::Accessor => each("accessors", &root.accessors, |accessor| {
+                Some(&accessor.extensions.as_ref()?.others)
+            }),
+            Kind::Animation => each("animations", &root.animations, |animation| {
+                Some(&animation.extensions.as_ref()?.others)
+            }),
+            Kind::Buffer => each("buffers", &root.buffers, |buffer| {
+                Some(&buffer.extensions.as_ref()?.others)
+            }),
+            Kind::View => each("bufferViews", &root.buffer_views, |view| {
+                Some(&view.extensions.as_ref()?.others)
+            }),
+            Kind::Camera => each("cameras", &root.cameras, |camera| {
+                Some(&camera.extensions.as_ref()?.others)
+            }),
+            Kind::Image => each("images", &root.images, |image| {
+                Some(&image.extensions.as_ref()?.others)
+            }),
+            Kind::Material => each("materials", &root.materials, |material| {
+                Some(&material.extensions.as_ref()?.others)
+            }),
+            Kind::Mesh => each("meshes", &root.meshes, |mesh| {
+                Some(&mesh.extensions.as_ref()?.others)
+            }),
+            Kind::Primitive => root
+                .meshes
+                .iter()
+                .enumerate()
+                .flat_map(|(mesh, json)| {
+                    json.primitives
+                        .iter()
+                        .enumerate()
+                        .filter_map(move |(primitive, json)| {
+                            let others = &json.extensions.as_ref()?.others;
+                            let path = Path::new()
+                                .field("meshes")
+                                .index(mesh)
+                                .field("primitives")
+                                .index(primitive);
+                            Some((path, others))
+                        })
+                })
+                .collect(),
+            Kind::Node => each("nodes", &root.nodes, |node| {
+                Some(&node.extensions.as_ref()?.others)
+            }),
+            Kind::Sampler => each("samplers", &root.samplers, |sampler| {
+                Some(&sampler.extensions.as_ref()?.others)
+            }),
+            Kind::Scene => each("scenes", &root.scenes, |scene| {
+                Some(&scene.extensions.as_ref()?.others)
+            }),
+            Kind::Skin => each("skins", &root.skins, |skin| {
+                Some(&skin.extensions.as_ref()?.others)
+            }),
+            Kind::Texture => each("textures", &root.textures, |texture| {
+                Some(&texture.extensions.as_ref()?.others)
+            }),
+        }
+    }
+}
+
+impl Registry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the extension `T` for objects of the given kind.
+    ///
+    /// An extension attached to several kinds of objects is registered once
+    /// per kind.
+    pub fn register<T: Extension>(&mut self, kind: Kind) -> &mut Self {
+        self.entries.push(Entry {
+            name: T::NAME,
+            kind,
+            validate: validate::<T>,
+        });
+        self
+    }
+
+    /// Returns `true` if an extension with the given name is registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.iter().any(|entry| entry.name == name)
+    }
+
+    /// Returns the names of the registered extensions.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.entries.iter().map(|entry| entry.name)
+    }
+
+    /// Validates a document, including the registered extensions.
+    ///
+    /// Registered extensions are accepted in `extensionsRequired` in addition
+    /// to the [enabled extensions](json::extensions::ENABLED_EXTENSIONS).
+    /// Registered extension data is reported as invalid if it fails to
+    /// deserialize and is otherwise validated with its `Validate`
+    /// implementation. See [`LoadOptions`] to also validate documents while
+    /// loading them.
+    pub fn validate(&self, document: &Document) -> Result<()> {
+        let options = LoadOptions {
+            registry: self.clone(),
+            ..LoadOptions::default()
+        };
+        options.validate(document)
+    }
+
+    /// Validates the data of the registered extensions.
+    pub(crate) fn validate_extensions(
+        &self,
+        root: &json::Root,
+        report: &mut dyn FnMut(&dyn Fn() -> Path, ValidationError),
+    ) {
+        for entry in &self.entries {
+            for (path, others) in entry.kind.extensions(root) {
+                if let Some(value) = others.get(entry.name) {
+                    let path = || path.field("extensions").field(entry.name);
+                    (entry.validate)(value, root, &path, report);
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Registry {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.entries.iter().map(|entry| (entry.name, entry.kind)))
+            .finish()
+    }
+}
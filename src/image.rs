@@ -41,6 +41,20 @@ pub enum Format {
 
     /// Red, green, blue, alpha (32 bits float)
     R32G32B32A32FLOAT,
+
+    /// A KTX2 image, as used by the `KHR_texture_basisu` extension, that is
+    /// kept encoded. The data can be read with
+    /// [`khr_texture_basisu::parse`](crate::khr_texture_basisu::parse).
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_basisu")))]
+    Ktx2,
+
+    /// A DDS image, as used by the `MSFT_texture_dds` extension, that is kept
+    /// encoded. The data can be read with
+    /// [`msft_texture_dds::parse`](crate::msft_texture_dds::parse).
+    #[cfg(feature = "MSFT_texture_dds")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "MSFT_texture_dds")))]
+    Dds,
 }
 
 /// Describes an image data source.
@@ -83,7 +97,8 @@ pub struct Image<'a> {
 #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
 #[derive(Clone, Debug)]
 pub struct Data {
-    /// The image pixel data bytes.
+    /// The image pixel data bytes, or the encoded image for formats that are
+    /// not decoded.
     pub pixels: Vec<u8>,
 
     /// The image pixel data format.
@@ -147,6 +162,25 @@ impl<'a> Image<'a> {
         ext.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
+    /// Returns the metadata packet of this image.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        let extensions = self.json.extensions.as_ref()?;
+        crate::khr_xmp_json_ld::Packet::resolve(self.document, extensions.khr_xmp_json_ld.as_ref())
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -181,4 +215,31 @@ impl Data {
             pixels,
         })
     }
+
+    /// Keeps a KTX2 image encoded, taking its dimensions from its header.
+    #[cfg(feature = "KHR_texture_basisu")]
+    pub(crate) fn from_ktx2(data: Vec<u8>) -> Result<Self> {
+        let header = *crate::khr_texture_basisu::parse(&data)
+            .map_err(Error::Ktx2)?
+            .header();
+        Ok(Data {
+            format: Format::Ktx2,
+            width: header.pixel_width,
+            height: header.pixel_height.max(1),
+            pixels: data,
+        })
+    }
+
+    /// Keeps a DDS image encoded, taking its dimensions from its header.
+    #[cfg(feature = "MSFT_texture_dds")]
+    pub(crate) fn from_dds(data: Vec<u8>) -> Result<Self> {
+        let dds = crate::msft_texture_dds::parse(&data).map_err(Error::Dds)?;
+        let (width, height) = (dds.width(), dds.height());
+        Ok(Data {
+            format: Format::Dds,
+            width,
+            height,
+            pixels: data,
+        })
+    }
 }
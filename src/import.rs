@@ -1,9 +1,15 @@
 use crate::buffer;
+#[cfg(feature = "EXT_lights_image_based")]
+use crate::ext_lights_image_based;
 use crate::image;
+#[cfg(feature = "KHR_draco_mesh_compression")]
+use crate::khr_draco_mesh_compression;
 use std::borrow::Cow;
 use std::{fs, io};
 
-use crate::{Document, Error, Gltf, Result};
+use crate::{Document, Error, Gltf, LoadOptions, Result};
+#[cfg(feature = "EXT_lights_image_based")]
+use image_crate::ImageFormat::Hdr;
 #[cfg(feature = "EXT_texture_webp")]
 use image_crate::ImageFormat::WebP;
 use image_crate::ImageFormat::{Jpeg, Png};
@@ -82,6 +88,74 @@ where
     Ok(data)
 }
 
+/// Resolves the URIs of external buffer and image resources.
+///
+/// The importer calls [`ResourceResolver::resolve`] for every buffer and image
+/// URI found in a glTF document, which allows resources to be loaded from
+/// sources other than the file system, e.g. asset packs or in-memory maps.
+/// [`DefaultResolver`] implements the standard behaviour of [`import`].
+///
+/// Closures of the form `Fn(&str) -> Result<Vec<u8>>` implement this trait.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use std::collections::HashMap;
+///
+/// let mut files = HashMap::new();
+/// files.insert("Box0.bin", std::fs::read("examples/Box0.bin")?);
+/// let resolver = |uri: &str| match files.get(uri) {
+///     Some(data) => Ok(data.clone()),
+///     None => Err(gltf::Error::UnsupportedScheme),
+/// };
+///
+/// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+/// let buffers = gltf::import_buffers_with_resolver(&gltf, &resolver, None)?;
+/// assert_eq!(buffers.len(), 1);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub trait ResourceResolver {
+    /// Returns the contents of the resource identified by `uri`.
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>>;
+}
+
+impl<F> ResourceResolver for F
+where
+    F: Fn(&str) -> Result<Vec<u8>>,
+{
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>> {
+        self(uri)
+    }
+}
+
+/// The default resource resolver.
+///
+/// Decodes `data:` URIs and reads `file:` and relative URIs from the file
+/// system. If no base directory is provided then only `data:` URIs can be
+/// resolved.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultResolver<'a> {
+    /// The directory relative URIs are resolved against.
+    base: Option<&'a Path>,
+}
+
+impl<'a> DefaultResolver<'a> {
+    /// Constructs a resolver that resolves relative URIs from the `base`
+    /// directory.
+    pub fn new(base: Option<&'a Path>) -> Self {
+        Self { base }
+    }
+}
+
+impl ResourceResolver for DefaultResolver<'_> {
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>> {
+        Scheme::read(self.base, uri)
+    }
+}
+
 impl buffer::Data {
     /// Construct a buffer data object by reading the given source.
     /// If `base` is provided, then external filesystem references will
@@ -99,9 +173,21 @@ impl buffer::Data {
         source: buffer::Source<'_>,
         base: Option<&Path>,
         blob: &mut Option<Vec<u8>>,
+    ) -> Result<Self> {
+        Self::from_source_with_resolver(source, &DefaultResolver::new(base), blob)
+    }
+
+    /// Construct a buffer data object by reading the given source.
+    /// External references are loaded with the given `resolver`.
+    /// `blob` represents the `BIN` section of a binary glTF file,
+    /// and it will be taken to fill the buffer if the `source` refers to it.
+    pub fn from_source_with_resolver(
+        source: buffer::Source<'_>,
+        resolver: &dyn ResourceResolver,
+        blob: &mut Option<Vec<u8>>,
     ) -> Result<Self> {
         let mut data = match source {
-            buffer::Source::Uri(uri) => Scheme::read(base, uri),
+            buffer::Source::Uri(uri) => resolver.resolve(uri),
             buffer::Source::Bin => blob.take().ok_or(Error::MissingBlob),
         }?;
         while data.len() % 4 != 0 {
@@ -120,11 +206,33 @@ impl buffer::Data {
 pub fn import_buffers(
     document: &Document,
     base: Option<&Path>,
+    blob: Option<Vec<u8>>,
+) -> Result<Vec<buffer::Data>> {
+    import_buffers_with_resolver(document, &DefaultResolver::new(base), blob)
+}
+
+/// Import buffer data referenced by a glTF document, loading external
+/// references with the given `resolver`.
+///
+/// ### Note
+///
+/// This function is intended for advanced users who wish to forego loading image data.
+/// A typical user should call [`import`] instead.
+pub fn import_buffers_with_resolver(
+    document: &Document,
+    resolver: &dyn ResourceResolver,
     mut blob: Option<Vec<u8>>,
 ) -> Result<Vec<buffer::Data>> {
     let mut buffers = Vec::new();
     for buffer in document.buffers() {
-        let data = buffer::Data::from_source_and_blob(buffer.source(), base, &mut blob)?;
+        #[cfg(feature = "EXT_meshopt_compression")]
+        if buffer.is_meshopt_fallback() {
+            // The contents are decompressed from other buffers below.
+            let length = (buffer.length() + 3) & !3;
+            buffers.push(buffer::Data(vec![0; length]));
+            continue;
+        }
+        let data = buffer::Data::from_source_with_resolver(buffer.source(), resolver, &mut blob)?;
         if data.len() < buffer.length() {
             return Err(Error::BufferLength {
                 buffer: buffer.index(),
@@ -134,9 +242,246 @@ pub fn import_buffers(
         }
         buffers.push(data);
     }
+    #[cfg(feature = "EXT_meshopt_compression")]
+    decompress_views(document, &mut buffers)?;
     Ok(buffers)
 }
 
+/// Replaces the contents of every buffer view compressed with the
+/// `EXT_meshopt_compression` extension with its decompressed data.
+#[cfg(feature = "EXT_meshopt_compression")]
+fn decompress_views(document: &Document, buffers: &mut [buffer::Data]) -> Result<()> {
+    use crate::ext_meshopt_compression::Error as DecodeError;
+
+    for view in document.views() {
+        let compression = match view.meshopt_compression() {
+            Some(compression) => compression,
+            None => continue,
+        };
+        let error = |error| Error::MeshoptCompression {
+            view: view.index(),
+            error,
+        };
+        let start = compression.offset();
+        let data = compression
+            .try_buffer()
+            .and_then(|buffer| buffers.get(buffer.index()))
+            .zip(start.checked_add(compression.length()))
+            .and_then(|(source, end)| source.get(start..end))
+            .ok_or_else(|| error(DecodeError::UnexpectedEnd))?;
+        let decoded = compression.decode(data).map_err(error)?;
+        let start = view.offset();
+        let destination = view
+            .try_buffer()
+            .and_then(|buffer| buffers.get_mut(buffer.index()))
+            .zip(start.checked_add(view.length()))
+            .and_then(|(destination, end)| destination.0.get_mut(start..end))
+            .filter(|destination| decoded.len() <= destination.len())
+            .ok_or_else(|| error(DecodeError::Length))?;
+        destination[..decoded.len()].copy_from_slice(&decoded);
+    }
+    Ok(())
+}
+
+/// Replaces every mesh primitive compressed with the
+/// `KHR_draco_mesh_compression` extension with its decoded data.
+///
+/// The decoded indices and attributes are appended to the buffer holding the
+/// compressed data in new buffer views, which the accessors of the primitive
+/// are pointed at, and the extension is removed from the document.
+#[cfg(feature = "KHR_draco_mesh_compression")]
+fn decompress_meshes(
+    document: Document,
+    mut buffers: Vec<buffer::Data>,
+) -> Result<(Document, Vec<buffer::Data>)> {
+    use crate::khr_draco_mesh_compression::Error as DecodeError;
+    use json::validation::{Checked, USize64};
+
+    let mut decoded = Vec::new();
+    for mesh in document.meshes() {
+        for primitive in mesh.primitives() {
+            let compression = match primitive.draco_mesh_compression() {
+                Some(compression) => compression,
+                None => continue,
+            };
+            let error = |error| Error::DracoMeshCompression {
+                mesh: mesh.index(),
+                primitive: primitive.index(),
+                error,
+            };
+            let view = compression
+                .try_view()
+                .ok_or_else(|| error(DecodeError::UnexpectedEnd))?;
+            let buffer = view
+                .try_buffer()
+                .ok_or_else(|| error(DecodeError::UnexpectedEnd))?;
+            let start = view.offset();
+            let data = buffers
+                .get(buffer.index())
+                .zip(start.checked_add(view.length()))
+                .and_then(|(source, end)| source.get(start..end))
+                .ok_or_else(|| error(DecodeError::UnexpectedEnd))?;
+            let decoded_mesh = compression.decode(data).map_err(error)?;
+            decoded.push((
+                mesh.index(),
+                primitive.index(),
+                buffer.index(),
+                decoded_mesh,
+            ));
+        }
+    }
+    if decoded.is_empty() {
+        return Ok((document, buffers));
+    }
+
+    let mut root = document.into_json();
+    for (mesh, index, buffer, decoded_mesh) in decoded {
+        let error = |error| Error::DracoMeshCompression {
+            mesh,
+            primitive: index,
+            error,
+        };
+        let primitive = &mut root.meshes[mesh].primitives[index];
+        let compression = primitive
+            .extensions
+            .as_mut()
+            .and_then(|extensions| extensions.khr_draco_mesh_compression.take())
+            .unwrap();
+        let mut outputs = Vec::new();
+        if let Some(accessor) = primitive.indices {
+            let values = khr_draco_mesh_compression::Values::Integer(
+                decoded_mesh
+                    .indices
+                    .iter()
+                    .map(|&index| index as i32)
+                    .collect(),
+            );
+            outputs.push((
+                accessor,
+                1,
+                values,
+                json::buffer::Target::ElementArrayBuffer,
+            ));
+        }
+        for (semantic, id) in &compression.attributes {
+            let accessor = *primitive
+                .attributes
+                .get(semantic)
+                .ok_or_else(|| error(DecodeError::MissingAttribute(*id)))?;
+            let attribute = decoded_mesh
+                .attribute(*id)
+                .ok_or_else(|| error(DecodeError::MissingAttribute(*id)))?;
+            outputs.push((
+                accessor,
+                attribute.components,
+                attribute.values.clone(),
+                json::buffer::Target::ArrayBuffer,
+            ));
+        }
+
+        for (accessor_index, components, values, target) in outputs {
+            let bytes = root
+                .accessors
+                .get(accessor_index.value())
+                .and_then(|accessor| accessor_bytes(accessor, components, &values))
+                .ok_or_else(|| error(DecodeError::Accessor(accessor_index.value())))?;
+            let data = &mut buffers[buffer].0;
+            data.truncate(root.buffers[buffer].byte_length.0 as usize);
+            while data.len() % 4 != 0 {
+                data.push(0);
+            }
+            let offset = data.len();
+            data.extend_from_slice(&bytes);
+            root.buffers[buffer].byte_length = USize64::from(data.len());
+            while data.len() % 4 != 0 {
+                data.push(0);
+            }
+            let view = root.push(json::buffer::View {
+                buffer: json::Index::new(buffer as u32),
+                byte_length: USize64::from(bytes.len()),
+                byte_offset: Some(USize64::from(offset)),
+                byte_stride: None,
+                #[cfg(feature = "names")]
+                name: None,
+                target: Some(Checked::Valid(target)),
+                extensions: None,
+                extras: Default::default(),
+            });
+            let accessor = &mut root.accessors[accessor_index.value()];
+            accessor.buffer_view = Some(view);
+            accessor.byte_offset = None;
+        }
+    }
+    let name = "KHR_draco_mesh_compression";
+    root.extensions_used.retain(|extension| extension != name);
+    root.extensions_required
+        .retain(|extension| extension != name);
+    Ok((Document::from_json_without_validation(root), buffers))
+}
+
+/// Converts decoded values to the component type of an accessor, returning
+/// `None` if they do not match its count or type.
+#[cfg(feature = "KHR_draco_mesh_compression")]
+fn accessor_bytes(
+    accessor: &json::Accessor,
+    components: usize,
+    values: &khr_draco_mesh_compression::Values,
+) -> Option<Vec<u8>> {
+    use json::accessor::ComponentType;
+    use json::validation::Checked;
+    use khr_draco_mesh_compression::Values;
+
+    let (component_type, multiplicity) = match (accessor.component_type, accessor.type_) {
+        (Checked::Valid(component_type), Checked::Valid(type_)) => {
+            (component_type.0, type_.multiplicity())
+        }
+        _ => return None,
+    };
+    let length = match values {
+        Values::Integer(values) => values.len(),
+        Values::Float(values) => values.len(),
+    };
+    if components != multiplicity || length != accessor.count.0 as usize * multiplicity {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(length * component_type.size());
+    // Floating point values are rounded to integer types, scaled to the
+    // range of the type if the accessor is normalized.
+    let integer = |value: usize, max: f32| match values {
+        Values::Integer(values) => f64::from(values[value]),
+        Values::Float(values) if accessor.normalized => f64::from((values[value] * max).round()),
+        Values::Float(values) => f64::from(values[value].round()),
+    };
+    for value in 0..length {
+        match component_type {
+            ComponentType::I8 => bytes.push(integer(value, 127.0) as i8 as u8),
+            ComponentType::U8 => bytes.push(integer(value, 255.0) as u8),
+            ComponentType::I16 => {
+                bytes.extend_from_slice(&(integer(value, 32767.0) as i16).to_le_bytes())
+            }
+            ComponentType::U16 => {
+                bytes.extend_from_slice(&(integer(value, 65535.0) as u16).to_le_bytes())
+            }
+            ComponentType::U32 => {
+                let value = match values {
+                    Values::Integer(values) => values[value] as u32,
+                    Values::Float(values) => values[value].round() as u32,
+                };
+                bytes.extend_from_slice(&value.to_le_bytes())
+            }
+            ComponentType::F32 => {
+                let value = match values {
+                    Values::Integer(values) => values[value] as f32,
+                    Values::Float(values) => values[value],
+                };
+                bytes.extend_from_slice(&value.to_le_bytes())
+            }
+        }
+    }
+    Some(bytes)
+}
+
 impl image::Data {
     /// Construct an image data object by reading the given source.
     /// If `base` is provided, then external filesystem references will
@@ -145,6 +490,16 @@ impl image::Data {
         source: image::Source<'_>,
         base: Option<&Path>,
         buffer_data: &[buffer::Data],
+    ) -> Result<Self> {
+        Self::from_source_with_resolver(source, &DefaultResolver::new(base), buffer_data)
+    }
+
+    /// Construct an image data object by reading the given source.
+    /// External references are loaded with the given `resolver`.
+    pub fn from_source_with_resolver(
+        source: image::Source<'_>,
+        resolver: &dyn ResourceResolver,
+        buffer_data: &[buffer::Data],
     ) -> Result<Self> {
         #[cfg(feature = "guess_mime_type")]
         let guess_format = |encoded_image: &[u8]| match image_crate::guess_format(encoded_image) {
@@ -152,63 +507,82 @@ impl image::Data {
             Ok(image_crate::ImageFormat::Jpeg) => Some(Jpeg),
             #[cfg(feature = "EXT_texture_webp")]
             Ok(image_crate::ImageFormat::WebP) => Some(WebP),
+            #[cfg(feature = "EXT_lights_image_based")]
+            Ok(image_crate::ImageFormat::Hdr) => Some(Hdr),
             _ => None,
         };
         #[cfg(not(feature = "guess_mime_type"))]
         let guess_format = |_encoded_image: &[u8]| None;
         let decoded_image = match source {
-            image::Source::Uri { uri, mime_type } if base.is_some() => match Scheme::parse(uri) {
-                Scheme::Data(Some(annoying_case), base64) => {
-                    let encoded_image = base64::decode(base64).map_err(Error::Base64)?;
-                    let encoded_format = match annoying_case {
-                        "image/png" => Png,
-                        "image/jpeg" => Jpeg,
-                        #[cfg(feature = "EXT_texture_webp")]
-                        "image/webp" => WebP,
-                        _ => match guess_format(&encoded_image) {
-                            Some(format) => format,
-                            None => return Err(Error::UnsupportedImageEncoding),
-                        },
-                    };
-
-                    image_crate::load_from_memory_with_format(&encoded_image, encoded_format)?
+            image::Source::Uri { uri, mime_type } => {
+                let encoded_image = resolver.resolve(uri)?;
+                // The media type of a data URI takes precedence.
+                let mime_type = match Scheme::parse(uri) {
+                    Scheme::Data(Some(media_type), _) => Some(media_type),
+                    _ => mime_type,
+                };
+                #[cfg(feature = "KHR_texture_basisu")]
+                if mime_type.map_or(uri.ends_with(".ktx2"), |x| x == "image/ktx2") {
+                    return image::Data::from_ktx2(encoded_image);
                 }
-                Scheme::Unsupported => return Err(Error::UnsupportedScheme),
-                _ => {
-                    let encoded_image = Scheme::read(base, uri)?;
-                    let encoded_format = match mime_type {
-                        Some("image/png") => Png,
-                        Some("image/jpeg") => Jpeg,
+                #[cfg(feature = "MSFT_texture_dds")]
+                if mime_type.map_or(uri.ends_with(".dds"), |x| x == "image/vnd-ms.dds") {
+                    return image::Data::from_dds(encoded_image);
+                }
+                let encoded_format = match mime_type {
+                    Some("image/png") => Png,
+                    Some("image/jpeg") => Jpeg,
+                    #[cfg(feature = "EXT_texture_webp")]
+                    Some("image/webp") => WebP,
+                    #[cfg(feature = "EXT_lights_image_based")]
+                    Some("image/vnd.radiance") => Hdr,
+                    Some(_) => match guess_format(&encoded_image) {
+                        Some(format) => format,
+                        None => return Err(Error::UnsupportedImageEncoding),
+                    },
+                    None => match uri.rsplit('.').next() {
+                        Some("png") => Png,
+                        Some("jpg") | Some("jpeg") => Jpeg,
                         #[cfg(feature = "EXT_texture_webp")]
-                        Some("image/webp") => WebP,
-                        Some(_) => match guess_format(&encoded_image) {
+                        Some("webp") => WebP,
+                        #[cfg(feature = "EXT_lights_image_based")]
+                        Some("hdr") => Hdr,
+                        _ => match guess_format(&encoded_image) {
                             Some(format) => format,
                             None => return Err(Error::UnsupportedImageEncoding),
                         },
-                        None => match uri.rsplit('.').next() {
-                            Some("png") => Png,
-                            Some("jpg") | Some("jpeg") => Jpeg,
-                            #[cfg(feature = "EXT_texture_webp")]
-                            Some("webp") => WebP,
-                            _ => match guess_format(&encoded_image) {
-                                Some(format) => format,
-                                None => return Err(Error::UnsupportedImageEncoding),
-                            },
-                        },
-                    };
-                    image_crate::load_from_memory_with_format(&encoded_image, encoded_format)?
-                }
-            },
+                    },
+                };
+                image_crate::load_from_memory_with_format(&encoded_image, encoded_format)?
+            }
             image::Source::View { view, mime_type } => {
-                let parent_buffer_data = &buffer_data[view.buffer().index()].0;
+                let buffer = view.buffer().index();
+                let parent_buffer_data = buffer_data.get(buffer).map_or(&[][..], |data| &data[..]);
                 let begin = view.offset();
                 let end = begin + view.length();
-                let encoded_image = &parent_buffer_data[begin..end];
+                let encoded_image =
+                    parent_buffer_data
+                        .get(begin..end)
+                        .ok_or(Error::BufferLength {
+                            buffer,
+                            expected: end,
+                            actual: parent_buffer_data.len(),
+                        })?;
+                #[cfg(feature = "KHR_texture_basisu")]
+                if mime_type == "image/ktx2" {
+                    return image::Data::from_ktx2(encoded_image.to_vec());
+                }
+                #[cfg(feature = "MSFT_texture_dds")]
+                if mime_type == "image/vnd-ms.dds" {
+                    return image::Data::from_dds(encoded_image.to_vec());
+                }
                 let encoded_format = match mime_type {
                     "image/png" => Png,
                     "image/jpeg" => Jpeg,
                     #[cfg(feature = "EXT_texture_webp")]
                     "image/webp" => WebP,
+                    #[cfg(feature = "EXT_lights_image_based")]
+                    "image/vnd.radiance" => Hdr,
                     _ => match guess_format(encoded_image) {
                         Some(format) => format,
                         None => return Err(Error::UnsupportedImageEncoding),
@@ -216,7 +590,6 @@ impl image::Data {
                 };
                 image_crate::load_from_memory_with_format(encoded_image, encoded_format)?
             }
-            _ => return Err(Error::ExternalReferenceInSliceImport),
         };
 
         image::Data::new(decoded_image)
@@ -233,26 +606,124 @@ pub fn import_images(
     document: &Document,
     base: Option<&Path>,
     buffer_data: &[buffer::Data],
+) -> Result<Vec<image::Data>> {
+    import_images_with_resolver(document, &DefaultResolver::new(base), buffer_data)
+}
+
+/// Import image data referenced by a glTF document, loading external
+/// references with the given `resolver`.
+///
+/// ### Note
+///
+/// This function is intended for advanced users who wish to forego loading buffer data.
+/// A typical user should call [`import`] instead.
+pub fn import_images_with_resolver(
+    document: &Document,
+    resolver: &dyn ResourceResolver,
+    buffer_data: &[buffer::Data],
 ) -> Result<Vec<image::Data>> {
     let mut images = Vec::new();
     for image in document.images() {
-        images.push(image::Data::from_source(image.source(), base, buffer_data)?);
+        images.push(image::Data::from_source_with_resolver(
+            image.source(),
+            resolver,
+            buffer_data,
+        )?);
     }
     Ok(images)
 }
 
-fn import_impl(Gltf { document, blob }: Gltf, base: Option<&Path>) -> Result<Import> {
-    let buffer_data = import_buffers(&document, base, blob)?;
-    let image_data = import_images(&document, base, &buffer_data)?;
+/// Import the specular cubemap of an image-based light, as defined by the
+/// `EXT_lights_image_based` extension.
+///
+/// The six faces of every mip level are decoded in the order +X, -X, +Y, -Y,
+/// +Z, -Z, starting from the largest level.
+///
+/// ### Note
+///
+/// The images are decoded again even if they were already imported by
+/// [`import_images`].
+#[cfg(feature = "EXT_lights_image_based")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_lights_image_based")))]
+pub fn import_image_based_light(
+    light: &ext_lights_image_based::Light<'_>,
+    base: Option<&Path>,
+    buffer_data: &[buffer::Data],
+) -> Result<Vec<[image::Data; 6]>> {
+    import_image_based_light_with_resolver(light, &DefaultResolver::new(base), buffer_data)
+}
+
+/// Import the specular cubemap of an image-based light, loading external
+/// references with the given `resolver`.
+///
+/// See [`import_image_based_light`] for the layout of the result.
+#[cfg(feature = "EXT_lights_image_based")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_lights_image_based")))]
+pub fn import_image_based_light_with_resolver(
+    light: &ext_lights_image_based::Light<'_>,
+    resolver: &dyn ResourceResolver,
+    buffer_data: &[buffer::Data],
+) -> Result<Vec<[image::Data; 6]>> {
+    let decode = |image: &crate::Image<'_>| {
+        image::Data::from_source_with_resolver(image.source(), resolver, buffer_data)
+    };
+    light
+        .specular_images()
+        .map(|[px, nx, py, ny, pz, nz]| {
+            Ok([
+                decode(&px)?,
+                decode(&nx)?,
+                decode(&py)?,
+                decode(&ny)?,
+                decode(&pz)?,
+                decode(&nz)?,
+            ])
+        })
+        .collect()
+}
+
+fn import_impl(
+    Gltf { document, blob }: Gltf,
+    resolver: &dyn ResourceResolver,
+    options: &LoadOptions,
+) -> Result<Import> {
+    // Compressed meshes are decoded from the buffers even if these are not
+    // returned, so that the document never refers to compressed data.
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    let decompress = document
+        .extensions_used()
+        .any(|name| name == "KHR_draco_mesh_compression");
+    #[cfg(not(feature = "KHR_draco_mesh_compression"))]
+    let decompress = false;
+    if !options.load_buffers && !options.load_images && !decompress {
+        return Ok((document, Vec::new(), Vec::new()));
+    }
+    let buffer_data = import_buffers_with_resolver(&document, resolver, blob)?;
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    let (document, buffer_data) = decompress_meshes(document, buffer_data)?;
+    let image_data = if options.load_images {
+        import_images_with_resolver(&document, resolver, &buffer_data)?
+    } else {
+        Vec::new()
+    };
+    let buffer_data = if options.load_buffers {
+        buffer_data
+    } else {
+        Vec::new()
+    };
     let import = (document, buffer_data, image_data);
     Ok(import)
 }
 
-fn import_path(path: &Path) -> Result<Import> {
+fn import_path(path: &Path, options: &LoadOptions) -> Result<Import> {
     let base = path.parent().unwrap_or_else(|| Path::new("./"));
     let file = fs::File::open(path).map_err(Error::Io)?;
     let reader = io::BufReader::new(file);
-    import_impl(Gltf::from_reader(reader)?, Some(base))
+    import_impl(
+        Gltf::from_reader_with_options(reader, options)?,
+        &DefaultResolver::new(Some(base)),
+        options,
+    )
 }
 
 /// Import glTF 2.0 from the file system.
@@ -286,11 +757,29 @@ pub fn import<P>(path: P) -> Result<Import>
 where
     P: AsRef<Path>,
 {
-    import_path(path.as_ref())
+    import_path(path.as_ref(), &LoadOptions::default())
+}
+
+/// Import glTF 2.0 from the file system according to the given `options`.
+///
+/// Buffers and images that are not selected for loading are returned empty.
+pub fn import_with_options<P>(path: P, options: &LoadOptions) -> Result<Import>
+where
+    P: AsRef<Path>,
+{
+    import_path(path.as_ref(), options)
 }
 
-fn import_slice_impl(slice: &[u8]) -> Result<Import> {
-    import_impl(Gltf::from_slice(slice)?, None)
+fn import_slice_impl(
+    slice: &[u8],
+    resolver: &dyn ResourceResolver,
+    options: &LoadOptions,
+) -> Result<Import> {
+    import_impl(
+        Gltf::from_slice_with_options(slice, options)?,
+        resolver,
+        options,
+    )
 }
 
 /// Import glTF 2.0 from a slice.
@@ -324,5 +813,106 @@ pub fn import_slice<S>(slice: S) -> Result<Import>
 where
     S: AsRef<[u8]>,
 {
-    import_slice_impl(slice.as_ref())
+    import_slice_impl(
+        slice.as_ref(),
+        &DefaultResolver::new(None),
+        &LoadOptions::default(),
+    )
+}
+
+/// Import glTF 2.0 from a slice, loading external references with the given
+/// `resolver`.
+///
+/// ### Note
+///
+/// This function is intended for advanced users.
+/// A typical user should call [`import`] instead.
+pub fn import_slice_with_resolver<S>(slice: S, resolver: &dyn ResourceResolver) -> Result<Import>
+where
+    S: AsRef<[u8]>,
+{
+    import_slice_impl(slice.as_ref(), resolver, &LoadOptions::default())
+}
+
+/// Import glTF 2.0 from a slice according to the given `options`, loading
+/// external references with the given `resolver`.
+///
+/// Buffers and images that are not selected for loading are returned empty.
+pub fn import_slice_with_options<S>(
+    slice: S,
+    resolver: &dyn ResourceResolver,
+    options: &LoadOptions,
+) -> Result<Import>
+where
+    S: AsRef<[u8]>,
+{
+    import_slice_impl(slice.as_ref(), resolver, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes a 1x1 RGBA image as PNG.
+    fn png() -> Vec<u8> {
+        let mut png = io::Cursor::new(Vec::new());
+        image_crate::write_buffer_with_format(
+            &mut png,
+            &[255, 0, 0, 255],
+            1,
+            1,
+            image_crate::ExtendedColorType::Rgba8,
+            image_crate::ImageFormat::Png,
+        )
+        .unwrap();
+        png.into_inner()
+    }
+
+    #[test]
+    fn custom_resolver() {
+        let gltf = fs::read("examples/Box.gltf").unwrap();
+        let bin = fs::read("examples/Box0.bin").unwrap();
+        let resolver = |uri: &str| match uri {
+            "Box0.bin" => Ok(bin.clone()),
+            _ => Err(Error::UnsupportedScheme),
+        };
+        let (_, buffers, _) = import_slice_with_resolver(&gltf, &resolver).unwrap();
+        assert_eq!(buffers.len(), 1);
+        assert_eq!(&buffers[0][..bin.len()], &bin[..]);
+    }
+
+    #[test]
+    fn missing_resource() {
+        let gltf = fs::read("examples/Box.gltf").unwrap();
+        let resolver = |_: &str| Err(Error::Io(io::ErrorKind::NotFound.into()));
+        match import_slice_with_resolver(&gltf, &resolver) {
+            Err(Error::Io(error)) => assert_eq!(error.kind(), io::ErrorKind::NotFound),
+            result => panic!("unexpected result: {:?}", result.map(|_| ())),
+        }
+
+        // Without a base directory, relative URIs cannot be resolved.
+        match import_slice(&gltf) {
+            Err(Error::ExternalReferenceInSliceImport) => {}
+            result => panic!("unexpected result: {:?}", result.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn data_uri() {
+        let uri = format!("data:image/png;base64,{}", base64::encode(png()));
+        assert_eq!(DefaultResolver::new(None).resolve(&uri).unwrap(), png());
+
+        // Data URIs are decoded without a base directory, and their media
+        // type takes precedence over `mimeType`.
+        let gltf = format!(
+            r#"{{
+                "asset": {{"version": "2.0"}},
+                "images": [{{"uri": "{}", "mimeType": "image/jpeg"}}]
+            }}"#,
+            uri
+        );
+        let (_, _, images) = import_slice(gltf.as_bytes()).unwrap();
+        assert_eq!((images[0].width, images[0].height), (1, 1));
+        assert_eq!(images[0].pixels, [255, 0, 0, 255]);
+    }
 }
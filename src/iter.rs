@@ -92,6 +92,18 @@ pub struct Lights<'a> {
     pub(crate) document: &'a Document,
 }
 
+/// An `Iterator` that visits every image-based light in a glTF asset.
+#[cfg(feature = "EXT_lights_image_based")]
+#[derive(Clone, Debug)]
+pub struct ImageBasedLights<'a> {
+    /// Internal light iterator.
+    pub(crate) iter:
+        iter::Enumerate<slice::Iter<'a, json::extensions::scene::ext_lights_image_based::Light>>,
+
+    /// The internal root glTF object.
+    pub(crate) document: &'a Document,
+}
+
 /// An `Iterator` that visits every variant in a glTF asset.
 #[cfg(feature = "KHR_materials_variants")]
 #[derive(Clone, Debug)]
@@ -104,6 +116,18 @@ pub struct Variants<'a> {
     pub(crate) document: &'a Document,
 }
 
+/// An `Iterator` that visits every metadata packet in a glTF asset.
+#[cfg(feature = "KHR_xmp_json_ld")]
+#[derive(Clone, Debug)]
+pub struct Packets<'a> {
+    /// Internal packet iterator.
+    pub(crate) iter:
+        iter::Enumerate<slice::Iter<'a, json::extensions::root::khr_xmp_json_ld::Packet>>,
+
+    /// The internal root glTF object.
+    pub(crate) document: &'a Document,
+}
+
 /// An `Iterator` that visits every material in a glTF asset.
 #[derive(Clone, Debug)]
 pub struct Materials<'a> {
@@ -188,10 +212,10 @@ impl<'a> Iterator for Accessors<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Accessor::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -215,10 +239,10 @@ impl<'a> Iterator for Animations<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Animation::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -242,10 +266,10 @@ impl<'a> Iterator for Buffers<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Buffer::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -309,10 +333,10 @@ impl<'a> Iterator for Views<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| View::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -336,10 +360,10 @@ impl<'a> Iterator for Cameras<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Camera::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -363,10 +387,10 @@ impl<'a> Iterator for Images<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Image::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -393,10 +417,10 @@ impl<'a> Iterator for Lights<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| crate::khr_lights_punctual::Light::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -406,6 +430,36 @@ impl<'a> Iterator for Lights<'a> {
     }
 }
 
+#[cfg(feature = "EXT_lights_image_based")]
+impl<'a> ExactSizeIterator for ImageBasedLights<'a> {}
+
+#[cfg(feature = "EXT_lights_image_based")]
+impl<'a> Iterator for ImageBasedLights<'a> {
+    type Item = crate::ext_lights_image_based::Light<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(index, json)| {
+            crate::ext_lights_image_based::Light::new(self.document, index, json)
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .next_back()
+            .map(|(index, json)| crate::ext_lights_image_based::Light::new(document, index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| {
+            crate::ext_lights_image_based::Light::new(self.document, index, json)
+        })
+    }
+}
+
 #[cfg(feature = "KHR_materials_variants")]
 impl<'a> ExactSizeIterator for Variants<'a> {}
 
@@ -423,10 +477,10 @@ impl<'a> Iterator for Variants<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| crate::khr_materials_variants::Variant::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -436,6 +490,36 @@ impl<'a> Iterator for Variants<'a> {
     }
 }
 
+#[cfg(feature = "KHR_xmp_json_ld")]
+impl<'a> ExactSizeIterator for Packets<'a> {}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+impl<'a> Iterator for Packets<'a> {
+    type Item = crate::khr_xmp_json_ld::Packet<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| crate::khr_xmp_json_ld::Packet::new(self.document, index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .next_back()
+            .map(|(index, json)| crate::khr_xmp_json_ld::Packet::new(document, index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter
+            .nth(n)
+            .map(|(index, json)| crate::khr_xmp_json_ld::Packet::new(self.document, index, json))
+    }
+}
+
 impl<'a> ExactSizeIterator for Materials<'a> {}
 impl<'a> Iterator for Materials<'a> {
     type Item = Material<'a>;
@@ -450,10 +534,10 @@ impl<'a> Iterator for Materials<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Material::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -477,10 +561,10 @@ impl<'a> Iterator for Meshes<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Mesh::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -504,10 +588,10 @@ impl<'a> Iterator for Nodes<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Node::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -531,10 +615,10 @@ impl<'a> Iterator for Samplers<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Sampler::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -558,10 +642,10 @@ impl<'a> Iterator for Scenes<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Scene::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -585,10 +669,10 @@ impl<'a> Iterator for Skins<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Skin::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -612,10 +696,10 @@ impl<'a> Iterator for Textures<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Texture::new(document, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
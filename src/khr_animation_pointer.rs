@@ -0,0 +1,100 @@
+use crate::{Camera, Document, Material, Mesh, Node};
+
+pub use json::extensions::animation::khr_animation_pointer::ValueType;
+
+#[cfg(feature = "KHR_lights_punctual")]
+use crate::khr_lights_punctual::Light;
+
+/// The property of an object targeted by an animation channel through a JSON
+/// pointer.
+#[derive(Clone, Debug)]
+pub struct Pointer<'a> {
+    /// The object containing the animated property.
+    object: Object<'a>,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::animation::khr_animation_pointer::AnimationPointer,
+
+    /// The pointer to the property relative to the object.
+    property: &'a str,
+
+    /// The type of the values of the property.
+    value_type: ValueType,
+}
+
+/// An object whose properties are animated through a JSON pointer.
+#[derive(Clone, Debug)]
+pub enum Object<'a> {
+    /// A camera.
+    Camera(Camera<'a>),
+
+    /// A punctual light.
+    #[cfg(feature = "KHR_lights_punctual")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
+    Light(Light<'a>),
+
+    /// A material.
+    Material(Material<'a>),
+
+    /// A mesh.
+    Mesh(Mesh<'a>),
+
+    /// A node.
+    Node(Node<'a>),
+}
+
+impl<'a> Pointer<'a> {
+    /// Constructs a `Pointer`, returning `None` if the JSON pointer does not
+    /// resolve to an animatable property of an object of the document.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::animation::khr_animation_pointer::AnimationPointer,
+    ) -> Option<Self> {
+        use json::extensions::animation::khr_animation_pointer::Object as Json;
+        let resolved = json.resolve()?;
+        let object = match resolved.object {
+            Json::Camera(index) => Object::Camera(document.cameras().nth(index.value())?),
+            #[cfg(feature = "KHR_lights_punctual")]
+            Json::Light(index) => Object::Light(document.lights()?.nth(index.value())?),
+            Json::Material(index) => Object::Material(document.materials().nth(index.value())?),
+            Json::Mesh(index) => Object::Mesh(document.meshes().nth(index.value())?),
+            Json::Node(index) => Object::Node(document.nodes().nth(index.value())?),
+        };
+        Some(Self {
+            object,
+            json,
+            property: resolved.property,
+            value_type: resolved.value_type,
+        })
+    }
+
+    /// Returns the JSON pointer, for example
+    /// `/materials/0/pbrMetallicRoughness/baseColorFactor`.
+    pub fn pointer(&self) -> &'a str {
+        &self.json.pointer
+    }
+
+    /// Returns the object containing the animated property.
+    pub fn object(&self) -> Object<'a> {
+        self.object.clone()
+    }
+
+    /// Returns the pointer to the animated property relative to the object,
+    /// for example `pbrMetallicRoughness/baseColorFactor`.
+    pub fn property(&self) -> &'a str {
+        self.property
+    }
+
+    /// Returns the type of the values of the animated property.
+    ///
+    /// The keyframe output accessor of the channel has the matching
+    /// [`accessor::Dimensions`](crate::accessor::Dimensions).
+    pub fn value_type(&self) -> ValueType {
+        self.value_type
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
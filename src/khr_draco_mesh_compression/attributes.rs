@@ -0,0 +1,1401 @@
+use super::bits::{decode_symbols, to_signed, BitDecoder, Reader};
+use super::connectivity::{around_corner, next, previous, Connectivity, Corners, INVALID};
+use super::{Attribute, Error, Values};
+
+/// The type of position attributes.
+const POSITION: u8 = 0;
+
+/// The number of attribute types.
+const NUM_ATTRIBUTE_TYPES: u8 = 5;
+
+/// Attribute data types.
+const INT8: u8 = 1;
+const UINT8: u8 = 2;
+const INT16: u8 = 3;
+const UINT16: u8 = 4;
+const INT32: u8 = 5;
+const UINT32: u8 = 6;
+const INT64: u8 = 7;
+const UINT64: u8 = 8;
+const FLOAT32: u8 = 9;
+const FLOAT64: u8 = 10;
+const BOOL: u8 = 11;
+
+/// Whether the attributes of a decoder have one value per vertex or one
+/// value per corner.
+const VERTEX_ATTRIBUTE: u8 = 0;
+const CORNER_ATTRIBUTE: u8 = 1;
+
+/// Orders in which the vertices of a mesh are traversed.
+const DEPTH_FIRST: u8 = 0;
+const PREDICTION_DEGREE: u8 = 1;
+
+/// Methods of coding attribute values.
+const GENERIC: u8 = 0;
+const INTEGER: u8 = 1;
+const QUANTIZATION: u8 = 2;
+const NORMALS: u8 = 3;
+
+/// Methods of predicting attribute values.
+const PREDICTION_NONE: i8 = -2;
+const PREDICTION_DIFFERENCE: i8 = 0;
+const PREDICTION_PARALLELOGRAM: i8 = 1;
+const PREDICTION_MULTI_PARALLELOGRAM: i8 = 2;
+const PREDICTION_CONSTRAINED_MULTI_PARALLELOGRAM: i8 = 4;
+const PREDICTION_TEX_COORDS_PORTABLE: i8 = 5;
+const PREDICTION_GEOMETRIC_NORMAL: i8 = 6;
+
+/// Transforms between predicted values and corrections.
+const TRANSFORM_WRAP: i8 = 1;
+const TRANSFORM_NORMAL_OCTAHEDRON: i8 = 2;
+const TRANSFORM_NORMAL_OCTAHEDRON_CANONICALIZED: i8 = 3;
+
+/// The largest number of parallelograms used to predict a value.
+const MAX_PARALLELOGRAMS: usize = 4;
+
+/// The number of priorities of the prediction degree traversal.
+const MAX_PRIORITY: usize = 3;
+
+/// The description of a coded attribute.
+struct Header {
+    attribute_type: u8,
+    data_type: u8,
+    components: usize,
+    normalized: bool,
+    unique_id: u32,
+    coding: u8,
+}
+
+/// A group of attributes coded in the same order.
+struct Group {
+    attribute_data: Option<usize>,
+    corner_attribute: bool,
+    traversal: u8,
+    headers: Vec<Header>,
+}
+
+/// Connectivity used to predict the values of a group of attributes.
+struct MeshData<'a> {
+    table: &'a dyn Corners,
+    vertex_to_value: Vec<u32>,
+    value_to_corner: Vec<u32>,
+}
+
+impl<'a> MeshData<'a> {
+    fn value(&self, corner: u32) -> usize {
+        let vertex = self.table.vertex(corner);
+        self.vertex_to_value
+            .get(vertex as usize)
+            .map_or(usize::MAX, |&value| value as usize)
+    }
+}
+
+/// The order in which the values of a group are coded and the values used by
+/// each point.
+struct Sequence<'a> {
+    /// The point of every value.
+    point_ids: Vec<u32>,
+
+    /// The value of every point.
+    point_to_value: Vec<u32>,
+
+    mesh: Option<MeshData<'a>>,
+}
+
+/// The values of the first position attribute, which are used to predict
+/// texture coordinates and normals.
+struct Positions {
+    values: Vec<i64>,
+    point_to_value: Vec<u32>,
+}
+
+impl Positions {
+    fn get(&self, point_ids: &[u32], value: usize) -> Result<[i64; 3], Error> {
+        let point = *point_ids.get(value).ok_or(Error::Invalid)?;
+        let index = *self
+            .point_to_value
+            .get(point as usize)
+            .ok_or(Error::Invalid)? as usize;
+        let position = self
+            .values
+            .get(3 * index..3 * index + 3)
+            .ok_or(Error::Invalid)?;
+        Ok([position[0], position[1], position[2]])
+    }
+}
+
+/// Decodes the attributes of a mesh.
+pub(crate) fn decode(
+    reader: &mut Reader,
+    connectivity: &Connectivity,
+) -> Result<Vec<Attribute>, Error> {
+    let num_groups = usize::from(reader.u8()?);
+    let mut groups = Vec::with_capacity(num_groups);
+    let mut has_position_data = false;
+    for _ in 0..num_groups {
+        let mut group = Group {
+            attribute_data: None,
+            corner_attribute: false,
+            traversal: DEPTH_FIRST,
+            headers: Vec::new(),
+        };
+        if connectivity.table.is_some() {
+            let attribute_data = reader.i8()?;
+            if attribute_data >= 0 {
+                let index = attribute_data as usize;
+                if index >= connectivity.attribute_tables.len() {
+                    return Err(Error::Invalid);
+                }
+                group.attribute_data = Some(index);
+            } else if has_position_data {
+                return Err(Error::Invalid);
+            } else {
+                has_position_data = true;
+            }
+            group.corner_attribute = match reader.u8()? {
+                VERTEX_ATTRIBUTE => false,
+                CORNER_ATTRIBUTE => true,
+                _ => return Err(Error::Invalid),
+            };
+            group.traversal = reader.u8()?;
+            if group.traversal > PREDICTION_DEGREE
+                || (group.corner_attribute
+                    && (group.traversal != DEPTH_FIRST || group.attribute_data.is_none()))
+            {
+                return Err(Error::Invalid);
+            }
+        }
+        groups.push(group);
+    }
+
+    for group in &mut groups {
+        let num_attributes = reader.varint32()? as usize;
+        if num_attributes == 0 || num_attributes > 5 * reader.remaining() {
+            return Err(Error::Invalid);
+        }
+        for _ in 0..num_attributes {
+            let attribute_type = reader.u8()?;
+            let data_type = reader.u8()?;
+            let components = usize::from(reader.u8()?);
+            let normalized = reader.u8()? != 0;
+            let unique_id = reader.varint32()?;
+            if attribute_type >= NUM_ATTRIBUTE_TYPES
+                || !(INT8..=BOOL).contains(&data_type)
+                || components == 0
+            {
+                return Err(Error::Invalid);
+            }
+            group.headers.push(Header {
+                attribute_type,
+                data_type,
+                components,
+                normalized,
+                unique_id,
+                coding: GENERIC,
+            });
+        }
+        for header in &mut group.headers {
+            header.coding = reader.u8()?;
+            match header.coding {
+                GENERIC | INTEGER => {}
+                QUANTIZATION if header.data_type == FLOAT32 => {}
+                NORMALS if header.data_type == FLOAT32 && header.components == 3 => {}
+                _ => return Err(Error::Unsupported("attribute coding")),
+            }
+        }
+    }
+
+    let mut attributes = Vec::new();
+    let mut positions: Option<Positions> = None;
+    for group in &groups {
+        let sequence = sequence(group, connectivity)?;
+        let mut portables = Vec::with_capacity(group.headers.len());
+        for header in &group.headers {
+            let portable = decode_portable(reader, header, &sequence, positions.as_ref())?;
+            if header.attribute_type == POSITION && positions.is_none() {
+                let values = match &portable {
+                    Values::Integer(values) => values.iter().map(|&value| value.into()).collect(),
+                    Values::Float(values) => values.iter().map(|&value| value as i64).collect(),
+                };
+                positions = Some(Positions {
+                    values,
+                    point_to_value: sequence.point_to_value.clone(),
+                });
+            }
+            portables.push(portable);
+        }
+        let mut outputs = Vec::with_capacity(group.headers.len());
+        for header in &group.headers {
+            outputs.push(decode_output(reader, header)?);
+        }
+        for ((header, portable), output) in group.headers.iter().zip(portables).zip(outputs) {
+            let (components, values) = output.apply(header, portable)?;
+            attributes.push(Attribute {
+                unique_id: header.unique_id,
+                components,
+                normalized: header.normalized,
+                values: per_point(values, components, &sequence.point_to_value)?,
+            });
+        }
+    }
+    Ok(attributes)
+}
+
+/// Expands the values of an attribute to one value for every point.
+fn per_point(values: Values, components: usize, point_to_value: &[u32]) -> Result<Values, Error> {
+    fn expand<T: Copy>(
+        values: &[T],
+        components: usize,
+        point_to_value: &[u32],
+    ) -> Result<Vec<T>, Error> {
+        let mut output = Vec::with_capacity(components * point_to_value.len());
+        for &value in point_to_value {
+            let start = value as usize * components;
+            let value = values
+                .get(start..start + components)
+                .ok_or(Error::Invalid)?;
+            output.extend_from_slice(value);
+        }
+        Ok(output)
+    }
+    Ok(match values {
+        Values::Integer(values) => Values::Integer(expand(&values, components, point_to_value)?),
+        Values::Float(values) => Values::Float(expand(&values, components, point_to_value)?),
+    })
+}
+
+/// Returns the order in which the values of a group are coded.
+fn sequence<'a>(group: &Group, connectivity: &'a Connectivity) -> Result<Sequence<'a>, Error> {
+    let table = match connectivity.table.as_ref() {
+        Some(table) => table,
+        None => {
+            let identity: Vec<u32> = (0..connectivity.num_points as u32).collect();
+            return Ok(Sequence {
+                point_ids: identity.clone(),
+                point_to_value: identity,
+                mesh: None,
+            });
+        }
+    };
+    let table: &dyn Corners = match group.attribute_data {
+        Some(index) if group.corner_attribute => &connectivity.attribute_tables[index],
+        _ => table,
+    };
+
+    let mut traversal = Traversal {
+        table,
+        faces: &connectivity.faces,
+        visited_faces: vec![false; table.num_faces()],
+        visited_vertices: vec![false; table.num_vertices()],
+        prediction_degrees: vec![0; table.num_vertices()],
+        stacks: Default::default(),
+        best_priority: 0,
+        point_ids: Vec::new(),
+        vertex_to_value: vec![INVALID; table.num_vertices()],
+        value_to_corner: Vec::new(),
+    };
+    for face in 0..table.num_faces() as u32 {
+        if group.traversal == PREDICTION_DEGREE {
+            traversal.prediction_degree_from(3 * face)?;
+        } else {
+            traversal.depth_first_from(3 * face)?;
+        }
+    }
+
+    let mut point_to_value = vec![INVALID; connectivity.num_points];
+    for (corner, &point) in connectivity.faces.iter().enumerate() {
+        let vertex = table.vertex(corner as u32);
+        let value = *traversal
+            .vertex_to_value
+            .get(vertex as usize)
+            .ok_or(Error::Invalid)?;
+        if value == INVALID {
+            return Err(Error::Invalid);
+        }
+        *point_to_value
+            .get_mut(point as usize)
+            .ok_or(Error::Invalid)? = value;
+    }
+    if point_to_value.contains(&INVALID) {
+        return Err(Error::Invalid);
+    }
+    Ok(Sequence {
+        point_ids: traversal.point_ids,
+        point_to_value,
+        mesh: Some(MeshData {
+            table,
+            vertex_to_value: traversal.vertex_to_value,
+            value_to_corner: traversal.value_to_corner,
+        }),
+    })
+}
+
+/// Visits the vertices of a mesh in the order in which the encoder coded
+/// their values.
+struct Traversal<'a> {
+    table: &'a dyn Corners,
+    faces: &'a [u32],
+    visited_faces: Vec<bool>,
+    visited_vertices: Vec<bool>,
+    prediction_degrees: Vec<u32>,
+    stacks: [Vec<u32>; MAX_PRIORITY],
+    best_priority: usize,
+    point_ids: Vec<u32>,
+    vertex_to_value: Vec<u32>,
+    value_to_corner: Vec<u32>,
+}
+
+impl<'a> Traversal<'a> {
+    fn is_face_visited(&self, corner: u32) -> bool {
+        corner == INVALID || self.visited_faces[(corner / 3) as usize]
+    }
+
+    fn is_vertex_visited(&self, vertex: u32) -> bool {
+        self.visited_vertices[vertex as usize]
+    }
+
+    /// Records the next value, which belongs to the point at `corner`.
+    fn visit_vertex(&mut self, vertex: u32, corner: u32) -> Result<(), Error> {
+        let point = *self.faces.get(corner as usize).ok_or(Error::Invalid)?;
+        self.visited_vertices[vertex as usize] = true;
+        self.vertex_to_value[vertex as usize] = self.point_ids.len() as u32;
+        self.point_ids.push(point);
+        self.value_to_corner.push(corner);
+        Ok(())
+    }
+
+    fn vertex(&self, corner: u32) -> Result<u32, Error> {
+        let vertex = self.table.vertex(corner);
+        if vertex as usize >= self.visited_vertices.len() {
+            return Err(Error::Invalid);
+        }
+        Ok(vertex)
+    }
+
+    /// Visits the vertices at the edge opposite to `corner`.
+    fn visit_edge(&mut self, corner: u32) -> Result<(), Error> {
+        for corner in [next(corner), previous(corner)] {
+            let vertex = self.vertex(corner)?;
+            if !self.is_vertex_visited(vertex) {
+                self.visit_vertex(vertex, corner)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn depth_first_from(&mut self, corner: u32) -> Result<(), Error> {
+        if self.is_face_visited(corner) {
+            return Ok(());
+        }
+        self.visit_edge(corner)?;
+        let mut stack = vec![corner];
+        while let Some(&top) = stack.last() {
+            let mut corner = top;
+            if self.is_face_visited(corner) {
+                stack.pop();
+                continue;
+            }
+            loop {
+                if corner == INVALID {
+                    return Err(Error::Invalid);
+                }
+                self.visited_faces[(corner / 3) as usize] = true;
+                let vertex = self.vertex(corner)?;
+                if !self.is_vertex_visited(vertex) {
+                    let on_boundary = self.table.is_on_boundary(vertex);
+                    self.visit_vertex(vertex, corner)?;
+                    if !on_boundary {
+                        corner = self.table.right_corner(corner);
+                        continue;
+                    }
+                }
+                let right = self.table.right_corner(corner);
+                let left = self.table.left_corner(corner);
+                match (self.is_face_visited(right), self.is_face_visited(left)) {
+                    (true, true) => {
+                        stack.pop();
+                        break;
+                    }
+                    (true, false) => corner = left,
+                    (false, true) => corner = right,
+                    (false, false) => {
+                        // Traverse the right face first and the left face
+                        // afterwards.
+                        *stack.last_mut().unwrap() = left;
+                        stack.push(right);
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn priority(&mut self, corner: u32) -> Result<usize, Error> {
+        let vertex = self.vertex(corner)?;
+        if self.is_vertex_visited(vertex) {
+            return Ok(0);
+        }
+        let degree = &mut self.prediction_degrees[vertex as usize];
+        *degree += 1;
+        Ok(if *degree > 1 { 1 } else { 2 })
+    }
+
+    fn push(&mut self, corner: u32, priority: usize) {
+        self.stacks[priority].push(corner);
+        self.best_priority = self.best_priority.min(priority);
+    }
+
+    fn pop(&mut self) -> Option<u32> {
+        for priority in self.best_priority..MAX_PRIORITY {
+            if let Some(corner) = self.stacks[priority].pop() {
+                self.best_priority = priority;
+                return Some(corner);
+            }
+        }
+        None
+    }
+
+    fn prediction_degree_from(&mut self, corner: u32) -> Result<(), Error> {
+        self.push(corner, 0);
+        self.best_priority = 0;
+        self.visit_edge(corner)?;
+        let tip = self.vertex(corner)?;
+        if !self.is_vertex_visited(tip) {
+            self.visit_vertex(tip, corner)?;
+        }
+        while let Some(mut corner) = self.pop() {
+            if self.is_face_visited(corner) {
+                continue;
+            }
+            loop {
+                self.visited_faces[(corner / 3) as usize] = true;
+                let vertex = self.vertex(corner)?;
+                if !self.is_vertex_visited(vertex) {
+                    self.visit_vertex(vertex, corner)?;
+                }
+                let right = self.table.right_corner(corner);
+                let left = self.table.left_corner(corner);
+                let right_visited = self.is_face_visited(right);
+                if !self.is_face_visited(left) {
+                    let priority = self.priority(left)?;
+                    if right_visited && priority <= self.best_priority {
+                        corner = left;
+                        continue;
+                    }
+                    self.push(left, priority);
+                }
+                if !right_visited {
+                    let priority = self.priority(right)?;
+                    if priority <= self.best_priority {
+                        corner = right;
+                        continue;
+                    }
+                    self.push(right, priority);
+                }
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads `count` uncompressed values of the given data type.
+fn read_values(reader: &mut Reader, data_type: u8, count: usize) -> Result<Values, Error> {
+    let size = match data_type {
+        INT8 | UINT8 | BOOL => 1,
+        INT16 | UINT16 => 2,
+        INT32 | UINT32 | FLOAT32 => 4,
+        INT64 | UINT64 | FLOAT64 => 8,
+        _ => return Err(Error::Invalid),
+    };
+    let bytes = reader.bytes(count.checked_mul(size).ok_or(Error::Invalid)?)?;
+    let chunks = bytes.chunks_exact(size);
+    Ok(match data_type {
+        INT8 => Values::Integer(chunks.map(|b| i32::from(b[0] as i8)).collect()),
+        UINT8 | BOOL => Values::Integer(chunks.map(|b| i32::from(b[0])).collect()),
+        INT16 => Values::Integer(
+            chunks
+                .map(|b| i32::from(i16::from_le_bytes([b[0], b[1]])))
+                .collect(),
+        ),
+        UINT16 => Values::Integer(
+            chunks
+                .map(|b| i32::from(u16::from_le_bytes([b[0], b[1]])))
+                .collect(),
+        ),
+        INT32 | UINT32 => Values::Integer(
+            chunks
+                .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect(),
+        ),
+        INT64 | UINT64 => Values::Integer(
+            chunks
+                .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect(),
+        ),
+        FLOAT32 => Values::Float(
+            chunks
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect(),
+        ),
+        _ => Values::Float(
+            chunks
+                .map(|b| {
+                    f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]) as f32
+                })
+                .collect(),
+        ),
+    })
+}
+
+/// Decodes the values of an attribute in the form used for prediction,
+/// such as quantized integers.
+fn decode_portable(
+    reader: &mut Reader,
+    header: &Header,
+    sequence: &Sequence,
+    positions: Option<&Positions>,
+) -> Result<Values, Error> {
+    let num_values = sequence.point_ids.len();
+    if header.coding == GENERIC {
+        let count = num_values
+            .checked_mul(header.components)
+            .ok_or(Error::Invalid)?;
+        return read_values(reader, header.data_type, count);
+    }
+
+    let components = if header.coding == NORMALS {
+        2
+    } else {
+        header.components
+    };
+    let method = reader.i8()?;
+    let transform_type = if method == PREDICTION_NONE {
+        None
+    } else {
+        Some(reader.i8()?)
+    };
+    let octahedron = match transform_type {
+        None => false,
+        Some(TRANSFORM_WRAP) if header.coding != NORMALS => false,
+        Some(TRANSFORM_NORMAL_OCTAHEDRON) | Some(TRANSFORM_NORMAL_OCTAHEDRON_CANONICALIZED)
+            if header.coding == NORMALS =>
+        {
+            true
+        }
+        _ => return Err(Error::Unsupported("prediction transform")),
+    };
+
+    let count = num_values.checked_mul(components).ok_or(Error::Invalid)?;
+    let mut corrections: Vec<i32> = if reader.u8()? != 0 {
+        decode_symbols(reader, count, components)?
+            .into_iter()
+            .map(|symbol| symbol as i32)
+            .collect()
+    } else {
+        let size = usize::from(reader.u8()?);
+        if !(1..=4).contains(&size)
+            || count
+                .checked_mul(size)
+                .map_or(true, |n| n > reader.remaining())
+        {
+            return Err(Error::Invalid);
+        }
+        let mut values = Vec::with_capacity(count);
+        for _ in 0..count {
+            let mut bytes = [0; 4];
+            bytes[..size].copy_from_slice(reader.bytes(size)?);
+            values.push(i32::from_le_bytes(bytes));
+        }
+        values
+    };
+    if !octahedron {
+        for value in &mut corrections {
+            *value = to_signed(*value as u32);
+        }
+    }
+
+    let transform_type = match transform_type {
+        Some(transform_type) => transform_type,
+        None => return Ok(Values::Integer(corrections)),
+    };
+    let scheme = match (&sequence.mesh, method) {
+        (_, PREDICTION_DIFFERENCE) | (None, _) => Scheme::Difference,
+        (Some(_), PREDICTION_PARALLELOGRAM) => Scheme::Parallelogram,
+        (Some(_), PREDICTION_MULTI_PARALLELOGRAM) => Scheme::MultiParallelogram,
+        (Some(_), PREDICTION_CONSTRAINED_MULTI_PARALLELOGRAM) => {
+            let mut is_crease_edge = Vec::with_capacity(MAX_PARALLELOGRAMS);
+            for _ in 0..MAX_PARALLELOGRAMS {
+                let num_flags = reader.varint32()? as usize;
+                if num_flags > count {
+                    return Err(Error::Invalid);
+                }
+                let mut flags = Vec::with_capacity(num_flags);
+                if num_flags > 0 {
+                    let mut decoder = BitDecoder::new(reader)?;
+                    for _ in 0..num_flags {
+                        flags.push(decoder.bit());
+                    }
+                }
+                is_crease_edge.push(flags);
+            }
+            Scheme::ConstrainedMultiParallelogram(is_crease_edge)
+        }
+        (Some(_), PREDICTION_TEX_COORDS_PORTABLE) => {
+            let num_orientations = reader.i32()?;
+            if num_orientations < 0 || num_orientations as usize > num_values {
+                return Err(Error::Invalid);
+            }
+            let mut orientations = Vec::with_capacity(num_orientations as usize);
+            let mut decoder = BitDecoder::new(reader)?;
+            let mut orientation = true;
+            for _ in 0..num_orientations {
+                if !decoder.bit() {
+                    orientation = !orientation;
+                }
+                orientations.push(orientation);
+            }
+            Scheme::TexCoordsPortable(orientations)
+        }
+        (Some(_), PREDICTION_GEOMETRIC_NORMAL) => Scheme::GeometricNormal,
+        _ => return Err(Error::Unsupported("prediction method")),
+    };
+    let transform = match transform_type {
+        TRANSFORM_WRAP => {
+            let min = reader.i32()?;
+            let max = reader.i32()?;
+            let difference = i64::from(max) - i64::from(min);
+            if difference < 0 || difference >= i64::from(i32::MAX) {
+                return Err(Error::Invalid);
+            }
+            Transform::Wrap {
+                min,
+                max,
+                max_difference: 1 + difference as i32,
+            }
+        }
+        _ => {
+            let canonicalized = transform_type == TRANSFORM_NORMAL_OCTAHEDRON_CANONICALIZED;
+            let max_quantized_value = reader.i32()?;
+            if canonicalized {
+                // The center value is derived from the maximum instead.
+                reader.i32()?;
+            }
+            let octahedron = Octahedron::new(max_quantized_value)?;
+            Transform::Octahedron {
+                octahedron,
+                canonicalized,
+            }
+        }
+    };
+    let flips = match scheme {
+        Scheme::GeometricNormal => Some(BitDecoder::new(reader)?),
+        _ => None,
+    };
+
+    let mut values = vec![0; count];
+    let prediction = Prediction {
+        corrections: &corrections,
+        components,
+        transform: &transform,
+    };
+    match (scheme, &sequence.mesh) {
+        (Scheme::Parallelogram, Some(mesh)) => prediction.parallelogram(mesh, &mut values),
+        (Scheme::MultiParallelogram, Some(mesh)) => {
+            prediction.multi_parallelogram(mesh, &mut values)
+        }
+        (Scheme::ConstrainedMultiParallelogram(is_crease_edge), Some(mesh)) => {
+            prediction.constrained_multi_parallelogram(mesh, &is_crease_edge, &mut values)?
+        }
+        (Scheme::TexCoordsPortable(orientations), Some(mesh)) => {
+            let positions = positions.ok_or(Error::Invalid)?;
+            prediction.tex_coords(
+                mesh,
+                positions,
+                &sequence.point_ids,
+                orientations,
+                &mut values,
+            )?
+        }
+        (Scheme::GeometricNormal, Some(mesh)) => {
+            let positions = positions.ok_or(Error::Invalid)?;
+            prediction.geometric_normal(
+                mesh,
+                positions,
+                &sequence.point_ids,
+                flips.unwrap(),
+                &mut values,
+            )?
+        }
+        _ => prediction.difference(&mut values),
+    }
+    Ok(Values::Integer(values))
+}
+
+/// A method of predicting attribute values together with its data.
+enum Scheme {
+    Difference,
+    Parallelogram,
+    MultiParallelogram,
+    ConstrainedMultiParallelogram(Vec<Vec<bool>>),
+    TexCoordsPortable(Vec<bool>),
+    GeometricNormal,
+}
+
+/// Constants of the octahedral mapping of quantized unit vectors.
+#[derive(Clone, Copy, Debug)]
+struct Octahedron {
+    max_quantized_value: i32,
+    max_value: i32,
+    center_value: i32,
+}
+
+impl Octahedron {
+    fn new(max_quantized_value: i32) -> Result<Self, Error> {
+        if max_quantized_value <= 0 || max_quantized_value % 2 == 0 {
+            return Err(Error::Invalid);
+        }
+        let bits = 32 - max_quantized_value.leading_zeros();
+        Self::with_bits(bits)
+    }
+
+    fn with_bits(bits: u32) -> Result<Self, Error> {
+        if !(2..=30).contains(&bits) {
+            return Err(Error::Invalid);
+        }
+        let max_quantized_value = (1 << bits) - 1;
+        let max_value = max_quantized_value - 1;
+        Ok(Self {
+            max_quantized_value,
+            max_value,
+            center_value: max_value / 2,
+        })
+    }
+
+    fn is_in_diamond(&self, s: i32, t: i32) -> bool {
+        s.abs() + t.abs() <= self.center_value
+    }
+
+    fn invert_diamond(&self, s: &mut i32, t: &mut i32) {
+        let (sign_s, sign_t) = if *s >= 0 && *t >= 0 {
+            (1, 1)
+        } else if *s <= 0 && *t <= 0 {
+            (-1, -1)
+        } else {
+            (if *s > 0 { 1 } else { -1 }, if *t > 0 { 1 } else { -1 })
+        };
+        let corner_s = sign_s * self.center_value;
+        let corner_t = sign_t * self.center_value;
+        let mut us = 2 * *s - corner_s;
+        let mut ut = 2 * *t - corner_t;
+        if sign_s * sign_t >= 0 {
+            let temp = us;
+            us = -ut;
+            ut = -temp;
+        } else {
+            std::mem::swap(&mut us, &mut ut);
+        }
+        *s = (us + corner_s) / 2;
+        *t = (ut + corner_t) / 2;
+    }
+
+    fn mod_max(&self, x: i32) -> i32 {
+        if x > self.center_value {
+            x - self.max_quantized_value
+        } else if x < -self.center_value {
+            x + self.max_quantized_value
+        } else {
+            x
+        }
+    }
+
+    /// Scales an integer vector so that the sum of the absolute values of
+    /// its elements is the center value.
+    fn canonicalize_vector(&self, vector: &mut [i64; 3]) {
+        let sum = vector[0].abs() + vector[1].abs() + vector[2].abs();
+        let center = i64::from(self.center_value);
+        if sum == 0 {
+            vector[0] = center;
+        } else {
+            vector[0] = vector[0] * center / sum;
+            vector[1] = vector[1] * center / sum;
+            let z = center - vector[0].abs() - vector[1].abs();
+            vector[2] = if vector[2] >= 0 { z } else { -z };
+        }
+    }
+
+    fn vector_to_coords(&self, vector: &[i64; 3]) -> [i32; 2] {
+        let (x, y, z) = (vector[0] as i32, vector[1] as i32, vector[2] as i32);
+        let (s, t) = if x >= 0 {
+            (y + self.center_value, z + self.center_value)
+        } else {
+            (
+                if y < 0 {
+                    z.abs()
+                } else {
+                    self.max_value - z.abs()
+                },
+                if z < 0 {
+                    y.abs()
+                } else {
+                    self.max_value - y.abs()
+                },
+            )
+        };
+        self.canonicalize_coords(s, t)
+    }
+
+    fn canonicalize_coords(&self, mut s: i32, mut t: i32) -> [i32; 2] {
+        let (max, center) = (self.max_value, self.center_value);
+        if (s == 0 && (t == 0 || t == max)) || (s == max && t == 0) {
+            s = max;
+            t = max;
+        } else if s == 0 && t > center {
+            t = center - (t - center);
+        } else if s == max && t < center {
+            t = center + (center - t);
+        } else if t == max && s < center {
+            s = center + (center - s);
+        } else if t == 0 && s > center {
+            s = center - (s - center);
+        }
+        [s, t]
+    }
+
+    fn coords_to_unit_vector(&self, s: i32, t: i32) -> [f32; 3] {
+        let scale = 2.0 / self.max_value as f32;
+        let mut y = s as f32 * scale - 1.0;
+        let mut z = t as f32 * scale - 1.0;
+        let x = 1.0 - y.abs() - z.abs();
+        let offset = (-x).max(0.0);
+        y += if y < 0.0 { offset } else { -offset };
+        z += if z < 0.0 { offset } else { -offset };
+        let norm_squared = x * x + y * y + z * z;
+        if norm_squared < 1e-6 {
+            [0.0; 3]
+        } else {
+            let d = 1.0 / norm_squared.sqrt();
+            [x * d, y * d, z * d]
+        }
+    }
+}
+
+/// Maps predicted values and corrections to the original values.
+enum Transform {
+    Wrap {
+        min: i32,
+        max: i32,
+        max_difference: i32,
+    },
+    Octahedron {
+        octahedron: Octahedron,
+        canonicalized: bool,
+    },
+}
+
+impl Transform {
+    fn apply(&self, predicted: &[i32], corrections: &[i32], output: &mut [i32]) {
+        match *self {
+            Transform::Wrap {
+                min,
+                max,
+                max_difference,
+            } => {
+                for ((output, &predicted), &correction) in
+                    output.iter_mut().zip(predicted).zip(corrections)
+                {
+                    let mut value = predicted.clamp(min, max).wrapping_add(correction);
+                    if value > max {
+                        value = value.wrapping_sub(max_difference);
+                    } else if value < min {
+                        value = value.wrapping_add(max_difference);
+                    }
+                    *output = value;
+                }
+            }
+            Transform::Octahedron {
+                ref octahedron,
+                canonicalized,
+            } => {
+                let center = octahedron.center_value;
+                let mut s = predicted[0] - center;
+                let mut t = predicted[1] - center;
+                let in_diamond = octahedron.is_in_diamond(s, t);
+                if !in_diamond {
+                    octahedron.invert_diamond(&mut s, &mut t);
+                }
+                let mut rotation = 0;
+                let in_bottom_left = (s == 0 && t == 0) || (s < 0 && t <= 0);
+                if canonicalized && !in_bottom_left {
+                    rotation = match (s.signum(), t.signum()) {
+                        (0, 0) => 0,
+                        (0, 1) => 3,
+                        (0, _) => 1,
+                        (1, y) if y >= 0 => 2,
+                        (1, _) => 1,
+                        (_, y) if y <= 0 => 0,
+                        _ => 3,
+                    };
+                    (s, t) = rotate(s, t, rotation);
+                }
+                let mut os = octahedron.mod_max(s + corrections[0]);
+                let mut ot = octahedron.mod_max(t + corrections[1]);
+                if canonicalized && !in_bottom_left {
+                    (os, ot) = rotate(os, ot, (4 - rotation) % 4);
+                }
+                if !in_diamond {
+                    octahedron.invert_diamond(&mut os, &mut ot);
+                }
+                output[0] = os + center;
+                output[1] = ot + center;
+            }
+        }
+    }
+}
+
+fn rotate(s: i32, t: i32, rotation: u32) -> (i32, i32) {
+    match rotation {
+        1 => (t, -s),
+        2 => (-s, -t),
+        3 => (-t, s),
+        _ => (s, t),
+    }
+}
+
+/// Computes original values from their predictions and corrections.
+struct Prediction<'a> {
+    corrections: &'a [i32],
+    components: usize,
+    transform: &'a Transform,
+}
+
+impl<'a> Prediction<'a> {
+    fn correct(&self, predicted: &[i32], index: usize, values: &mut [i32]) {
+        let c = self.components;
+        self.transform.apply(
+            predicted,
+            &self.corrections[index * c..(index + 1) * c],
+            &mut values[index * c..(index + 1) * c],
+        );
+    }
+
+    /// Predicts every value from the previous value.
+    fn correct_from_previous(&self, index: usize, values: &mut [i32]) {
+        let c = self.components;
+        let (previous, rest) = values.split_at_mut(index * c);
+        self.transform.apply(
+            &previous[(index - 1) * c..],
+            &self.corrections[index * c..(index + 1) * c],
+            &mut rest[..c],
+        );
+    }
+
+    fn num_values(&self) -> usize {
+        self.corrections.len() / self.components
+    }
+
+    fn difference(&self, values: &mut [i32]) {
+        if self.num_values() == 0 {
+            return;
+        }
+        self.correct(&vec![0; self.components], 0, values);
+        for index in 1..self.num_values() {
+            self.correct_from_previous(index, values);
+        }
+    }
+
+    /// Predicts the value at `corner` from the triangle on the other side
+    /// of its opposite edge.
+    fn parallelogram_at(
+        &self,
+        mesh: &MeshData,
+        index: usize,
+        corner: u32,
+        values: &[i32],
+        predicted: &mut [i32],
+    ) -> bool {
+        let opposite = mesh.table.opposite(corner);
+        if opposite == INVALID {
+            return false;
+        }
+        let o = mesh.value(opposite);
+        let n = mesh.value(next(opposite));
+        let p = mesh.value(previous(opposite));
+        if o >= index || n >= index || p >= index {
+            return false;
+        }
+        let c = self.components;
+        for (i, predicted) in predicted.iter_mut().enumerate() {
+            *predicted = values[n * c + i]
+                .wrapping_add(values[p * c + i])
+                .wrapping_sub(values[o * c + i]);
+        }
+        true
+    }
+
+    fn parallelogram(&self, mesh: &MeshData, values: &mut [i32]) {
+        if self.num_values() == 0 {
+            return;
+        }
+        let mut predicted = vec![0; self.components];
+        self.correct(&predicted, 0, values);
+        for index in 1..mesh.value_to_corner.len().min(self.num_values()) {
+            let corner = mesh.value_to_corner[index];
+            if self.parallelogram_at(mesh, index, corner, values, &mut predicted) {
+                self.correct(&predicted, index, values);
+            } else {
+                self.correct_from_previous(index, values);
+            }
+        }
+    }
+
+    fn multi_parallelogram(&self, mesh: &MeshData, values: &mut [i32]) {
+        if self.num_values() == 0 {
+            return;
+        }
+        let mut predicted = vec![0; self.components];
+        let mut parallelogram = vec![0; self.components];
+        self.correct(&predicted, 0, values);
+        for index in 1..mesh.value_to_corner.len().min(self.num_values()) {
+            let start = mesh.value_to_corner[index];
+            let mut corner = start;
+            let mut count = 0;
+            predicted.iter_mut().for_each(|value| *value = 0);
+            while corner != INVALID {
+                if self.parallelogram_at(mesh, index, corner, values, &mut parallelogram) {
+                    for (sum, value) in predicted.iter_mut().zip(&parallelogram) {
+                        *sum = sum.wrapping_add(*value);
+                    }
+                    count += 1;
+                }
+                corner = mesh.table.swing_right(corner);
+                if corner == start {
+                    break;
+                }
+            }
+            if count == 0 {
+                self.correct_from_previous(index, values);
+            } else {
+                predicted.iter_mut().for_each(|value| *value /= count);
+                self.correct(&predicted, index, values);
+            }
+        }
+    }
+
+    fn constrained_multi_parallelogram(
+        &self,
+        mesh: &MeshData,
+        is_crease_edge: &[Vec<bool>],
+        values: &mut [i32],
+    ) -> Result<(), Error> {
+        if self.num_values() == 0 {
+            return Ok(());
+        }
+        let c = self.components;
+        let mut parallelograms = vec![0; MAX_PARALLELOGRAMS * c];
+        let mut predicted = vec![0; c];
+        let mut positions = [0; MAX_PARALLELOGRAMS];
+        self.correct(&predicted, 0, values);
+        for index in 1..mesh.value_to_corner.len().min(self.num_values()) {
+            let start = mesh.value_to_corner[index];
+            let mut corner = start;
+            let mut count = 0;
+            let mut first_pass = true;
+            // Swing left from the start and then right once a boundary is
+            // reached.
+            while corner != INVALID {
+                let slot = &mut parallelograms[count * c..(count + 1) * c];
+                if self.parallelogram_at(mesh, index, corner, values, slot) {
+                    count += 1;
+                    if count == MAX_PARALLELOGRAMS {
+                        break;
+                    }
+                }
+                corner = if first_pass {
+                    mesh.table.swing_left(corner)
+                } else {
+                    mesh.table.swing_right(corner)
+                };
+                if corner == start {
+                    break;
+                }
+                if corner == INVALID && first_pass {
+                    first_pass = false;
+                    corner = mesh.table.swing_right(start);
+                }
+            }
+
+            let mut used = 0;
+            predicted.iter_mut().for_each(|value| *value = 0);
+            if count > 0 {
+                let context = count - 1;
+                for i in 0..count {
+                    let position = positions[context];
+                    positions[context] += 1;
+                    let is_crease = *is_crease_edge[context]
+                        .get(position)
+                        .ok_or(Error::Invalid)?;
+                    if !is_crease {
+                        used += 1;
+                        for (sum, value) in predicted.iter_mut().zip(&parallelograms[i * c..]) {
+                            *sum = sum.wrapping_add(*value);
+                        }
+                    }
+                }
+            }
+            if used == 0 {
+                self.correct_from_previous(index, values);
+            } else {
+                predicted.iter_mut().for_each(|value| *value /= used);
+                self.correct(&predicted, index, values);
+            }
+        }
+        Ok(())
+    }
+
+    /// Predicts texture coordinates from the shape of the triangle in
+    /// position space.
+    fn tex_coords(
+        &self,
+        mesh: &MeshData,
+        positions: &Positions,
+        point_ids: &[u32],
+        mut orientations: Vec<bool>,
+        values: &mut [i32],
+    ) -> Result<(), Error> {
+        if self.components != 2 {
+            return Err(Error::Invalid);
+        }
+        for index in 0..mesh.value_to_corner.len().min(self.num_values()) {
+            let corner = mesh.value_to_corner[index];
+            let next_index = mesh.value(next(corner));
+            let previous_index = mesh.value(previous(corner));
+            let uv = |index: usize| {
+                [
+                    i64::from(values[2 * index]),
+                    i64::from(values[2 * index + 1]),
+                ]
+            };
+            let mut predicted = None;
+            if previous_index < index && next_index < index {
+                let next_uv = uv(next_index);
+                let previous_uv = uv(previous_index);
+                if next_uv == previous_uv {
+                    predicted = Some(previous_uv);
+                } else {
+                    let tip = positions.get(point_ids, index)?;
+                    let next_position = positions.get(point_ids, next_index)?;
+                    let previous_position = positions.get(point_ids, previous_index)?;
+                    let pn = sub3(previous_position, next_position);
+                    let pn_norm_squared = dot3(pn, pn);
+                    if pn_norm_squared != 0 {
+                        let cn = sub3(tip, next_position);
+                        let cn_dot_pn = dot3(pn, cn);
+                        let pn_uv = [previous_uv[0] - next_uv[0], previous_uv[1] - next_uv[1]];
+                        let x_uv = [
+                            next_uv[0]
+                                .wrapping_mul(pn_norm_squared)
+                                .wrapping_add(cn_dot_pn.wrapping_mul(pn_uv[0])),
+                            next_uv[1]
+                                .wrapping_mul(pn_norm_squared)
+                                .wrapping_add(cn_dot_pn.wrapping_mul(pn_uv[1])),
+                        ];
+                        let mut x_position = [0; 3];
+                        for i in 0..3 {
+                            x_position[i] =
+                                next_position[i] + cn_dot_pn.wrapping_mul(pn[i]) / pn_norm_squared;
+                        }
+                        let cx = sub3(tip, x_position);
+                        let cx_norm_squared = dot3(cx, cx);
+                        let norm =
+                            int_sqrt((cx_norm_squared as u64).wrapping_mul(pn_norm_squared as u64))
+                                as i64;
+                        let cx_uv = [pn_uv[1].wrapping_mul(norm), (-pn_uv[0]).wrapping_mul(norm)];
+                        let orientation = orientations.pop().ok_or(Error::Invalid)?;
+                        let uv = if orientation {
+                            [
+                                x_uv[0].wrapping_add(cx_uv[0]),
+                                x_uv[1].wrapping_add(cx_uv[1]),
+                            ]
+                        } else {
+                            [
+                                x_uv[0].wrapping_sub(cx_uv[0]),
+                                x_uv[1].wrapping_sub(cx_uv[1]),
+                            ]
+                        };
+                        predicted = Some([uv[0] / pn_norm_squared, uv[1] / pn_norm_squared]);
+                    }
+                }
+            }
+            let predicted = match predicted {
+                Some(uv) => [uv[0] as i32, uv[1] as i32],
+                // Without both neighbouring values, predict from the next
+                // corner or else from the previous value.
+                None if next_index < index => [values[2 * next_index], values[2 * next_index + 1]],
+                None if index > 0 => [values[2 * index - 2], values[2 * index - 1]],
+                None => [0, 0],
+            };
+            self.correct(&predicted, index, values);
+        }
+        Ok(())
+    }
+
+    /// Predicts normals from the area weighted normals of the triangles
+    /// around each vertex.
+    fn geometric_normal(
+        &self,
+        mesh: &MeshData,
+        positions: &Positions,
+        point_ids: &[u32],
+        mut flips: BitDecoder,
+        values: &mut [i32],
+    ) -> Result<(), Error> {
+        let octahedron = match *self.transform {
+            Transform::Octahedron { octahedron, .. } if self.components == 2 => octahedron,
+            _ => return Err(Error::Invalid),
+        };
+        let position = |corner: u32| {
+            let index = mesh.value(corner);
+            positions.get(point_ids, index)
+        };
+        for index in 0..mesh.value_to_corner.len().min(self.num_values()) {
+            let corner = mesh.value_to_corner[index];
+            let center = position(corner)?;
+            let mut normal = [0i64; 3];
+            for corner in around_corner(mesh.table, corner) {
+                let next_position = sub3(position(next(corner))?, center);
+                let previous_position = sub3(position(previous(corner))?, center);
+                let cross = cross3(next_position, previous_position);
+                for (sum, value) in normal.iter_mut().zip(cross) {
+                    *sum = sum.wrapping_add(value);
+                }
+            }
+            const UPPER_BOUND: i64 = 1 << 29;
+            let sum = normal
+                .iter()
+                .fold(0i64, |sum, value| sum.wrapping_add(value.wrapping_abs()));
+            if sum > UPPER_BOUND {
+                let quotient = sum / UPPER_BOUND;
+                normal.iter_mut().for_each(|value| *value /= quotient);
+            }
+            let mut normal = normal.map(|value| i64::from(value as i32));
+            octahedron.canonicalize_vector(&mut normal);
+            if flips.bit() {
+                normal = normal.map(|value| -value);
+            }
+            let predicted = octahedron.vector_to_coords(&normal);
+            self.correct(&predicted, index, values);
+        }
+        Ok(())
+    }
+}
+
+fn sub3(a: [i64; 3], b: [i64; 3]) -> [i64; 3] {
+    [
+        a[0].wrapping_sub(b[0]),
+        a[1].wrapping_sub(b[1]),
+        a[2].wrapping_sub(b[2]),
+    ]
+}
+
+fn dot3(a: [i64; 3], b: [i64; 3]) -> i64 {
+    a[0].wrapping_mul(b[0])
+        .wrapping_add(a[1].wrapping_mul(b[1]))
+        .wrapping_add(a[2].wrapping_mul(b[2]))
+}
+
+fn cross3(a: [i64; 3], b: [i64; 3]) -> [i64; 3] {
+    [
+        a[1].wrapping_mul(b[2])
+            .wrapping_sub(a[2].wrapping_mul(b[1])),
+        a[2].wrapping_mul(b[0])
+            .wrapping_sub(a[0].wrapping_mul(b[2])),
+        a[0].wrapping_mul(b[1])
+            .wrapping_sub(a[1].wrapping_mul(b[0])),
+    ]
+}
+
+/// Returns the largest integer whose square is at most `number`.
+fn int_sqrt(number: u64) -> u64 {
+    if number == 0 {
+        return 0;
+    }
+    let mut remaining = number;
+    let mut root: u64 = 1;
+    while remaining >= 2 {
+        root *= 2;
+        remaining /= 4;
+    }
+    loop {
+        root = (root + number / root) / 2;
+        if root.wrapping_mul(root) <= number {
+            return root;
+        }
+    }
+}
+
+/// The data that maps portable values back to the original values.
+enum Output {
+    None,
+    Quantization {
+        min: Vec<f32>,
+        range: f32,
+        bits: u32,
+    },
+    Normals(Octahedron),
+}
+
+impl Output {
+    fn apply(self, header: &Header, portable: Values) -> Result<(usize, Values), Error> {
+        let values = match portable {
+            Values::Integer(values) => values,
+            portable => return Ok((header.components, portable)),
+        };
+        Ok(match self {
+            Output::None => (header.components, Values::Integer(values)),
+            Output::Quantization { min, range, bits } => {
+                let max_quantized_value = ((1u32 << bits) - 1) as f32;
+                let delta = range / max_quantized_value;
+                let output = values
+                    .chunks_exact(header.components)
+                    .flat_map(|value| {
+                        value
+                            .iter()
+                            .zip(&min)
+                            .map(|(&value, &min)| value as f32 * delta + min)
+                    })
+                    .collect();
+                (header.components, Values::Float(output))
+            }
+            Output::Normals(octahedron) => {
+                let output = values
+                    .chunks_exact(2)
+                    .flat_map(|value| octahedron.coords_to_unit_vector(value[0], value[1]))
+                    .collect();
+                (3, Values::Float(output))
+            }
+        })
+    }
+}
+
+/// Reads the data needed to map portable values back to the original
+/// values.
+fn decode_output(reader: &mut Reader, header: &Header) -> Result<Output, Error> {
+    Ok(match header.coding {
+        QUANTIZATION => {
+            let mut min = Vec::with_capacity(header.components);
+            for _ in 0..header.components {
+                min.push(reader.f32()?);
+            }
+            let range = reader.f32()?;
+            let bits = u32::from(reader.u8()?);
+            if !(1..=30).contains(&bits) {
+                return Err(Error::Invalid);
+            }
+            Output::Quantization { min, range, bits }
+        }
+        NORMALS => Output::Normals(Octahedron::with_bits(u32::from(reader.u8()?))?),
+        _ => Output::None,
+    })
+}
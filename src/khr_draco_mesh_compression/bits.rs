@@ -0,0 +1,378 @@
+use super::Error;
+
+/// The smallest rANS state of the bit decoder.
+const BIT_STATE_BASE: u32 = 4096;
+
+/// The precision of the probability of a zero bit.
+const BIT_PRECISION: u32 = 256;
+
+/// The base in which the rANS state is refilled.
+const IO_BASE: u32 = 256;
+
+/// The entropy coding scheme of a symbol stream.
+const TAGGED_SYMBOLS: u8 = 0;
+const RAW_SYMBOLS: u8 = 1;
+
+/// Reads little-endian values from compressed data.
+#[derive(Clone, Debug)]
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    /// Returns the unread data.
+    pub fn rest(&self) -> &'a [u8] {
+        &self.data[self.position..]
+    }
+
+    pub fn skip(&mut self, length: usize) -> Result<(), Error> {
+        self.bytes(length).map(|_| ())
+    }
+
+    pub fn bytes(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        if self.remaining() < length {
+            return Err(Error::UnexpectedEnd);
+        }
+        let bytes = &self.data[self.position..self.position + length];
+        self.position += length;
+        Ok(bytes)
+    }
+
+    pub fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub fn i8(&mut self) -> Result<i8, Error> {
+        Ok(self.u8()? as i8)
+    }
+
+    pub fn u16(&mut self) -> Result<u16, Error> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub fn u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub fn i32(&mut self) -> Result<i32, Error> {
+        Ok(self.u32()? as i32)
+    }
+
+    pub fn f32(&mut self) -> Result<f32, Error> {
+        Ok(f32::from_bits(self.u32()?))
+    }
+
+    /// Reads an unsigned integer encoded in groups of seven bits.
+    pub fn varint(&mut self) -> Result<u64, Error> {
+        let mut result = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            result |= u64::from(byte & 0x7f) << shift;
+            if byte < 0x80 {
+                return Ok(result);
+            }
+        }
+        Err(Error::Invalid)
+    }
+
+    /// Reads a variable length integer that must fit in 32 bits.
+    pub fn varint32(&mut self) -> Result<u32, Error> {
+        let value = self.varint()?;
+        if value > u64::from(u32::MAX) {
+            return Err(Error::Invalid);
+        }
+        Ok(value as u32)
+    }
+
+    /// Reads a variable length size that must not exceed the remaining data.
+    pub fn size(&mut self) -> Result<usize, Error> {
+        let size = self.varint()?;
+        if size > self.remaining() as u64 {
+            return Err(Error::UnexpectedEnd);
+        }
+        Ok(size as usize)
+    }
+}
+
+/// Reads bits from the least significant bit of each byte upwards.
+#[derive(Clone, Debug)]
+pub(crate) struct BitReader<'a> {
+    data: &'a [u8],
+    bit: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, bit: 0 }
+    }
+
+    /// Reads `count` bits. Reading beyond the end of the data yields zeros.
+    pub fn bits(&mut self, count: u32) -> u32 {
+        let mut value = 0;
+        for i in 0..count {
+            let byte = self.data.get(self.bit / 8).copied().unwrap_or(0);
+            value |= u32::from((byte >> (self.bit % 8)) & 1) << i;
+            self.bit += 1;
+        }
+        value
+    }
+
+    /// Returns the number of whole or partial bytes read.
+    pub fn bytes_read(&self) -> usize {
+        (self.bit + 7) / 8
+    }
+}
+
+/// Reads the initial rANS state from the end of `data`.
+///
+/// Returns the state and the number of bytes before it that remain to be
+/// read.
+fn ans_init(
+    data: &[u8],
+    base: u32,
+    io_base: u32,
+    allow_four_bytes: bool,
+) -> Result<(u32, usize), Error> {
+    let last = *data.last().ok_or(Error::Invalid)?;
+    let length = data.len();
+    let (state, offset) = match last >> 6 {
+        0 => (u32::from(last & 0x3f), length - 1),
+        1 if length >= 2 => {
+            let value = u32::from(data[length - 2]) | u32::from(last) << 8;
+            (value & 0x3fff, length - 2)
+        }
+        2 if length >= 3 => {
+            let value = u32::from(data[length - 3])
+                | u32::from(data[length - 2]) << 8
+                | u32::from(last) << 16;
+            (value & 0x3f_ffff, length - 3)
+        }
+        3 if allow_four_bytes && length >= 4 => {
+            let bytes = &data[length - 4..];
+            let value = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            (value & 0x3fff_ffff, length - 4)
+        }
+        _ => return Err(Error::Invalid),
+    };
+    let state = state + base;
+    if u64::from(state) >= u64::from(base) * u64::from(io_base) {
+        return Err(Error::Invalid);
+    }
+    Ok((state, offset))
+}
+
+/// Decodes bits with a fixed probability using an rANS coder.
+#[derive(Clone, Debug)]
+pub(crate) struct BitDecoder<'a> {
+    data: &'a [u8],
+    offset: usize,
+    state: u32,
+    probability_zero: u32,
+}
+
+impl<'a> BitDecoder<'a> {
+    /// Reads the probability and the coded data from `reader`.
+    pub fn new(reader: &mut Reader<'a>) -> Result<Self, Error> {
+        let probability_zero = u32::from(reader.u8()?);
+        let size = reader.size()?;
+        let data = reader.bytes(size)?;
+        let (state, offset) = ans_init(data, BIT_STATE_BASE, IO_BASE, false)?;
+        Ok(Self {
+            data,
+            offset,
+            state,
+            probability_zero,
+        })
+    }
+
+    pub fn bit(&mut self) -> bool {
+        let p = BIT_PRECISION - self.probability_zero;
+        if self.state < BIT_STATE_BASE && self.offset > 0 {
+            self.offset -= 1;
+            self.state = self.state * IO_BASE + u32::from(self.data[self.offset]);
+        }
+        let quotient = self.state / BIT_PRECISION;
+        let remainder = self.state % BIT_PRECISION;
+        let scaled = quotient * p;
+        if remainder < p {
+            self.state = scaled + remainder;
+            true
+        } else {
+            self.state = self.state - scaled - p;
+            false
+        }
+    }
+}
+
+/// Decodes symbols with a table of probabilities using an rANS coder.
+struct SymbolDecoder<'a> {
+    data: &'a [u8],
+    offset: usize,
+    state: u32,
+    precision: u32,
+    probabilities: Vec<u32>,
+    cumulative: Vec<u32>,
+    lookup: Vec<u32>,
+}
+
+impl<'a> SymbolDecoder<'a> {
+    /// Reads the probability table of symbols of up to `max_bit_length`
+    /// bits and the coded data from `reader`.
+    fn new(reader: &mut Reader<'a>, max_bit_length: u32) -> Result<Self, Error> {
+        let precision_bits = ((3 * max_bit_length) / 2).clamp(12, 20);
+        let precision = 1 << precision_bits;
+
+        let num_symbols = reader.varint32()? as usize;
+        if num_symbols / precision as usize > reader.remaining() {
+            return Err(Error::Invalid);
+        }
+        let mut probabilities = vec![0; num_symbols];
+        let mut i = 0;
+        while i < num_symbols {
+            let data = reader.u8()?;
+            let token = data & 3;
+            if token == 3 {
+                // A run of symbols with zero probability.
+                let run = usize::from(data >> 2) + 1;
+                if i + run > num_symbols {
+                    return Err(Error::Invalid);
+                }
+                i += run;
+            } else {
+                let mut probability = u32::from(data >> 2);
+                for b in 0..u32::from(token) {
+                    probability |= u32::from(reader.u8()?) << (8 * (b + 1) - 2);
+                }
+                probabilities[i] = probability;
+                i += 1;
+            }
+        }
+
+        let mut cumulative = Vec::with_capacity(num_symbols);
+        let mut lookup = vec![0; precision as usize];
+        let mut total = 0u32;
+        for (symbol, &probability) in probabilities.iter().enumerate() {
+            cumulative.push(total);
+            let end = total.checked_add(probability).ok_or(Error::Invalid)?;
+            if end > precision {
+                return Err(Error::Invalid);
+            }
+            for entry in &mut lookup[total as usize..end as usize] {
+                *entry = symbol as u32;
+            }
+            total = end;
+        }
+        if num_symbols > 0 && total != precision {
+            return Err(Error::Invalid);
+        }
+
+        let mut decoder = Self {
+            data: &[],
+            offset: 0,
+            state: 0,
+            precision,
+            probabilities,
+            cumulative,
+            lookup,
+        };
+        let size = reader.size()?;
+        decoder.data = reader.bytes(size)?;
+        if num_symbols > 0 {
+            let (state, offset) = ans_init(decoder.data, 4 * precision, IO_BASE, true)?;
+            decoder.state = state;
+            decoder.offset = offset;
+        }
+        Ok(decoder)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.probabilities.is_empty()
+    }
+
+    fn symbol(&mut self) -> u32 {
+        let base = 4 * self.precision;
+        while self.state < base && self.offset > 0 {
+            self.offset -= 1;
+            self.state = self.state * IO_BASE + u32::from(self.data[self.offset]);
+        }
+        let quotient = self.state / self.precision;
+        let remainder = self.state % self.precision;
+        let symbol = self.lookup[remainder as usize];
+        let index = symbol as usize;
+        self.state = quotient * self.probabilities[index] + remainder - self.cumulative[index];
+        symbol
+    }
+}
+
+/// Decodes `count` entropy coded symbols in groups of `components`.
+pub(crate) fn decode_symbols(
+    reader: &mut Reader,
+    count: usize,
+    components: usize,
+) -> Result<Vec<u32>, Error> {
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+    let mut output = Vec::with_capacity(count);
+    match reader.u8()? {
+        TAGGED_SYMBOLS => {
+            // Each group is preceded by a coded bit length and the values
+            // follow as plain bits after the coded data.
+            let mut tags = SymbolDecoder::new(reader, 5)?;
+            if tags.is_empty() {
+                return Err(Error::Invalid);
+            }
+            let mut bits = BitReader::new(reader.rest());
+            while output.len() < count {
+                let length = tags.symbol();
+                if length > 32 {
+                    return Err(Error::Invalid);
+                }
+                for _ in 0..components {
+                    output.push(bits.bits(length));
+                }
+            }
+            output.truncate(count);
+            reader.skip(bits.bytes_read().min(reader.remaining()))?;
+        }
+        RAW_SYMBOLS => {
+            let max_bit_length = u32::from(reader.u8()?);
+            if !(1..=18).contains(&max_bit_length) {
+                return Err(Error::Invalid);
+            }
+            let mut symbols = SymbolDecoder::new(reader, max_bit_length)?;
+            if symbols.is_empty() {
+                return Err(Error::Invalid);
+            }
+            for _ in 0..count {
+                output.push(symbols.symbol());
+            }
+        }
+        _ => return Err(Error::Invalid),
+    }
+    Ok(output)
+}
+
+/// Maps an unsigned symbol back onto the signed integer it represents.
+pub(crate) fn to_signed(symbol: u32) -> i32 {
+    if symbol & 1 == 0 {
+        (symbol >> 1) as i32
+    } else {
+        -((symbol >> 1) as i32) - 1
+    }
+}
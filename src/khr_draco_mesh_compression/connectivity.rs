@@ -0,0 +1,906 @@
+use std::collections::HashMap;
+
+use super::bits::{decode_symbols, BitDecoder, BitReader, Reader};
+use super::Error;
+
+/// Marks a missing corner or vertex.
+pub(crate) const INVALID: u32 = u32::MAX;
+
+/// Edgebreaker symbols.
+const TOPOLOGY_C: u32 = 0;
+const TOPOLOGY_S: u32 = 1;
+const TOPOLOGY_L: u32 = 3;
+const TOPOLOGY_R: u32 = 5;
+const TOPOLOGY_E: u32 = 7;
+
+/// Edgebreaker symbols in the order used by the valence coder.
+const VALENCE_SYMBOLS: [u32; 5] = [TOPOLOGY_C, TOPOLOGY_S, TOPOLOGY_L, TOPOLOGY_R, TOPOLOGY_E];
+
+/// Edgebreaker traversal coders.
+const STANDARD_EDGEBREAKER: u8 = 0;
+const VALENCE_EDGEBREAKER: u8 = 2;
+
+/// Methods of storing sequential indices.
+const COMPRESSED_INDICES: u8 = 0;
+
+pub(crate) fn next(corner: u32) -> u32 {
+    if corner == INVALID {
+        INVALID
+    } else if corner % 3 == 2 {
+        corner - 2
+    } else {
+        corner + 1
+    }
+}
+
+pub(crate) fn previous(corner: u32) -> u32 {
+    if corner == INVALID {
+        INVALID
+    } else if corner % 3 == 0 {
+        corner + 2
+    } else {
+        corner - 1
+    }
+}
+
+/// Read access to the connectivity of a triangle mesh in terms of corners,
+/// the vertices they belong to and the corners opposite to them.
+pub(crate) trait Corners {
+    fn num_faces(&self) -> usize;
+    fn num_vertices(&self) -> usize;
+    fn vertex(&self, corner: u32) -> u32;
+    fn opposite(&self, corner: u32) -> u32;
+    fn left_most_corner(&self, vertex: u32) -> u32;
+
+    fn swing_left(&self, corner: u32) -> u32 {
+        next(self.opposite(next(corner)))
+    }
+
+    fn swing_right(&self, corner: u32) -> u32 {
+        previous(self.opposite(previous(corner)))
+    }
+
+    fn right_corner(&self, corner: u32) -> u32 {
+        self.opposite(next(corner))
+    }
+
+    fn left_corner(&self, corner: u32) -> u32 {
+        self.opposite(previous(corner))
+    }
+
+    fn is_on_boundary(&self, vertex: u32) -> bool {
+        let corner = self.left_most_corner(vertex);
+        corner == INVALID || self.swing_left(corner) == INVALID
+    }
+}
+
+/// The connectivity of the vertex positions.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CornerTable {
+    corner_to_vertex: Vec<u32>,
+    opposite: Vec<u32>,
+    vertex_corners: Vec<u32>,
+}
+
+impl CornerTable {
+    fn new(num_faces: usize) -> Self {
+        Self {
+            corner_to_vertex: vec![INVALID; 3 * num_faces],
+            opposite: vec![INVALID; 3 * num_faces],
+            vertex_corners: Vec::new(),
+        }
+    }
+
+    fn add_vertex(&mut self) -> u32 {
+        self.vertex_corners.push(INVALID);
+        self.vertex_corners.len() as u32 - 1
+    }
+
+    fn map(&mut self, corner: u32, vertex: u32) -> Result<(), Error> {
+        *self
+            .corner_to_vertex
+            .get_mut(corner as usize)
+            .ok_or(Error::Invalid)? = vertex;
+        Ok(())
+    }
+
+    fn set_left_most_corner(&mut self, vertex: u32, corner: u32) -> Result<(), Error> {
+        if vertex != INVALID {
+            *self
+                .vertex_corners
+                .get_mut(vertex as usize)
+                .ok_or(Error::Invalid)? = corner;
+        }
+        Ok(())
+    }
+
+    fn set_opposite(&mut self, a: u32, b: u32) -> Result<(), Error> {
+        if a as usize >= self.opposite.len() || b as usize >= self.opposite.len() {
+            return Err(Error::Invalid);
+        }
+        self.opposite[a as usize] = b;
+        self.opposite[b as usize] = a;
+        Ok(())
+    }
+}
+
+impl Corners for CornerTable {
+    fn num_faces(&self) -> usize {
+        self.corner_to_vertex.len() / 3
+    }
+
+    fn num_vertices(&self) -> usize {
+        self.vertex_corners.len()
+    }
+
+    fn vertex(&self, corner: u32) -> u32 {
+        self.corner_to_vertex
+            .get(corner as usize)
+            .copied()
+            .unwrap_or(INVALID)
+    }
+
+    fn opposite(&self, corner: u32) -> u32 {
+        self.opposite
+            .get(corner as usize)
+            .copied()
+            .unwrap_or(INVALID)
+    }
+
+    fn left_most_corner(&self, vertex: u32) -> u32 {
+        self.vertex_corners
+            .get(vertex as usize)
+            .copied()
+            .unwrap_or(INVALID)
+    }
+}
+
+/// The connectivity of an attribute whose values are not shared across
+/// seam edges, layered on top of the position connectivity.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AttributeCornerTable {
+    is_edge_on_seam: Vec<bool>,
+    is_vertex_on_seam: Vec<bool>,
+    corner_to_vertex: Vec<u32>,
+    vertex_to_left_most_corner: Vec<u32>,
+    base_opposite: Vec<u32>,
+}
+
+impl AttributeCornerTable {
+    fn new(table: &CornerTable, seams: &[u32]) -> Result<Self, Error> {
+        let mut this = Self {
+            is_edge_on_seam: vec![false; table.opposite.len()],
+            is_vertex_on_seam: vec![false; table.num_vertices()],
+            corner_to_vertex: vec![INVALID; table.opposite.len()],
+            vertex_to_left_most_corner: Vec::new(),
+            base_opposite: table.opposite.clone(),
+        };
+        for &corner in seams {
+            this.add_seam_edge(table, corner);
+            let opposite = table.opposite(corner);
+            if opposite != INVALID {
+                this.add_seam_edge(table, opposite);
+            }
+        }
+        this.recompute_vertices(table)?;
+        Ok(this)
+    }
+
+    fn add_seam_edge(&mut self, table: &CornerTable, corner: u32) {
+        self.is_edge_on_seam[corner as usize] = true;
+        for vertex in [table.vertex(next(corner)), table.vertex(previous(corner))] {
+            if let Some(flag) = self.is_vertex_on_seam.get_mut(vertex as usize) {
+                *flag = true;
+            }
+        }
+    }
+
+    /// Splits the position vertices along the seam edges.
+    fn recompute_vertices(&mut self, table: &CornerTable) -> Result<(), Error> {
+        for vertex in 0..table.num_vertices() as u32 {
+            let corner = table.left_most_corner(vertex);
+            if corner == INVALID {
+                continue;
+            }
+            let mut new_vertex = self.vertex_to_left_most_corner.len() as u32;
+            let mut first = corner;
+            if self.is_vertex_on_seam[vertex as usize] {
+                // Start from the first corner after a seam in the
+                // counter-clockwise direction.
+                let mut current = self.swing_left(first);
+                while current != INVALID {
+                    first = current;
+                    current = self.swing_left(current);
+                    if current == corner {
+                        return Err(Error::Invalid);
+                    }
+                }
+            }
+            self.corner_to_vertex[first as usize] = new_vertex;
+            self.vertex_to_left_most_corner.push(first);
+            let mut current = table.swing_right(first);
+            while current != INVALID && current != first {
+                if self.is_edge_on_seam[next(current) as usize] {
+                    new_vertex = self.vertex_to_left_most_corner.len() as u32;
+                    self.vertex_to_left_most_corner.push(current);
+                }
+                self.corner_to_vertex[current as usize] = new_vertex;
+                current = table.swing_right(current);
+            }
+        }
+        Ok(())
+    }
+
+    fn is_corner_on_seam(&self, table: &CornerTable, corner: u32) -> bool {
+        self.is_vertex_on_seam
+            .get(table.vertex(corner) as usize)
+            .copied()
+            .unwrap_or(false)
+    }
+}
+
+impl Corners for AttributeCornerTable {
+    fn num_faces(&self) -> usize {
+        self.corner_to_vertex.len() / 3
+    }
+
+    fn num_vertices(&self) -> usize {
+        self.vertex_to_left_most_corner.len()
+    }
+
+    fn vertex(&self, corner: u32) -> u32 {
+        self.corner_to_vertex
+            .get(corner as usize)
+            .copied()
+            .unwrap_or(INVALID)
+    }
+
+    fn opposite(&self, corner: u32) -> u32 {
+        match self.is_edge_on_seam.get(corner as usize) {
+            Some(false) => self.base_opposite[corner as usize],
+            _ => INVALID,
+        }
+    }
+
+    fn left_most_corner(&self, vertex: u32) -> u32 {
+        self.vertex_to_left_most_corner
+            .get(vertex as usize)
+            .copied()
+            .unwrap_or(INVALID)
+    }
+}
+
+/// Decoded connectivity of a mesh.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Connectivity {
+    /// The point index of each corner.
+    pub faces: Vec<u32>,
+
+    /// The number of points.
+    pub num_points: usize,
+
+    /// The position connectivity, which is only available for Edgebreaker
+    /// coded meshes.
+    pub table: Option<CornerTable>,
+
+    /// The connectivity of every attribute with seams.
+    pub attribute_tables: Vec<AttributeCornerTable>,
+}
+
+/// Decodes connectivity stored as a plain list of faces.
+pub(crate) fn decode_sequential(reader: &mut Reader) -> Result<Connectivity, Error> {
+    let num_faces = reader.varint32()? as usize;
+    let num_points = reader.varint32()? as usize;
+    if num_faces > u32::MAX as usize / 3 || num_faces > reader.remaining() / 3 {
+        return Err(Error::Invalid);
+    }
+    let mut faces = Vec::with_capacity(3 * num_faces);
+    if reader.u8()? == COMPRESSED_INDICES {
+        let symbols = decode_symbols(reader, 3 * num_faces, 1)?;
+        let mut last = 0i32;
+        for symbol in symbols {
+            let difference = (symbol >> 1) as i32;
+            last = if symbol & 1 != 0 {
+                last.wrapping_sub(difference)
+            } else {
+                last.wrapping_add(difference)
+            };
+            faces.push(last as u32);
+        }
+    } else {
+        for _ in 0..3 * num_faces {
+            let index = if num_points < 1 << 8 {
+                u32::from(reader.u8()?)
+            } else if num_points < 1 << 16 {
+                u32::from(reader.u16()?)
+            } else if num_points < 1 << 21 {
+                reader.varint32()?
+            } else {
+                reader.u32()?
+            };
+            faces.push(index);
+        }
+    }
+    if faces.iter().any(|&index| index as usize >= num_points) {
+        return Err(Error::Invalid);
+    }
+    Ok(Connectivity {
+        faces,
+        num_points,
+        table: None,
+        attribute_tables: Vec::new(),
+    })
+}
+
+/// A split of the traversal into two components that are joined again by
+/// an `S` symbol.
+struct TopologySplit {
+    source_symbol: u32,
+    split_symbol: u32,
+    source_edge: u32,
+}
+
+/// Reads the Edgebreaker symbols and the data coded alongside them.
+struct Traversal<'a> {
+    symbols: Option<BitReader<'a>>,
+    start_faces: BitDecoder<'a>,
+    seams: Vec<BitDecoder<'a>>,
+    valence: Option<Valence>,
+}
+
+/// State of the valence coder, which predicts symbols from the number of
+/// edges of the active vertex.
+struct Valence {
+    contexts: Vec<Vec<u32>>,
+    active_context: Option<usize>,
+    last_symbol: u32,
+    valences: Vec<i32>,
+}
+
+impl<'a> Traversal<'a> {
+    fn symbol(&mut self) -> Result<u32, Error> {
+        if let Some(valence) = self.valence.as_mut() {
+            valence.last_symbol = match valence.active_context {
+                Some(context) => {
+                    let symbol = valence.contexts[context].pop().ok_or(Error::Invalid)?;
+                    *VALENCE_SYMBOLS.get(symbol as usize).ok_or(Error::Invalid)?
+                }
+                // The first symbol is always `E`.
+                None => TOPOLOGY_E,
+            };
+            return Ok(valence.last_symbol);
+        }
+        let symbols = self.symbols.as_mut().ok_or(Error::Invalid)?;
+        if symbols.bits(1) == TOPOLOGY_C {
+            Ok(TOPOLOGY_C)
+        } else {
+            Ok(1 | symbols.bits(2) << 1)
+        }
+    }
+
+    fn new_active_corner(&mut self, table: &CornerTable, corner: u32) {
+        let valence = match self.valence.as_mut() {
+            Some(valence) => valence,
+            None => return,
+        };
+        let vertex = |corner| table.vertex(corner) as usize;
+        let (tip, next_vertex, previous_vertex) = (
+            vertex(corner),
+            vertex(next(corner)),
+            vertex(previous(corner)),
+        );
+        let increments = match valence.last_symbol {
+            TOPOLOGY_C | TOPOLOGY_S => [0, 1, 1],
+            TOPOLOGY_R => [1, 1, 2],
+            TOPOLOGY_L => [1, 2, 1],
+            TOPOLOGY_E => [2, 2, 2],
+            _ => [0, 0, 0],
+        };
+        for (vertex, increment) in [tip, next_vertex, previous_vertex]
+            .into_iter()
+            .zip(increments)
+        {
+            if let Some(valence) = valence.valences.get_mut(vertex) {
+                *valence += increment;
+            }
+        }
+        let active = valence.valences.get(next_vertex).copied().unwrap_or(0);
+        valence.active_context = Some((active.clamp(2, 7) - 2) as usize);
+    }
+
+    fn merge_vertices(&mut self, destination: u32, source: u32) {
+        if let Some(valence) = self.valence.as_mut() {
+            let source = valence.valences.get(source as usize).copied().unwrap_or(0);
+            if let Some(destination) = valence.valences.get_mut(destination as usize) {
+                *destination += source;
+            }
+        }
+    }
+}
+
+/// Decodes connectivity coded with the Edgebreaker algorithm.
+pub(crate) fn decode_edgebreaker(reader: &mut Reader) -> Result<Connectivity, Error> {
+    let coder = reader.u8()?;
+    if coder != STANDARD_EDGEBREAKER && coder != VALENCE_EDGEBREAKER {
+        return Err(Error::Unsupported("Edgebreaker coder"));
+    }
+
+    let num_encoded_vertices = reader.varint32()? as usize;
+    let num_faces = reader.varint32()? as usize;
+    let num_attribute_data = usize::from(reader.u8()?);
+    let num_symbols = reader.varint32()? as usize;
+    let num_split_symbols = reader.varint32()? as usize;
+    if num_faces < num_symbols
+        || num_faces > num_symbols + num_symbols / 3
+        || num_split_symbols > num_symbols
+        || num_encoded_vertices > 3 * num_faces + 3
+    {
+        return Err(Error::Invalid);
+    }
+    let max_vertices = num_encoded_vertices + num_split_symbols;
+
+    // The traversal data is followed by the topology split events.
+    let traversal_size = reader.size()?;
+    let traversal_data = reader.bytes(traversal_size)?;
+    let mut events = Reader::new(reader.rest());
+    let mut splits = decode_topology_splits(&mut events, num_faces)?;
+    reader.skip(events.position())?;
+
+    let mut traversal_reader = Reader::new(traversal_data);
+    let symbols = if coder == STANDARD_EDGEBREAKER {
+        let size = traversal_reader.size()?;
+        Some(BitReader::new(traversal_reader.bytes(size)?))
+    } else {
+        None
+    };
+    let start_faces = BitDecoder::new(&mut traversal_reader)?;
+    let mut seams = Vec::with_capacity(num_attribute_data);
+    for _ in 0..num_attribute_data {
+        seams.push(BitDecoder::new(&mut traversal_reader)?);
+    }
+    let valence = if coder == VALENCE_EDGEBREAKER {
+        // Only the mode with valences from two to seven exists.
+        if traversal_reader.i8()? != 0 {
+            return Err(Error::Unsupported("valence mode"));
+        }
+        let mut contexts = Vec::with_capacity(6);
+        for _ in 0..6 {
+            let count = traversal_reader.varint32()? as usize;
+            if count > num_faces {
+                return Err(Error::Invalid);
+            }
+            // Symbols are consumed from the back.
+            contexts.push(decode_symbols(&mut traversal_reader, count, 1)?);
+        }
+        Some(Valence {
+            contexts,
+            active_context: None,
+            last_symbol: INVALID,
+            valences: vec![0; max_vertices],
+        })
+    } else {
+        None
+    };
+    let mut traversal = Traversal {
+        symbols,
+        start_faces,
+        seams,
+        valence,
+    };
+
+    let mut table = CornerTable::new(num_faces);
+    let mut is_vertex_hole = vec![true; max_vertices];
+    let num_vertices = decode_faces(
+        &mut traversal,
+        &mut table,
+        &mut is_vertex_hole,
+        &mut splits,
+        num_symbols,
+        max_vertices,
+        num_attribute_data == 0,
+    )?;
+
+    // Attribute seams are coded for each edge shared by two faces, from
+    // the face with the lower index.
+    let mut seam_corners = vec![Vec::new(); num_attribute_data];
+    if num_attribute_data > 0 {
+        for face in 0..num_faces as u32 {
+            let first = 3 * face;
+            for corner in [first, next(first), previous(first)] {
+                let opposite = table.opposite(corner);
+                if opposite == INVALID {
+                    // Boundary edges are always seams.
+                    for corners in &mut seam_corners {
+                        corners.push(corner);
+                    }
+                    continue;
+                }
+                if opposite / 3 < face {
+                    continue;
+                }
+                for (corners, decoder) in seam_corners.iter_mut().zip(&mut traversal.seams) {
+                    if decoder.bit() {
+                        corners.push(corner);
+                    }
+                }
+            }
+        }
+    }
+    let attribute_tables = seam_corners
+        .iter()
+        .map(|corners| AttributeCornerTable::new(&table, corners))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (faces, num_points) =
+        assign_points(&table, &attribute_tables, &is_vertex_hole, num_vertices)?;
+    Ok(Connectivity {
+        faces,
+        num_points,
+        table: Some(table),
+        attribute_tables,
+    })
+}
+
+fn decode_topology_splits(
+    reader: &mut Reader,
+    num_faces: usize,
+) -> Result<Vec<TopologySplit>, Error> {
+    let count = reader.varint32()? as usize;
+    if count > num_faces {
+        return Err(Error::Invalid);
+    }
+    let mut splits = Vec::with_capacity(count);
+    let mut last_source = 0u32;
+    for _ in 0..count {
+        let source_symbol = last_source
+            .checked_add(reader.varint32()?)
+            .ok_or(Error::Invalid)?;
+        let split_symbol = source_symbol
+            .checked_sub(reader.varint32()?)
+            .ok_or(Error::Invalid)?;
+        splits.push(TopologySplit {
+            source_symbol,
+            split_symbol,
+            source_edge: 0,
+        });
+        last_source = source_symbol;
+    }
+    if count > 0 {
+        let mut bits = BitReader::new(reader.rest());
+        for split in &mut splits {
+            split.source_edge = bits.bits(1);
+        }
+        let read = bits.bytes_read();
+        reader.skip(read)?;
+    }
+    Ok(splits)
+}
+
+/// Rebuilds the faces from the Edgebreaker symbols, which are stored in
+/// the reverse order of the encoder traversal.
+///
+/// Returns the number of vertices.
+fn decode_faces(
+    traversal: &mut Traversal,
+    table: &mut CornerTable,
+    is_vertex_hole: &mut [bool],
+    splits: &mut Vec<TopologySplit>,
+    num_symbols: usize,
+    max_vertices: usize,
+    remove_invalid_vertices: bool,
+) -> Result<usize, Error> {
+    let mut active_corners: Vec<u32> = Vec::new();
+    let mut split_active_corners: HashMap<usize, u32> = HashMap::new();
+    let mut invalid_vertices = Vec::new();
+    let mut num_faces = 0usize;
+
+    for symbol_id in 0..num_symbols {
+        let corner = 3 * num_faces as u32;
+        num_faces += 1;
+        let mut check_topology_split = false;
+        match traversal.symbol()? {
+            TOPOLOGY_C => {
+                let corner_a = *active_corners.last().ok_or(Error::Invalid)?;
+                let vertex_x = table.vertex(next(corner_a));
+                let corner_b = next(table.left_most_corner(vertex_x));
+                if corner_a == corner_b
+                    || corner_b == INVALID
+                    || table.opposite(corner_a) != INVALID
+                    || table.opposite(corner_b) != INVALID
+                {
+                    return Err(Error::Invalid);
+                }
+                table.set_opposite(corner_a, corner + 1)?;
+                table.set_opposite(corner_b, corner + 2)?;
+                let vertex_a_previous = table.vertex(previous(corner_a));
+                let vertex_b_next = table.vertex(next(corner_b));
+                if vertex_x == vertex_a_previous || vertex_x == vertex_b_next {
+                    return Err(Error::Invalid);
+                }
+                table.map(corner, vertex_x)?;
+                table.map(corner + 1, vertex_b_next)?;
+                table.map(corner + 2, vertex_a_previous)?;
+                table.set_left_most_corner(vertex_a_previous, corner + 2)?;
+                clear_hole(is_vertex_hole, vertex_x)?;
+                *active_corners.last_mut().unwrap() = corner;
+            }
+            symbol @ (TOPOLOGY_R | TOPOLOGY_L) => {
+                let corner_a = *active_corners.last().ok_or(Error::Invalid)?;
+                if table.opposite(corner_a) != INVALID {
+                    return Err(Error::Invalid);
+                }
+                let (opposite, corner_l, corner_r) = if symbol == TOPOLOGY_R {
+                    (corner + 2, corner + 1, corner)
+                } else {
+                    (corner + 1, corner, corner + 2)
+                };
+                table.set_opposite(opposite, corner_a)?;
+                let new_vertex = table.add_vertex();
+                if table.num_vertices() > max_vertices {
+                    return Err(Error::Invalid);
+                }
+                table.map(opposite, new_vertex)?;
+                table.set_left_most_corner(new_vertex, opposite)?;
+                let vertex_r = table.vertex(previous(corner_a));
+                table.map(corner_r, vertex_r)?;
+                table.set_left_most_corner(vertex_r, corner_r)?;
+                table.map(corner_l, table.vertex(next(corner_a)))?;
+                *active_corners.last_mut().unwrap() = corner;
+                check_topology_split = true;
+            }
+            TOPOLOGY_S => {
+                let corner_b = active_corners.pop().ok_or(Error::Invalid)?;
+                if let Some(&split_corner) = split_active_corners.get(&symbol_id) {
+                    active_corners.push(split_corner);
+                }
+                let corner_a = *active_corners.last().ok_or(Error::Invalid)?;
+                if corner_a == corner_b
+                    || table.opposite(corner_a) != INVALID
+                    || table.opposite(corner_b) != INVALID
+                {
+                    return Err(Error::Invalid);
+                }
+                table.set_opposite(corner_a, corner + 2)?;
+                table.set_opposite(corner_b, corner + 1)?;
+                let vertex_p = table.vertex(previous(corner_a));
+                table.map(corner, vertex_p)?;
+                table.map(corner + 1, table.vertex(next(corner_a)))?;
+                let vertex_b_previous = table.vertex(previous(corner_b));
+                table.map(corner + 2, vertex_b_previous)?;
+                table.set_left_most_corner(vertex_b_previous, corner + 2)?;
+
+                // Merge the vertex at corner "n" into the vertex at "p".
+                let mut corner_n = next(corner_b);
+                let vertex_n = table.vertex(corner_n);
+                if vertex_n == INVALID || vertex_p == INVALID {
+                    return Err(Error::Invalid);
+                }
+                traversal.merge_vertices(vertex_p, vertex_n);
+                table.set_left_most_corner(vertex_p, table.left_most_corner(vertex_n))?;
+                let first = corner_n;
+                while corner_n != INVALID {
+                    table.map(corner_n, vertex_p)?;
+                    corner_n = table.swing_left(corner_n);
+                    if corner_n == first {
+                        return Err(Error::Invalid);
+                    }
+                }
+                table.set_left_most_corner(vertex_n, INVALID)?;
+                if remove_invalid_vertices {
+                    invalid_vertices.push(vertex_n);
+                }
+                *active_corners.last_mut().unwrap() = corner;
+            }
+            TOPOLOGY_E => {
+                let first_vertex = table.add_vertex();
+                table.add_vertex();
+                table.add_vertex();
+                if table.num_vertices() > max_vertices {
+                    return Err(Error::Invalid);
+                }
+                for i in 0..3 {
+                    table.map(corner + i, first_vertex + i)?;
+                    table.set_left_most_corner(first_vertex + i, corner + i)?;
+                }
+                active_corners.push(corner);
+                check_topology_split = true;
+            }
+            _ => return Err(Error::Invalid),
+        }
+        traversal.new_active_corner(table, *active_corners.last().unwrap());
+
+        if check_topology_split {
+            // The encoder numbers the symbols in the opposite order.
+            let encoder_symbol_id = (num_symbols - symbol_id - 1) as u32;
+            while let Some(split) = splits.last() {
+                if split.source_symbol != encoder_symbol_id {
+                    break;
+                }
+                let top = *active_corners.last().unwrap();
+                let new_active_corner = if split.source_edge == 1 {
+                    next(top)
+                } else {
+                    previous(top)
+                };
+                let decoder_split_symbol = num_symbols
+                    .checked_sub(split.split_symbol as usize + 1)
+                    .ok_or(Error::Invalid)?;
+                split_active_corners.insert(decoder_split_symbol, new_active_corner);
+                splits.pop();
+            }
+        }
+    }
+
+    // Connect the remaining active edges through their start faces.
+    while let Some(corner) = active_corners.pop() {
+        if !traversal.start_faces.bit() {
+            // The traversal started from a boundary.
+            continue;
+        }
+        if num_faces >= table.num_faces() {
+            return Err(Error::Invalid);
+        }
+        let vertex_n = table.vertex(next(corner));
+        let corner_b = next(table.left_most_corner(vertex_n));
+        let vertex_x = table.vertex(next(corner_b));
+        let corner_c = next(table.left_most_corner(vertex_x));
+        if corner == corner_b
+            || corner == corner_c
+            || corner_b == corner_c
+            || corner_b == INVALID
+            || corner_c == INVALID
+            || table.opposite(corner) != INVALID
+            || table.opposite(corner_b) != INVALID
+            || table.opposite(corner_c) != INVALID
+        {
+            return Err(Error::Invalid);
+        }
+        let vertex_p = table.vertex(next(corner_c));
+        let new_corner = 3 * num_faces as u32;
+        num_faces += 1;
+        table.set_opposite(new_corner, corner)?;
+        table.set_opposite(new_corner + 1, corner_b)?;
+        table.set_opposite(new_corner + 2, corner_c)?;
+        table.map(new_corner, vertex_x)?;
+        table.map(new_corner + 1, vertex_p)?;
+        table.map(new_corner + 2, vertex_n)?;
+        for vertex in [vertex_x, vertex_p, vertex_n] {
+            clear_hole(is_vertex_hole, vertex)?;
+        }
+    }
+    if num_faces != table.num_faces() {
+        return Err(Error::Invalid);
+    }
+
+    // Move the last valid vertices into the place of vertices that were
+    // merged away, so that all vertices are in use.
+    let mut num_vertices = table.num_vertices();
+    for invalid in invalid_vertices {
+        while num_vertices > 0 && table.left_most_corner(num_vertices as u32 - 1) == INVALID {
+            num_vertices -= 1;
+        }
+        if num_vertices == 0 {
+            break;
+        }
+        let source = num_vertices as u32 - 1;
+        if source < invalid {
+            continue;
+        }
+        for corner in vertex_corners(table, source) {
+            if table.vertex(corner) != source {
+                return Err(Error::Invalid);
+            }
+            table.map(corner, invalid)?;
+        }
+        table.set_left_most_corner(invalid, table.left_most_corner(source))?;
+        table.set_left_most_corner(source, INVALID)?;
+        let is_hole = *is_vertex_hole.get(source as usize).ok_or(Error::Invalid)?;
+        *is_vertex_hole
+            .get_mut(invalid as usize)
+            .ok_or(Error::Invalid)? = is_hole;
+        clear_hole(is_vertex_hole, source)?;
+        num_vertices -= 1;
+    }
+    table.vertex_corners.truncate(num_vertices);
+    Ok(num_vertices)
+}
+
+/// Marks a vertex as not being on a hole.
+fn clear_hole(is_vertex_hole: &mut [bool], vertex: u32) -> Result<(), Error> {
+    *is_vertex_hole
+        .get_mut(vertex as usize)
+        .ok_or(Error::Invalid)? = false;
+    Ok(())
+}
+
+/// Returns the corners around a vertex, swinging left from its left-most
+/// corner and then right if a boundary is reached.
+pub(crate) fn vertex_corners<T: Corners + ?Sized>(table: &T, vertex: u32) -> Vec<u32> {
+    around_corner(table, table.left_most_corner(vertex))
+}
+
+/// Returns the corners around the vertex of `start`, beginning with
+/// `start`.
+pub(crate) fn around_corner<T: Corners + ?Sized>(table: &T, start: u32) -> Vec<u32> {
+    let mut corners = Vec::new();
+    if start == INVALID {
+        return corners;
+    }
+    corners.push(start);
+    let mut corner = table.swing_left(start);
+    while corner != INVALID && corner != start {
+        corners.push(corner);
+        corner = table.swing_left(corner);
+    }
+    if corner == INVALID {
+        corner = table.swing_right(start);
+        while corner != INVALID {
+            corners.push(corner);
+            corner = table.swing_right(corner);
+        }
+    }
+    corners
+}
+
+/// Assigns a point to every corner, creating a new point wherever the
+/// value of any attribute changes around a vertex.
+fn assign_points(
+    table: &CornerTable,
+    attribute_tables: &[AttributeCornerTable],
+    is_vertex_hole: &[bool],
+    num_vertices: usize,
+) -> Result<(Vec<u32>, usize), Error> {
+    if attribute_tables.is_empty() {
+        return Ok((table.corner_to_vertex.clone(), num_vertices));
+    }
+    let mut corner_to_point = vec![INVALID; table.corner_to_vertex.len()];
+    let mut num_points = 0u32;
+    for vertex in 0..table.num_vertices() as u32 {
+        let corner = table.left_most_corner(vertex);
+        if corner == INVALID {
+            continue;
+        }
+        let mut first = corner;
+        if !is_vertex_hole[vertex as usize] {
+            // Start at a seam of any attribute.
+            'attributes: for attribute in attribute_tables {
+                if !attribute.is_corner_on_seam(table, corner) {
+                    continue;
+                }
+                let value = attribute.vertex(corner);
+                let mut current = table.swing_right(corner);
+                while current != corner {
+                    if current == INVALID {
+                        return Err(Error::Invalid);
+                    }
+                    if attribute.vertex(current) != value {
+                        first = current;
+                        break 'attributes;
+                    }
+                    current = table.swing_right(current);
+                }
+            }
+        }
+        corner_to_point[first as usize] = num_points;
+        num_points += 1;
+        let mut previous_corner = first;
+        let mut current = table.swing_right(first);
+        while current != INVALID && current != first {
+            let seam = attribute_tables
+                .iter()
+                .any(|attribute| attribute.vertex(current) != attribute.vertex(previous_corner));
+            corner_to_point[current as usize] = if seam {
+                num_points += 1;
+                num_points - 1
+            } else {
+                corner_to_point[previous_corner as usize]
+            };
+            previous_corner = current;
+            current = table.swing_right(current);
+        }
+    }
+    if corner_to_point.contains(&INVALID) {
+        return Err(Error::Invalid);
+    }
+    Ok((corner_to_point, num_points as usize))
+}
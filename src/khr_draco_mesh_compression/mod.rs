@@ -0,0 +1,260 @@
+use std::fmt;
+
+use crate::{buffer, Document, Semantic};
+use json::validation::Checked;
+
+mod attributes;
+mod bits;
+mod connectivity;
+
+use bits::Reader;
+
+/// The magic string at the start of compressed data.
+const MAGIC: &[u8] = b"DRACO";
+
+/// The supported major version of the bitstream.
+const VERSION_MAJOR: u8 = 2;
+
+/// The geometry type of triangle meshes.
+const TRIANGULAR_MESH: u8 = 1;
+
+/// Methods of coding mesh connectivity.
+const SEQUENTIAL_ENCODING: u8 = 0;
+const EDGEBREAKER_ENCODING: u8 = 1;
+
+/// The header flag set when metadata follows the header.
+const METADATA_FLAG: u16 = 0x8000;
+
+/// An error that occurred while decoding compressed data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The data does not start with the Draco header.
+    Header,
+
+    /// The data was encoded with an unsupported version of the bitstream.
+    Version(u8, u8),
+
+    /// The data uses a feature of the bitstream that is not supported.
+    Unsupported(&'static str),
+
+    /// The data ends before the mesh has been decoded.
+    UnexpectedEnd,
+
+    /// The data is not a valid encoding of a mesh.
+    Invalid,
+
+    /// No attribute with the unique ID referenced by the extension was
+    /// decoded.
+    MissingAttribute(u32),
+
+    /// The decoded data does not match the accessor with the given index.
+    Accessor(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Header => write!(f, "missing Draco header"),
+            Error::Version(major, minor) => {
+                write!(f, "unsupported bitstream version {}.{}", major, minor)
+            }
+            Error::Unsupported(feature) => write!(f, "unsupported {}", feature),
+            Error::UnexpectedEnd => write!(f, "unexpected end of compressed data"),
+            Error::Invalid => write!(f, "invalid compressed data"),
+            Error::MissingAttribute(id) => write!(f, "missing attribute with unique ID {}", id),
+            Error::Accessor(index) => {
+                write!(f, "decoded data does not match accessor {}", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A decoded triangle mesh.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Mesh {
+    /// The point indices of every triangle.
+    pub indices: Vec<u32>,
+
+    /// The number of points.
+    pub num_points: usize,
+
+    /// The decoded attributes.
+    pub attributes: Vec<Attribute>,
+}
+
+impl Mesh {
+    /// Returns the attribute with the given unique ID.
+    pub fn attribute(&self, unique_id: u32) -> Option<&Attribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.unique_id == unique_id)
+    }
+}
+
+/// The values of a decoded attribute.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Values {
+    /// Integer values, including unsigned 32 bit integers stored with the
+    /// same bits.
+    Integer(Vec<i32>),
+
+    /// Floating point values, including dequantized positions and unit
+    /// length normals.
+    Float(Vec<f32>),
+}
+
+/// A decoded attribute with one value of `components` elements per point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+    /// The ID the extension uses to refer to the attribute.
+    pub unique_id: u32,
+
+    /// The number of elements of each value.
+    pub components: usize,
+
+    /// Whether integer values represent normalized floating point values.
+    pub normalized: bool,
+
+    /// The values of every point.
+    pub values: Values,
+}
+
+/// Decodes a triangle mesh compressed with Draco.
+pub fn decode(data: &[u8]) -> Result<Mesh, Error> {
+    let mut reader = Reader::new(data);
+    if reader.bytes(MAGIC.len()).map_err(|_| Error::Header)? != MAGIC {
+        return Err(Error::Header);
+    }
+    let (major, minor) = (reader.u8()?, reader.u8()?);
+    if major != VERSION_MAJOR {
+        return Err(Error::Version(major, minor));
+    }
+    if reader.u8()? != TRIANGULAR_MESH {
+        return Err(Error::Unsupported("geometry type"));
+    }
+    let method = reader.u8()?;
+    let flags = reader.u16()?;
+    if flags & METADATA_FLAG != 0 {
+        skip_metadata(&mut reader)?;
+    }
+
+    let connectivity = match method {
+        SEQUENTIAL_ENCODING => connectivity::decode_sequential(&mut reader)?,
+        EDGEBREAKER_ENCODING => connectivity::decode_edgebreaker(&mut reader)?,
+        _ => return Err(Error::Unsupported("connectivity encoding")),
+    };
+    let attributes = attributes::decode(&mut reader, &connectivity)?;
+    Ok(Mesh {
+        indices: connectivity.faces,
+        num_points: connectivity.num_points,
+        attributes,
+    })
+}
+
+/// Skips the metadata of the attributes and of the mesh.
+fn skip_metadata(reader: &mut Reader) -> Result<(), Error> {
+    let num_attributes = reader.varint32()?;
+    for _ in 0..num_attributes {
+        reader.varint32()?;
+        skip_metadata_entries(reader, 0)?;
+    }
+    skip_metadata_entries(reader, 0)
+}
+
+fn skip_metadata_entries(reader: &mut Reader, depth: usize) -> Result<(), Error> {
+    // Limits the recursion of nested metadata.
+    if depth > 32 {
+        return Err(Error::Invalid);
+    }
+    let num_entries = reader.varint32()?;
+    for _ in 0..num_entries {
+        let name_length = usize::from(reader.u8()?);
+        reader.skip(name_length)?;
+        let value_length = reader.size()?;
+        reader.skip(value_length)?;
+    }
+    let num_children = reader.varint32()?;
+    for _ in 0..num_children {
+        let name_length = usize::from(reader.u8()?);
+        reader.skip(name_length)?;
+        skip_metadata_entries(reader, depth + 1)?;
+    }
+    Ok(())
+}
+
+/// Draco compressed geometry of a mesh primitive.
+#[derive(Clone, Debug)]
+pub struct DracoMeshCompression<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::mesh::khr_draco_mesh_compression::DracoMeshCompression,
+}
+
+impl<'a> DracoMeshCompression<'a> {
+    /// Constructs a `DracoMeshCompression`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::mesh::khr_draco_mesh_compression::DracoMeshCompression,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the buffer view holding the compressed data.
+    pub fn view(&self) -> buffer::View<'a> {
+        self.try_view().unwrap()
+    }
+
+    /// Returns the buffer view holding the compressed data, or `None` if the
+    /// index is out of bounds.
+    pub(crate) fn try_view(&self) -> Option<buffer::View<'a>> {
+        self.document.views().nth(self.json.buffer_view.value())
+    }
+
+    /// Returns the unique ID of the compressed attribute with the given
+    /// semantic.
+    pub fn attribute(&self, semantic: &Semantic) -> Option<u32> {
+        self.json
+            .attributes
+            .get(&Checked::Valid(semantic.clone()))
+            .copied()
+    }
+
+    /// Returns an `Iterator` over the semantics of the compressed attributes
+    /// and their unique IDs.
+    pub fn attributes(&self) -> Attributes<'a> {
+        Attributes {
+            iter: self.json.attributes.iter(),
+        }
+    }
+
+    /// Decodes the compressed data, which must be exactly the bytes of
+    /// [`view`](Self::view).
+    pub fn decode(&self, data: &[u8]) -> Result<Mesh, Error> {
+        decode(data)
+    }
+}
+
+/// An `Iterator` over the compressed attributes of a primitive.
+#[derive(Clone, Debug)]
+pub struct Attributes<'a> {
+    /// The internal attribute iterator.
+    iter: std::collections::btree_map::Iter<'a, Checked<Semantic>, u32>,
+}
+
+impl<'a> ExactSizeIterator for Attributes<'a> {}
+impl<'a> Iterator for Attributes<'a> {
+    type Item = (Semantic, u32);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(semantic, id)| (semantic.as_ref().unwrap().clone(), *id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -2,6 +2,7 @@ use crate::Document;
 use gltf_json::Extras;
 
 /// A light in the scene.
+#[derive(Clone, Debug)]
 pub struct Light<'a> {
     /// The parent `Document` struct.
     #[allow(dead_code)]
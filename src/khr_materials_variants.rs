@@ -7,7 +7,6 @@ pub struct Variant<'a> {
     document: &'a Document,
 
     /// The corresponding JSON index.
-    #[allow(dead_code)]
     index: usize,
 
     /// The corresponding JSON struct.
@@ -28,12 +27,57 @@ impl<'a> Variant<'a> {
         }
     }
 
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
     /// Name of the variant.
     pub fn name(&self) -> &'a str {
         &self.json.name
     }
 }
 
+/// Selects a variant by its index or by its name.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VariantRef<'s> {
+    /// The index of the variant.
+    Index(usize),
+
+    /// The name of the variant.
+    Name(&'s str),
+}
+
+impl VariantRef<'_> {
+    /// Returns the index of the selected variant, or `None` if there is no
+    /// such variant in `root`.
+    pub(crate) fn resolve(&self, root: &json::Root) -> Option<usize> {
+        let variants = &root
+            .extensions
+            .as_ref()?
+            .khr_materials_variants
+            .as_ref()?
+            .variants;
+        match *self {
+            VariantRef::Index(index) if index < variants.len() => Some(index),
+            VariantRef::Index(_) => None,
+            VariantRef::Name(name) => variants.iter().position(|variant| variant.name == name),
+        }
+    }
+}
+
+impl From<usize> for VariantRef<'_> {
+    fn from(index: usize) -> Self {
+        VariantRef::Index(index)
+    }
+}
+
+impl<'s> From<&'s str> for VariantRef<'s> {
+    fn from(name: &'s str) -> Self {
+        VariantRef::Name(name)
+    }
+}
+
 /// A mapping.
 pub struct Mapping<'a> {
     /// The parent `Document` struct.
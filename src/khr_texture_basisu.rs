@@ -0,0 +1,453 @@
+use std::fmt;
+
+use crate::{image, Document};
+
+/// The identifier at the start of every KTX2 file.
+const IDENTIFIER: [u8; 12] = [
+    0xab, 0x4b, 0x54, 0x58, 0x20, 0x32, 0x30, 0xbb, 0x0d, 0x0a, 0x1a, 0x0a,
+];
+
+/// The size of the header and of the section index that follows it.
+const HEADER_SIZE: usize = 80;
+
+/// The size of an entry of the level index.
+const LEVEL_INDEX_ENTRY_SIZE: usize = 24;
+
+/// The format of textures whose data must be transcoded.
+pub const VK_FORMAT_UNDEFINED: u32 = 0;
+
+/// The color model of data format descriptors of UASTC textures.
+pub const KHR_DF_MODEL_UASTC: u8 = 166;
+
+/// The color model of data format descriptors of ETC1S textures.
+pub const KHR_DF_MODEL_ETC1S: u8 = 163;
+
+/// The transfer function of data format descriptors of sRGB encoded data.
+pub const KHR_DF_TRANSFER_SRGB: u8 = 2;
+
+/// The size of the fixed part of a basic data format descriptor block.
+const BASIC_DESCRIPTOR_SIZE: usize = 24;
+
+/// The size of a sample of a basic data format descriptor block.
+const SAMPLE_SIZE: usize = 16;
+
+/// An error that occurred while parsing a KTX2 file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The data does not start with the KTX2 identifier.
+    Identifier,
+
+    /// The data ends before the header, an index or a section it refers to.
+    UnexpectedEnd,
+
+    /// The header or a section is not valid.
+    Invalid,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Identifier => write!(f, "missing KTX2 identifier"),
+            Error::UnexpectedEnd => write!(f, "unexpected end of KTX2 data"),
+            Error::Invalid => write!(f, "invalid KTX2 data"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The scheme used to supercompress the mip levels.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SupercompressionScheme {
+    /// The mip levels are not supercompressed.
+    None,
+
+    /// The mip levels are ETC1S data compressed with BasisLZ, using the
+    /// supercompression global data.
+    BasisLz,
+
+    /// The mip levels are compressed with Zstandard.
+    Zstandard,
+
+    /// The mip levels are compressed with zlib.
+    Zlib,
+
+    /// A scheme unknown to this crate version.
+    Other(u32),
+}
+
+impl SupercompressionScheme {
+    fn from_u32(value: u32) -> Self {
+        match value {
+            0 => SupercompressionScheme::None,
+            1 => SupercompressionScheme::BasisLz,
+            2 => SupercompressionScheme::Zstandard,
+            3 => SupercompressionScheme::Zlib,
+            other => SupercompressionScheme::Other(other),
+        }
+    }
+}
+
+/// The header of a KTX2 file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Header {
+    /// The Vulkan format of the data, `VK_FORMAT_UNDEFINED` for Basis
+    /// Universal textures.
+    pub vk_format: u32,
+
+    /// The size of the data type in bytes.
+    pub type_size: u32,
+
+    /// The width of the base level in pixels.
+    pub pixel_width: u32,
+
+    /// The height of the base level in pixels, zero for 1D textures.
+    pub pixel_height: u32,
+
+    /// The depth of the base level in pixels, zero for 1D and 2D textures.
+    pub pixel_depth: u32,
+
+    /// The number of array layers, zero for textures that are not arrays.
+    pub layer_count: u32,
+
+    /// The number of cubemap faces, either 1 or 6.
+    pub face_count: u32,
+
+    /// The number of mip levels, zero when the client is asked to generate
+    /// them from the single level stored.
+    pub level_count: u32,
+
+    /// The scheme used to supercompress the mip levels.
+    pub supercompression_scheme: SupercompressionScheme,
+}
+
+/// The location of a mip level in a KTX2 file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Level {
+    /// The offset of the level data from the start of the file.
+    pub offset: usize,
+
+    /// The length of the level data in bytes.
+    pub length: usize,
+
+    /// The length of the level data in bytes once the supercompression is
+    /// undone.
+    pub uncompressed_length: u64,
+}
+
+/// A sample of a basic data format descriptor block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Sample {
+    /// The offset of the first bit of the sample.
+    pub bit_offset: u16,
+
+    /// The number of bits of the sample.
+    pub bit_length: u8,
+
+    /// The channel of the color model the sample belongs to.
+    pub channel_type: u8,
+
+    /// The qualifier flags of the channel.
+    pub qualifiers: u8,
+
+    /// The position of the sample in the texel block.
+    pub positions: [u8; 4],
+
+    /// The value of the sample that represents the lower bound.
+    pub lower: u32,
+
+    /// The value of the sample that represents the upper bound.
+    pub upper: u32,
+}
+
+/// The basic data format descriptor block describing the texel data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BasicDescriptor {
+    /// The color model, such as `KHR_DF_MODEL_UASTC` or `KHR_DF_MODEL_ETC1S`.
+    pub color_model: u8,
+
+    /// The color primaries.
+    pub color_primaries: u8,
+
+    /// The transfer function, such as `KHR_DF_TRANSFER_SRGB`.
+    pub transfer_function: u8,
+
+    /// The flags, whose lowest bit is set for premultiplied alpha.
+    pub flags: u8,
+
+    /// The dimensions of a texel block in pixels.
+    pub texel_block_dimensions: [u8; 4],
+
+    /// The number of bytes of every plane.
+    pub bytes_planes: [u8; 8],
+
+    /// The samples of the texel block.
+    pub samples: Vec<Sample>,
+}
+
+/// A parsed KTX2 file, borrowing the data of its sections.
+#[derive(Clone, Debug)]
+pub struct Ktx2<'a> {
+    /// The whole file.
+    data: &'a [u8],
+
+    /// The header of the file.
+    header: Header,
+
+    /// The mip levels, starting from the base level.
+    levels: Vec<Level>,
+
+    /// The descriptor blocks of the data format descriptor.
+    dfd: &'a [u8],
+
+    /// The key/value pairs.
+    key_values: Vec<(&'a str, &'a [u8])>,
+
+    /// The supercompression global data.
+    supercompression_global_data: &'a [u8],
+}
+
+impl<'a> Ktx2<'a> {
+    /// Returns the header of the file.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the locations of the mip levels, starting from the base level.
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    /// Returns the data of the mip level with the given index, as stored in
+    /// the file.
+    pub fn level(&self, index: usize) -> Option<&'a [u8]> {
+        let level = self.levels.get(index)?;
+        Some(&self.data[level.offset..level.offset + level.length])
+    }
+
+    /// Returns the descriptor blocks of the data format descriptor.
+    pub fn dfd(&self) -> &'a [u8] {
+        self.dfd
+    }
+
+    /// Returns the first descriptor block of the data format descriptor if
+    /// it is a basic one.
+    pub fn basic_descriptor(&self) -> Option<BasicDescriptor> {
+        let block = self.dfd.get(..8)?;
+        let header = read_u32(block, 0);
+        let vendor_id = header & 0x1ffff;
+        let descriptor_type = header >> 17;
+        let size = (read_u32(block, 4) >> 16) as usize;
+        if vendor_id != 0 || descriptor_type != 0 || size < BASIC_DESCRIPTOR_SIZE {
+            return None;
+        }
+        let block = self.dfd.get(..size)?;
+        let mut texel_block_dimensions = [0; 4];
+        for (i, dimension) in texel_block_dimensions.iter_mut().enumerate() {
+            // Dimensions are stored minus one.
+            *dimension = block[12 + i].wrapping_add(1);
+        }
+        let mut bytes_planes = [0; 8];
+        bytes_planes.copy_from_slice(&block[16..24]);
+        let samples = block[BASIC_DESCRIPTOR_SIZE..]
+            .chunks_exact(SAMPLE_SIZE)
+            .map(|sample| Sample {
+                bit_offset: u16::from_le_bytes([sample[0], sample[1]]),
+                bit_length: sample[2].wrapping_add(1),
+                channel_type: sample[3] & 0x0f,
+                qualifiers: sample[3] >> 4,
+                positions: [sample[4], sample[5], sample[6], sample[7]],
+                lower: read_u32(sample, 8),
+                upper: read_u32(sample, 12),
+            })
+            .collect();
+        Some(BasicDescriptor {
+            color_model: block[8],
+            color_primaries: block[9],
+            transfer_function: block[10],
+            flags: block[11],
+            texel_block_dimensions,
+            bytes_planes,
+            samples,
+        })
+    }
+
+    /// Returns the key/value pairs in the order they are stored.
+    ///
+    /// Values are returned as stored, including the terminating NUL of
+    /// string values.
+    pub fn key_values(&self) -> &[(&'a str, &'a [u8])] {
+        &self.key_values
+    }
+
+    /// Returns the value of the given key.
+    pub fn key_value(&self, key: &str) -> Option<&'a [u8]> {
+        self.key_values
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the supercompression global data, which holds the codebooks
+    /// of BasisLZ supercompressed textures.
+    pub fn supercompression_global_data(&self) -> &'a [u8] {
+        self.supercompression_global_data
+    }
+
+    /// Returns whether the data is Basis Universal data that must be
+    /// transcoded, as required by the `KHR_texture_basisu` extension.
+    pub fn is_basis_universal(&self) -> bool {
+        if self.header.vk_format != VK_FORMAT_UNDEFINED {
+            return false;
+        }
+        match self.header.supercompression_scheme {
+            SupercompressionScheme::BasisLz => true,
+            _ => self
+                .basic_descriptor()
+                .map_or(false, |dfd| dfd.color_model == KHR_DF_MODEL_UASTC),
+        }
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    let bytes = &data[offset..offset + 4];
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Returns the section of `data` with the given offset and length.
+fn section(data: &[u8], offset: u64, length: u64) -> Result<&[u8], Error> {
+    if length == 0 {
+        return Ok(&[]);
+    }
+    let end = offset.checked_add(length).ok_or(Error::Invalid)?;
+    if end > data.len() as u64 {
+        return Err(Error::UnexpectedEnd);
+    }
+    Ok(&data[offset as usize..end as usize])
+}
+
+/// Parses the key/value pairs of the key/value data section.
+fn parse_key_values(mut data: &[u8]) -> Result<Vec<(&str, &[u8])>, Error> {
+    let mut key_values = Vec::new();
+    while !data.is_empty() {
+        if data.len() < 4 {
+            return Err(Error::UnexpectedEnd);
+        }
+        let length = read_u32(data, 0) as usize;
+        let entry = data.get(4..4 + length).ok_or(Error::UnexpectedEnd)?;
+        let nul = entry.iter().position(|&b| b == 0).ok_or(Error::Invalid)?;
+        let key = std::str::from_utf8(&entry[..nul]).map_err(|_| Error::Invalid)?;
+        key_values.push((key, &entry[nul + 1..]));
+        // Entries are padded to a multiple of four bytes, which the last
+        // one may omit.
+        let padded = (4 + length + 3) & !3;
+        data = &data[padded.min(data.len())..];
+    }
+    Ok(key_values)
+}
+
+/// Parses a KTX2 file.
+pub fn parse(data: &[u8]) -> Result<Ktx2<'_>, Error> {
+    if data.len() < IDENTIFIER.len() || data[..IDENTIFIER.len()] != IDENTIFIER {
+        return Err(Error::Identifier);
+    }
+    if data.len() < HEADER_SIZE {
+        return Err(Error::UnexpectedEnd);
+    }
+    let header = Header {
+        vk_format: read_u32(data, 12),
+        type_size: read_u32(data, 16),
+        pixel_width: read_u32(data, 20),
+        pixel_height: read_u32(data, 24),
+        pixel_depth: read_u32(data, 28),
+        layer_count: read_u32(data, 32),
+        face_count: read_u32(data, 36),
+        level_count: read_u32(data, 40),
+        supercompression_scheme: SupercompressionScheme::from_u32(read_u32(data, 44)),
+    };
+    if header.pixel_width == 0 || (header.face_count != 1 && header.face_count != 6) {
+        return Err(Error::Invalid);
+    }
+
+    let dfd = section(
+        data,
+        u64::from(read_u32(data, 48)),
+        u64::from(read_u32(data, 52)),
+    )?;
+    let dfd = match dfd.len() {
+        0 => dfd,
+        // The descriptor blocks follow their total size.
+        length if length >= 4 && read_u32(dfd, 0) as usize == length => &dfd[4..],
+        _ => return Err(Error::Invalid),
+    };
+    let key_values = section(
+        data,
+        u64::from(read_u32(data, 56)),
+        u64::from(read_u32(data, 60)),
+    )?;
+    let key_values = parse_key_values(key_values)?;
+    let supercompression_global_data = section(data, read_u64(data, 64), read_u64(data, 72))?;
+
+    let level_count = header.level_count.max(1) as usize;
+    let index = data
+        .get(HEADER_SIZE..HEADER_SIZE + level_count * LEVEL_INDEX_ENTRY_SIZE)
+        .ok_or(Error::UnexpectedEnd)?;
+    let levels = index
+        .chunks_exact(LEVEL_INDEX_ENTRY_SIZE)
+        .map(|entry| {
+            let (offset, length) = (read_u64(entry, 0), read_u64(entry, 8));
+            section(data, offset, length)?;
+            Ok(Level {
+                offset: offset as usize,
+                length: length as usize,
+                uncompressed_length: read_u64(entry, 16),
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(Ktx2 {
+        data,
+        header,
+        levels,
+        dfd,
+        key_values,
+        supercompression_global_data,
+    })
+}
+
+/// A texture whose source is a KTX2 image with Basis Universal
+/// supercompression.
+#[derive(Clone, Debug)]
+pub struct TextureBasisu<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::texture::TextureBasisu,
+}
+
+impl<'a> TextureBasisu<'a> {
+    /// Constructs a `TextureBasisu`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::texture::TextureBasisu,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the KTX2 image used by the texture.
+    ///
+    /// Imported KTX2 images are kept encoded and can be read with [`parse`].
+    pub fn source(&self) -> image::Image<'a> {
+        self.document
+            .images()
+            .nth(self.json.source.value())
+            .unwrap()
+    }
+}
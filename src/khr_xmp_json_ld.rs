@@ -0,0 +1,63 @@
+use crate::Document;
+
+use serde_json::{Map, Value};
+
+/// A JSON-LD metadata packet following the XMP data model, such as the
+/// licensing and authoring information of an object.
+#[derive(Clone, Debug)]
+pub struct Packet<'a> {
+    /// The parent `Document` struct.
+    #[allow(dead_code)]
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::khr_xmp_json_ld::Packet,
+}
+
+impl<'a> Packet<'a> {
+    /// Constructs a `Packet`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::root::khr_xmp_json_ld::Packet,
+    ) -> Self {
+        Self {
+            document,
+            index,
+            json,
+        }
+    }
+
+    /// Resolves the packet referenced by the extension of an object.
+    pub(crate) fn resolve(
+        document: &'a Document,
+        reference: Option<&'a json::extensions::root::khr_xmp_json_ld::KhrXmpJsonLd>,
+    ) -> Option<Self> {
+        document.xmp_packets()?.nth(reference?.packet.value())
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the JSON-LD context, which maps the namespace prefixes used by
+    /// the packet to their URIs.
+    pub fn context(&self) -> Option<&'a Map<String, Value>> {
+        self.json.context()?.as_object()
+    }
+
+    /// Returns the value of a property, named by its prefixed term such as
+    /// `dc:creator` or `xmpRights:UsageTerms`.
+    pub fn get(&self, term: &str) -> Option<&'a Value> {
+        self.json.0.get(term)
+    }
+
+    /// Returns every entry of the packet, including the `@context`.
+    pub fn properties(&self) -> &'a Map<String, Value> {
+        &self.json.0
+    }
+}
@@ -102,9 +102,40 @@ pub mod binary;
 /// Buffers and buffer views.
 pub mod buffer;
 
+/// Construction of glTF documents from typed data.
+pub mod builder;
+
 /// Cameras and their projections.
 pub mod camera;
 
+/// Operations that modify glTF documents.
+pub mod edit;
+
+/// The reference exporter.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+mod export;
+
+/// Support for the `EXT_lights_image_based` extension.
+#[cfg(feature = "EXT_lights_image_based")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_lights_image_based")))]
+pub mod ext_lights_image_based;
+
+/// Support for the `EXT_mesh_gpu_instancing` extension.
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_gpu_instancing")))]
+pub mod ext_mesh_gpu_instancing;
+
+/// Support for the `EXT_meshopt_compression` extension.
+#[cfg(feature = "EXT_meshopt_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+pub mod ext_meshopt_compression;
+
+/// Typed access to extensions defined by the application.
+#[cfg(feature = "extensions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+pub mod extension;
+
 /// Images that may be used by textures.
 pub mod image;
 
@@ -116,6 +147,16 @@ mod import;
 /// Iterators for walking the glTF node hierarchy.
 pub mod iter;
 
+/// Support for the `KHR_animation_pointer` extension.
+#[cfg(feature = "KHR_animation_pointer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+pub mod khr_animation_pointer;
+
+/// Support for the `KHR_draco_mesh_compression` extension.
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+pub mod khr_draco_mesh_compression;
+
 /// Support for the `KHR_lights_punctual` extension.
 #[cfg(feature = "KHR_lights_punctual")]
 #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
@@ -126,6 +167,16 @@ pub mod khr_lights_punctual;
 #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
 pub mod khr_materials_variants;
 
+/// Support for the `KHR_texture_basisu` extension.
+#[cfg(feature = "KHR_texture_basisu")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_basisu")))]
+pub mod khr_texture_basisu;
+
+/// Support for the `KHR_xmp_json_ld` extension.
+#[cfg(feature = "KHR_xmp_json_ld")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+pub mod khr_xmp_json_ld;
+
 /// Material properties of primitives.
 pub mod material;
 
@@ -135,6 +186,14 @@ mod math;
 /// Meshes and their primitives.
 pub mod mesh;
 
+/// Support for the `MSFT_texture_dds` extension.
+#[cfg(feature = "MSFT_texture_dds")]
+#[cfg_attr(docsrs, doc(cfg(feature = "MSFT_texture_dds")))]
+pub mod msft_texture_dds;
+
+/// Runtime options for loading glTF.
+mod options;
+
 /// The glTF node heirarchy.
 pub mod scene;
 
@@ -144,6 +203,9 @@ pub mod skin;
 /// Textures and their samplers.
 pub mod texture;
 
+/// Reporting of validation issues.
+pub mod validator;
+
 #[cfg(feature = "extensions")]
 use json::Value;
 #[cfg(feature = "extensions")]
@@ -159,6 +221,9 @@ pub use self::binary::Glb;
 pub use self::buffer::Buffer;
 #[doc(inline)]
 pub use self::camera::Camera;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::export::{export, export_with_resolver, pack, pack_slice, unpack};
 #[doc(inline)]
 pub use self::image::Image;
 #[cfg(feature = "import")]
@@ -173,11 +238,27 @@ pub use self::import::import_images;
 #[cfg(feature = "import")]
 #[doc(inline)]
 pub use self::import::import_slice;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{
+    import_buffers_with_resolver, import_images_with_resolver, import_slice_with_resolver,
+};
+#[cfg(all(feature = "import", feature = "EXT_lights_image_based"))]
+#[doc(inline)]
+pub use self::import::{import_image_based_light, import_image_based_light_with_resolver};
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{import_slice_with_options, import_with_options};
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{DefaultResolver, ResourceResolver};
 #[doc(inline)]
 pub use self::material::Material;
 #[doc(inline)]
 pub use self::mesh::{Attribute, Mesh, Primitive, Semantic};
 #[doc(inline)]
+pub use self::options::{LoadOptions, Validation};
+#[doc(inline)]
 pub use self::scene::{Node, Scene};
 #[doc(inline)]
 pub use self::skin::Skin;
@@ -225,11 +306,54 @@ pub enum Error {
     /// Standard I/O error.
     Io(std::io::Error),
 
+    /// Compressed buffer view data could not be decoded.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+    MeshoptCompression {
+        /// The index of the offending buffer view.
+        view: usize,
+
+        /// The decoding error.
+        error: ext_meshopt_compression::Error,
+    },
+
+    /// Compressed mesh primitive data could not be decoded.
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+    DracoMeshCompression {
+        /// The index of the offending mesh.
+        mesh: usize,
+
+        /// The index of the offending primitive within its mesh.
+        primitive: usize,
+
+        /// The decoding error.
+        error: khr_draco_mesh_compression::Error,
+    },
+
     /// Image decoding error.
     #[cfg(feature = "import")]
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
     Image(image_crate::ImageError),
 
+    /// A KTX2 image could not be parsed.
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_basisu")))]
+    Ktx2(khr_texture_basisu::Error),
+
+    /// A DDS image could not be parsed.
+    #[cfg(feature = "MSFT_texture_dds")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "MSFT_texture_dds")))]
+    Dds(msft_texture_dds::Error),
+
+    /// Binary glTF to be written exceeds the 4 GiB limit of its header.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    GlbLength {
+        /// The length of the binary glTF in bytes.
+        length: usize,
+    },
+
     /// The `BIN` chunk of binary glTF is referenced but does not exist.
     #[cfg(feature = "import")]
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
@@ -339,6 +463,24 @@ impl Gltf {
         gltf.document.validate()?;
         Ok(gltf)
     }
+
+    /// Loads glTF from a reader, validating it according to `options`.
+    pub fn from_reader_with_options<R>(reader: R, options: &LoadOptions) -> Result<Self>
+    where
+        R: io::Read + io::Seek,
+    {
+        let gltf = Self::from_reader_without_validation(reader)?;
+        options.validate(&gltf.document)?;
+        Ok(gltf)
+    }
+
+    /// Loads glTF from a slice of bytes, validating it according to
+    /// `options`.
+    pub fn from_slice_with_options(slice: &[u8], options: &LoadOptions) -> Result<Self> {
+        let gltf = Self::from_slice_without_validation(slice)?;
+        options.validate(&gltf.document)?;
+        Ok(gltf)
+    }
 }
 
 impl ops::Deref for Gltf {
@@ -394,7 +536,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the accessors of the glTF asset.
-    pub fn accessors(&self) -> iter::Accessors {
+    pub fn accessors(&self) -> iter::Accessors<'_> {
         iter::Accessors {
             iter: self.0.accessors.iter().enumerate(),
             document: self,
@@ -402,7 +544,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the animations of the glTF asset.
-    pub fn animations(&self) -> iter::Animations {
+    pub fn animations(&self) -> iter::Animations<'_> {
         iter::Animations {
             iter: self.0.animations.iter().enumerate(),
             document: self,
@@ -410,7 +552,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the pre-loaded buffers of the glTF asset.
-    pub fn buffers(&self) -> iter::Buffers {
+    pub fn buffers(&self) -> iter::Buffers<'_> {
         iter::Buffers {
             iter: self.0.buffers.iter().enumerate(),
             document: self,
@@ -418,7 +560,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the cameras of the glTF asset.
-    pub fn cameras(&self) -> iter::Cameras {
+    pub fn cameras(&self) -> iter::Cameras<'_> {
         iter::Cameras {
             iter: self.0.cameras.iter().enumerate(),
             document: self,
@@ -426,7 +568,7 @@ impl Document {
     }
 
     /// Returns the default scene, if provided.
-    pub fn default_scene(&self) -> Option<Scene> {
+    pub fn default_scene(&self) -> Option<Scene<'_>> {
         self.0
             .scene
             .as_ref()
@@ -434,17 +576,17 @@ impl Document {
     }
 
     /// Returns the extensions referenced in this .document file.
-    pub fn extensions_used(&self) -> iter::ExtensionsUsed {
+    pub fn extensions_used(&self) -> iter::ExtensionsUsed<'_> {
         iter::ExtensionsUsed(self.0.extensions_used.iter())
     }
 
     /// Returns the extensions required to load and render this asset.
-    pub fn extensions_required(&self) -> iter::ExtensionsRequired {
+    pub fn extensions_required(&self) -> iter::ExtensionsRequired<'_> {
         iter::ExtensionsRequired(self.0.extensions_required.iter())
     }
 
     /// Returns an `Iterator` that visits the pre-loaded images of the glTF asset.
-    pub fn images(&self) -> iter::Images {
+    pub fn images(&self) -> iter::Images<'_> {
         iter::Images {
             iter: self.0.images.iter().enumerate(),
             document: self,
@@ -467,11 +609,22 @@ impl Document {
         root.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
     /// Returns an `Iterator` that visits the lights of the glTF asset as defined by the
     /// `KHR_lights_punctual` extension.
     #[cfg(feature = "KHR_lights_punctual")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
-    pub fn lights(&self) -> Option<iter::Lights> {
+    pub fn lights(&self) -> Option<iter::Lights<'_>> {
         let iter = self
             .0
             .extensions
@@ -488,11 +641,32 @@ impl Document {
         })
     }
 
+    /// Returns an `Iterator` that visits the image-based lights of the glTF asset as
+    /// defined by the `EXT_lights_image_based` extension.
+    #[cfg(feature = "EXT_lights_image_based")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_lights_image_based")))]
+    pub fn image_based_lights(&self) -> Option<iter::ImageBasedLights<'_>> {
+        let iter = self
+            .0
+            .extensions
+            .as_ref()?
+            .ext_lights_image_based
+            .as_ref()?
+            .lights
+            .iter()
+            .enumerate();
+
+        Some(iter::ImageBasedLights {
+            iter,
+            document: self,
+        })
+    }
+
     /// Returns an `Iterator` that visits the variants of the glTF asset as defined by the
     /// `KHR_materials_variants` extension.
     #[cfg(feature = "KHR_materials_variants")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
-    pub fn variants(&self) -> Option<iter::Variants> {
+    pub fn variants(&self) -> Option<iter::Variants<'_>> {
         let iter = self
             .0
             .extensions
@@ -509,8 +683,37 @@ impl Document {
         })
     }
 
+    /// Returns an `Iterator` that visits the metadata packets of the glTF asset as
+    /// defined by the `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packets(&self) -> Option<iter::Packets<'_>> {
+        let iter = self
+            .0
+            .extensions
+            .as_ref()?
+            .khr_xmp_json_ld
+            .as_ref()?
+            .packets
+            .iter()
+            .enumerate();
+
+        Some(iter::Packets {
+            iter,
+            document: self,
+        })
+    }
+
+    /// Returns the metadata packet of the glTF asset as a whole.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<khr_xmp_json_ld::Packet<'_>> {
+        let extensions = self.0.asset.extensions.as_ref()?;
+        khr_xmp_json_ld::Packet::resolve(self, extensions.khr_xmp_json_ld.as_ref())
+    }
+
     /// Returns an `Iterator` that visits the materials of the glTF asset.
-    pub fn materials(&self) -> iter::Materials {
+    pub fn materials(&self) -> iter::Materials<'_> {
         iter::Materials {
             iter: self.0.materials.iter().enumerate(),
             document: self,
@@ -518,7 +721,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the meshes of the glTF asset.
-    pub fn meshes(&self) -> iter::Meshes {
+    pub fn meshes(&self) -> iter::Meshes<'_> {
         iter::Meshes {
             iter: self.0.meshes.iter().enumerate(),
             document: self,
@@ -526,7 +729,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the nodes of the glTF asset.
-    pub fn nodes(&self) -> iter::Nodes {
+    pub fn nodes(&self) -> iter::Nodes<'_> {
         iter::Nodes {
             iter: self.0.nodes.iter().enumerate(),
             document: self,
@@ -534,7 +737,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the samplers of the glTF asset.
-    pub fn samplers(&self) -> iter::Samplers {
+    pub fn samplers(&self) -> iter::Samplers<'_> {
         iter::Samplers {
             iter: self.0.samplers.iter().enumerate(),
             document: self,
@@ -542,7 +745,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the scenes of the glTF asset.
-    pub fn scenes(&self) -> iter::Scenes {
+    pub fn scenes(&self) -> iter::Scenes<'_> {
         iter::Scenes {
             iter: self.0.scenes.iter().enumerate(),
             document: self,
@@ -550,7 +753,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the skins of the glTF asset.
-    pub fn skins(&self) -> iter::Skins {
+    pub fn skins(&self) -> iter::Skins<'_> {
         iter::Skins {
             iter: self.0.skins.iter().enumerate(),
             document: self,
@@ -558,7 +761,7 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the textures of the glTF asset.
-    pub fn textures(&self) -> iter::Textures {
+    pub fn textures(&self) -> iter::Textures<'_> {
         iter::Textures {
             iter: self.0.textures.iter().enumerate(),
             document: self,
@@ -567,7 +770,7 @@ impl Document {
 
     /// Returns an `Iterator` that visits the pre-loaded buffer views of the glTF
     /// asset.
-    pub fn views(&self) -> iter::Views {
+    pub fn views(&self) -> iter::Views<'_> {
         iter::Views {
             iter: self.0.buffer_views.iter().enumerate(),
             document: self,
@@ -595,8 +798,26 @@ impl std::fmt::Display for Error {
             }
             Error::Deserialize(ref e) => e.fmt(f),
             Error::Io(ref e) => e.fmt(f),
+            #[cfg(feature = "EXT_meshopt_compression")]
+            Error::MeshoptCompression { view, error } => {
+                write!(f, "buffer view {}: {}", view, error)
+            }
+            #[cfg(feature = "KHR_draco_mesh_compression")]
+            Error::DracoMeshCompression {
+                mesh,
+                primitive,
+                error,
+            } => write!(f, "mesh {} primitive {}: {}", mesh, primitive, error),
             #[cfg(feature = "import")]
             Error::Image(ref e) => e.fmt(f),
+            #[cfg(feature = "KHR_texture_basisu")]
+            Error::Ktx2(ref e) => e.fmt(f),
+            #[cfg(feature = "MSFT_texture_dds")]
+            Error::Dds(ref e) => e.fmt(f),
+            #[cfg(feature = "import")]
+            Error::GlbLength { length } => {
+                write!(f, "binary glTF of {} bytes exceeds the 4 GiB limit", length)
+            }
             #[cfg(feature = "import")]
             Error::MissingBlob => write!(f, "missing binary portion of binary glTF"),
             #[cfg(feature = "import")]
@@ -112,6 +112,25 @@ impl<'a> Material<'a> {
         ext.others.get(key)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
+    /// Returns the metadata packet of this material.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        let extensions = self.json.extensions.as_ref()?;
+        crate::khr_xmp_json_ld::Packet::resolve(self.document, extensions.khr_xmp_json_ld.as_ref())
+    }
+
     /// Parameter values that define the specular-glossiness material model from
     /// Physically-Based Rendering (PBR) methodology.
     #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
@@ -137,6 +156,19 @@ impl<'a> Material<'a> {
             .map(|x| Transmission::new(self.document, x))
     }
 
+    /// Parameter values that define the diffuse transmission of light through
+    /// thin surfaces of the material
+    #[cfg(feature = "KHR_materials_diffuse_transmission")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_diffuse_transmission")))]
+    pub fn diffuse_transmission(&self) -> Option<DiffuseTransmission<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .diffuse_transmission
+            .as_ref()
+            .map(|x| DiffuseTransmission::new(self.document, x))
+    }
+
     /// Parameter values that define the index of refraction of the material
     #[cfg(feature = "KHR_materials_ior")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_ior")))]
@@ -168,6 +200,19 @@ impl<'a> Material<'a> {
             .map(|x| Volume::new(self.document, x))
     }
 
+    /// Parameter value that defines the strength of the dispersion of light
+    /// through the volume of the material
+    #[cfg(feature = "KHR_materials_dispersion")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_dispersion")))]
+    pub fn dispersion(&self) -> Option<f32> {
+        self.json
+            .extensions
+            .as_ref()?
+            .dispersion
+            .as_ref()
+            .map(|x| x.dispersion.0)
+    }
+
     /// Parameter values that define the strength and colour of the specular reflection of the material
     #[cfg(feature = "KHR_materials_specular")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_specular")))]
@@ -185,7 +230,7 @@ impl<'a> Material<'a> {
     /// [`KHR_materials_clearcoat`](https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_clearcoat/README.md)
     #[cfg(feature = "KHR_materials_clearcoat")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_clearcoat")))]
-    pub fn clearcoat(&self) -> Option<Clearcoat> {
+    pub fn clearcoat(&self) -> Option<Clearcoat<'_>> {
         self.json
             .extensions
             .as_ref()?
@@ -199,7 +244,7 @@ impl<'a> Material<'a> {
     /// [`KHR_materials_sheen`](https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_sheen/README.md)
     #[cfg(feature = "KHR_materials_sheen")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_sheen")))]
-    pub fn sheen(&self) -> Option<Sheen> {
+    pub fn sheen(&self) -> Option<Sheen<'_>> {
         self.json
             .extensions
             .as_ref()?
@@ -208,6 +253,34 @@ impl<'a> Material<'a> {
             .map(|sheen| Sheen::new(self.document, sheen))
     }
 
+    /// Parameter values that define the iridescence material model.
+    ///
+    /// [`KHR_materials_iridescence`](https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_iridescence/README.md)
+    #[cfg(feature = "KHR_materials_iridescence")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_iridescence")))]
+    pub fn iridescence(&self) -> Option<Iridescence<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .iridescence
+            .as_ref()
+            .map(|iridescence| Iridescence::new(self.document, iridescence))
+    }
+
+    /// Parameter values that define the anisotropy material model.
+    ///
+    /// [`KHR_materials_anisotropy`](https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_anisotropy/README.md)
+    #[cfg(feature = "KHR_materials_anisotropy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_anisotropy")))]
+    pub fn anisotropy(&self) -> Option<Anisotropy<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .anisotropy
+            .as_ref()
+            .map(|anisotropy| Anisotropy::new(self.document, anisotropy))
+    }
+
     /// A tangent space normal map.
     ///
     /// The texture contains RGB components in linear space. Each texel represents
@@ -414,6 +487,69 @@ impl<'a> Transmission<'a> {
     }
 }
 
+/// Parameter values that define the diffuse transmission of light through
+/// thin surfaces of the material
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_diffuse_transmission")))]
+pub struct DiffuseTransmission<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::DiffuseTransmission,
+}
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_diffuse_transmission")))]
+impl<'a> DiffuseTransmission<'a> {
+    /// Constructs `DiffuseTransmission`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::DiffuseTransmission,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the percentage of non-specularly reflected light that is
+    /// diffusely transmitted through the surface.
+    ///
+    /// The default value is `0.0`.
+    pub fn diffuse_transmission_factor(&self) -> f32 {
+        self.json.diffuse_transmission_factor.0
+    }
+
+    /// Returns the diffuse transmission texture, sampled from the A channel.
+    pub fn diffuse_transmission_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.diffuse_transmission_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Returns the color that modulates the transmitted light in linear space.
+    ///
+    /// The default value is `[1.0, 1.0, 1.0]`.
+    pub fn diffuse_transmission_color_factor(&self) -> [f32; 3] {
+        self.json.diffuse_transmission_color_factor.0
+    }
+
+    /// Returns the diffuse transmission color texture (sRGB).
+    pub fn diffuse_transmission_color_texture(&self) -> Option<texture::Info<'a>> {
+        self.json
+            .diffuse_transmission_color_texture
+            .as_ref()
+            .map(|json| {
+                let texture = self.document.textures().nth(json.index.value()).unwrap();
+                texture::Info::new(texture, json)
+            })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
 /// Parameter values that define a volume for the transmission of light through the material
 #[cfg(feature = "KHR_materials_volume")]
 #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_volume")))]
@@ -806,7 +942,7 @@ impl<'a> Clearcoat<'a> {
     }
 
     /// Returns the clearcoat normal map texture.
-    pub fn clearcoat_normal_texture(&self) -> Option<NormalTexture> {
+    pub fn clearcoat_normal_texture(&self) -> Option<NormalTexture<'_>> {
         self.json.clearcoat_normal_texture.as_ref().map(|json| {
             let texture = self.document.textures().nth(json.index.value()).unwrap();
             NormalTexture::new(texture, json)
@@ -875,3 +1011,135 @@ impl<'a> Sheen<'a> {
         &self.json.extras
     }
 }
+
+/// Parameter values that define the iridescence material model.
+///
+/// [`KHR_materials_iridescence`](https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_iridescence/README.md)
+#[cfg(feature = "KHR_materials_iridescence")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_iridescence")))]
+pub struct Iridescence<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Iridescence,
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_iridescence")))]
+impl<'a> Iridescence<'a> {
+    /// Constructs `Iridescence`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Iridescence,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the iridescence intensity.
+    ///
+    /// The default value is `0.0`.
+    pub fn iridescence_factor(&self) -> f32 {
+        self.json.iridescence_factor.0
+    }
+
+    /// Returns the iridescence intensity texture, sampled from the R channel.
+    pub fn iridescence_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.iridescence_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Returns the index of refraction of the thin-film layer.
+    ///
+    /// The default value is `1.3`.
+    pub fn iridescence_ior(&self) -> f32 {
+        self.json.iridescence_ior.0
+    }
+
+    /// Returns the minimum thickness of the thin-film layer in nanometers.
+    ///
+    /// The default value is `100.0`.
+    pub fn iridescence_thickness_minimum(&self) -> f32 {
+        self.json.iridescence_thickness_minimum.0
+    }
+
+    /// Returns the maximum thickness of the thin-film layer in nanometers.
+    ///
+    /// The default value is `400.0`.
+    pub fn iridescence_thickness_maximum(&self) -> f32 {
+        self.json.iridescence_thickness_maximum.0
+    }
+
+    /// Returns the thickness texture of the thin-film layer, whose G channel
+    /// interpolates between the minimum and maximum thickness.
+    pub fn iridescence_thickness_texture(&self) -> Option<texture::Info<'a>> {
+        self.json
+            .iridescence_thickness_texture
+            .as_ref()
+            .map(|json| {
+                let texture = self.document.textures().nth(json.index.value()).unwrap();
+                texture::Info::new(texture, json)
+            })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+/// Parameter values that define the anisotropy material model.
+///
+/// [`KHR_materials_anisotropy`](https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_anisotropy/README.md)
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_anisotropy")))]
+pub struct Anisotropy<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Anisotropy,
+}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_anisotropy")))]
+impl<'a> Anisotropy<'a> {
+    /// Constructs `Anisotropy`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Anisotropy,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the anisotropy strength.
+    ///
+    /// The default value is `0.0`.
+    pub fn anisotropy_strength(&self) -> f32 {
+        self.json.anisotropy_strength.0
+    }
+
+    /// Returns the rotation of the anisotropy direction in radians,
+    /// counter-clockwise from the tangent.
+    ///
+    /// The default value is `0.0`.
+    pub fn anisotropy_rotation(&self) -> f32 {
+        self.json.anisotropy_rotation.0
+    }
+
+    /// Returns the anisotropy texture, whose RG channels hold the direction
+    /// in tangent space and whose B channel holds the strength.
+    pub fn anisotropy_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.anisotropy_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
@@ -71,10 +71,10 @@ impl<'a> Iterator for Primitives<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let mesh = self.mesh;
         self.iter
-            .last()
+            .next_back()
             .map(|(index, json)| Primitive::new(mesh, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -121,10 +121,10 @@ impl<'a> Iterator for MorphTargets<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|json| map_morph_target(document, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
@@ -162,10 +162,10 @@ impl<'a> Iterator for Mappings<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
+    fn last(mut self) -> Option<Self::Item> {
         let document = self.document;
         self.iter
-            .last()
+            .next_back()
             .map(|json| crate::khr_materials_variants::Mapping::new(document, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
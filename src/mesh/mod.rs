@@ -164,6 +164,25 @@ impl<'a> Mesh<'a> {
         ext.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
+    /// Returns the metadata packet of this mesh.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        let extensions = self.json.extensions.as_ref()?;
+        crate::khr_xmp_json_ld::Packet::resolve(self.document, extensions.khr_xmp_json_ld.as_ref())
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -231,6 +250,17 @@ impl<'a> Primitive<'a> {
         ext.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -295,6 +325,22 @@ impl<'a> Primitive<'a> {
         }
     }
 
+    /// Returns the Draco compressed geometry of the primitive.
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+    pub fn draco_mesh_compression(
+        &self,
+    ) -> Option<crate::khr_draco_mesh_compression::DracoMeshCompression<'a>> {
+        let extension = self.json.extensions.as_ref()?;
+        let compression = extension.khr_draco_mesh_compression.as_ref()?;
+        Some(
+            crate::khr_draco_mesh_compression::DracoMeshCompression::new(
+                self.mesh.document,
+                compression,
+            ),
+        )
+    }
+
     /// Get the material variants.
     #[cfg(feature = "KHR_materials_variants")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
@@ -313,6 +359,25 @@ impl<'a> Primitive<'a> {
         }
     }
 
+    /// Returns the material of the primitive when the given variant is
+    /// active.
+    ///
+    /// The primitive's own material is returned when no mapping lists the
+    /// variant. Returns `None` if the document has no such variant.
+    #[cfg(feature = "KHR_materials_variants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+    pub fn material_for_variant<'s>(
+        &self,
+        variant: impl Into<crate::khr_materials_variants::VariantRef<'s>>,
+    ) -> Option<Material<'a>> {
+        let index = variant.into().resolve(self.mesh.document.as_json())? as u32;
+        let material = self
+            .mappings()
+            .find(|mapping| mapping.variants().contains(&index))
+            .map_or_else(|| self.material(), |mapping| mapping.material());
+        Some(material)
+    }
+
     /// Constructs the primitive reader.
     #[cfg(feature = "utils")]
     #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
@@ -333,23 +398,38 @@ where
     F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
     /// Visits the vertex positions of a primitive.
+    ///
+    /// Returns `None` if the positions are not `f32`, such as those quantized
+    /// with `KHR_mesh_quantization`; see `read_positions_quantized`, available with
+    /// the `KHR_mesh_quantization` feature.
     pub fn read_positions(&self) -> Option<util::ReadPositions<'s>> {
         self.primitive
             .get(&Semantic::Positions)
+            .filter(|accessor| accessor.data_type() == accessor::DataType::F32)
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
 
     /// Visits the vertex normals of a primitive.
+    ///
+    /// Returns `None` if the normals are not `f32`, such as those quantized
+    /// with `KHR_mesh_quantization`; see `read_normals_quantized`, available with
+    /// the `KHR_mesh_quantization` feature.
     pub fn read_normals(&self) -> Option<util::ReadNormals<'s>> {
         self.primitive
             .get(&Semantic::Normals)
+            .filter(|accessor| accessor.data_type() == accessor::DataType::F32)
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
 
     /// Visits the vertex tangents of a primitive.
+    ///
+    /// Returns `None` if the tangents are not `f32`, such as those quantized
+    /// with `KHR_mesh_quantization`; see `read_tangents_quantized`, available with
+    /// the `KHR_mesh_quantization` feature.
     pub fn read_tangents(&self) -> Option<util::ReadTangents<'s>> {
         self.primitive
             .get(&Semantic::Tangents)
+            .filter(|accessor| accessor.data_type() == accessor::DataType::F32)
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
 
@@ -449,6 +529,47 @@ where
             })
     }
 
+    /// Visits the vertex positions of a primitive with any component type
+    /// permitted by `KHR_mesh_quantization`.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+    pub fn read_positions_quantized(&self) -> Option<util::ReadQuantized<'s, 3>> {
+        self.read_quantized(&Semantic::Positions)
+    }
+
+    /// Visits the vertex normals of a primitive with any component type
+    /// permitted by `KHR_mesh_quantization`.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+    pub fn read_normals_quantized(&self) -> Option<util::ReadQuantized<'s, 3>> {
+        self.read_quantized(&Semantic::Normals)
+    }
+
+    /// Visits the vertex tangents of a primitive with any component type
+    /// permitted by `KHR_mesh_quantization`.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+    pub fn read_tangents_quantized(&self) -> Option<util::ReadQuantized<'s, 4>> {
+        self.read_quantized(&Semantic::Tangents)
+    }
+
+    /// Visits the vertex texture co-ordinates of a primitive with any
+    /// component type permitted by `KHR_mesh_quantization`.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+    pub fn read_tex_coords_quantized(&self, set: u32) -> Option<util::ReadQuantized<'s, 2>> {
+        self.read_quantized(&Semantic::TexCoords(set))
+    }
+
+    #[cfg(feature = "KHR_mesh_quantization")]
+    fn read_quantized<const N: usize>(
+        &self,
+        semantic: &Semantic,
+    ) -> Option<util::ReadQuantized<'s, N>> {
+        let accessor = self.primitive.get(semantic)?;
+        util::quantized::read(accessor, self.get_buffer_data.clone())
+    }
+
     /// Visits the morph targets of the primitive.
     pub fn read_morph_targets(&self) -> util::ReadMorphTargets<'a, 's, F> {
         util::ReadMorphTargets {
@@ -7,6 +7,14 @@ pub mod indices;
 /// Casting iterator adapters for joint indices.
 pub mod joints;
 
+/// Casting iterator adapters for quantized vertex attributes.
+#[cfg(any(feature = "KHR_mesh_quantization", feature = "EXT_mesh_gpu_instancing"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "KHR_mesh_quantization", feature = "EXT_mesh_gpu_instancing")))
+)]
+pub mod quantized;
+
 /// Casting iterator adapters for texture co-ordinates.
 pub mod tex_coords;
 
@@ -89,6 +97,35 @@ pub enum ReadTexCoords<'a> {
     F32(Iter<'a, [f32; 2]>),
 }
 
+/// Vertex attributes of `N` components, with any of the component types
+/// permitted by `KHR_mesh_quantization`.
+#[cfg(any(feature = "KHR_mesh_quantization", feature = "EXT_mesh_gpu_instancing"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "KHR_mesh_quantization", feature = "EXT_mesh_gpu_instancing")))
+)]
+#[derive(Clone, Debug)]
+pub enum ReadQuantized<'a, const N: usize> {
+    /// Components of type `i8`.
+    I8(Iter<'a, [i8; N]>),
+    /// Normalized components of type `i8`.
+    I8Normalized(Iter<'a, [i8; N]>),
+    /// Components of type `u8`.
+    U8(Iter<'a, [u8; N]>),
+    /// Normalized components of type `u8`.
+    U8Normalized(Iter<'a, [u8; N]>),
+    /// Components of type `i16`.
+    I16(Iter<'a, [i16; N]>),
+    /// Normalized components of type `i16`.
+    I16Normalized(Iter<'a, [i16; N]>),
+    /// Components of type `u16`.
+    U16(Iter<'a, [u16; N]>),
+    /// Normalized components of type `u16`.
+    U16Normalized(Iter<'a, [u16; N]>),
+    /// Components of type `f32`.
+    F32(Iter<'a, [f32; N]>),
+}
+
 /// Weights.
 #[derive(Clone, Debug)]
 pub enum ReadWeights<'a> {
@@ -221,6 +258,15 @@ impl<'a> ReadTexCoords<'a> {
     }
 }
 
+#[cfg(any(feature = "KHR_mesh_quantization", feature = "EXT_mesh_gpu_instancing"))]
+impl<'a, const N: usize> ReadQuantized<'a, N> {
+    /// Dequantize the components into f32, taking normalization into
+    /// account.
+    pub fn into_f32(self) -> self::quantized::CastingIter<'a, N> {
+        self::quantized::CastingIter::new(self)
+    }
+}
+
 impl<'a> ReadWeights<'a> {
     /// Reinterpret weights as u8.  Lossy if the underlying iterator yields u16
     /// or f32.
@@ -0,0 +1,118 @@
+use crate::accessor::{self, Accessor, DataType};
+use crate::{Buffer, Normalize};
+
+use super::ReadQuantized;
+
+/// Casting iterator that dequantizes vertex attributes into `f32`.
+///
+/// Normalized integers are mapped onto `[-1.0, 1.0]` or `[0.0, 1.0]` and other
+/// integers are converted as they are.
+#[derive(Clone, Debug)]
+pub struct CastingIter<'a, const N: usize>(ReadQuantized<'a, N>);
+
+/// Dequantizes the element, if any, produced by `$next` on the iterator of
+/// each variant.
+macro_rules! dequantize {
+    ($read:expr, $iter:ident => $next:expr) => {
+        match $read {
+            ReadQuantized::I8($iter) => $next.map(|x| x.map(f32::from)),
+            ReadQuantized::I8Normalized($iter) => $next.map(|x| x.map(Normalize::normalize)),
+            ReadQuantized::U8($iter) => $next.map(|x| x.map(f32::from)),
+            ReadQuantized::U8Normalized($iter) => $next.map(|x| x.map(Normalize::normalize)),
+            ReadQuantized::I16($iter) => $next.map(|x| x.map(f32::from)),
+            ReadQuantized::I16Normalized($iter) => $next.map(|x| x.map(Normalize::normalize)),
+            ReadQuantized::U16($iter) => $next.map(|x| x.map(f32::from)),
+            ReadQuantized::U16Normalized($iter) => $next.map(|x| x.map(Normalize::normalize)),
+            ReadQuantized::F32($iter) => $next,
+        }
+    };
+}
+
+impl<'a, const N: usize> CastingIter<'a, N> {
+    pub(crate) fn new(iter: ReadQuantized<'a, N>) -> Self {
+        CastingIter(iter)
+    }
+
+    /// Unwrap underlying `ReadQuantized` object.
+    pub fn unwrap(self) -> ReadQuantized<'a, N> {
+        self.0
+    }
+}
+
+impl<'a, const N: usize> ExactSizeIterator for CastingIter<'a, N> {}
+impl<'a, const N: usize> Iterator for CastingIter<'a, N> {
+    type Item = [f32; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        dequantize!(&mut self.0, i => i.next())
+    }
+
+    #[inline]
+    fn nth(&mut self, x: usize) -> Option<Self::Item> {
+        dequantize!(&mut self.0, i => i.nth(x))
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        dequantize!(self.0, i => i.last())
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.0 {
+            ReadQuantized::I8(ref i) | ReadQuantized::I8Normalized(ref i) => i.size_hint(),
+            ReadQuantized::U8(ref i) | ReadQuantized::U8Normalized(ref i) => i.size_hint(),
+            ReadQuantized::I16(ref i) | ReadQuantized::I16Normalized(ref i) => i.size_hint(),
+            ReadQuantized::U16(ref i) | ReadQuantized::U16Normalized(ref i) => i.size_hint(),
+            ReadQuantized::F32(ref i) => i.size_hint(),
+        }
+    }
+}
+
+/// Reads an accessor of `N` components with any of the component types
+/// permitted by `KHR_mesh_quantization`.
+pub(crate) fn read<'a, 's, F, const N: usize>(
+    accessor: Accessor<'a>,
+    get_buffer_data: F,
+) -> Option<ReadQuantized<'s, N>>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    if accessor.dimensions().multiplicity() != N {
+        return None;
+    }
+    match (accessor.data_type(), accessor.normalized()) {
+        (DataType::I8, false) => {
+            accessor::Iter::new(accessor, get_buffer_data).map(ReadQuantized::I8)
+        }
+        (DataType::I8, true) => {
+            accessor::Iter::new(accessor, get_buffer_data).map(ReadQuantized::I8Normalized)
+        }
+        (DataType::U8, false) => {
+            accessor::Iter::new(accessor, get_buffer_data).map(ReadQuantized::U8)
+        }
+        (DataType::U8, true) => {
+            accessor::Iter::new(accessor, get_buffer_data).map(ReadQuantized::U8Normalized)
+        }
+        (DataType::I16, false) => {
+            accessor::Iter::new(accessor, get_buffer_data).map(ReadQuantized::I16)
+        }
+        (DataType::I16, true) => {
+            accessor::Iter::new(accessor, get_buffer_data).map(ReadQuantized::I16Normalized)
+        }
+        (DataType::U16, false) => {
+            accessor::Iter::new(accessor, get_buffer_data).map(ReadQuantized::U16)
+        }
+        (DataType::U16, true) => {
+            accessor::Iter::new(accessor, get_buffer_data).map(ReadQuantized::U16Normalized)
+        }
+        (DataType::F32, _) => {
+            accessor::Iter::new(accessor, get_buffer_data).map(ReadQuantized::F32)
+        }
+        (DataType::U32, _) => None,
+    }
+}
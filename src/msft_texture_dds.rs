@@ -0,0 +1,543 @@
+use std::fmt;
+
+use crate::{image, Document};
+
+/// The magic number at the start of every DDS file.
+const MAGIC: &[u8] = b"DDS ";
+
+/// The size of the header that follows the magic number.
+const HEADER_SIZE: u32 = 124;
+
+/// The size of the pixel format of the header.
+const PIXEL_FORMAT_SIZE: u32 = 32;
+
+/// The size of the header of files with a DXGI format.
+const DX10_HEADER_SIZE: usize = 20;
+
+/// The pixel format flag set when the format is given by a four-character
+/// code.
+pub const DDPF_FOURCC: u32 = 0x4;
+
+/// The pixel format flag set when uncompressed color data is described by
+/// bit masks.
+pub const DDPF_RGB: u32 = 0x40;
+
+/// The pixel format flag set when uncompressed luminance data is described
+/// by bit masks.
+pub const DDPF_LUMINANCE: u32 = 0x2_0000;
+
+/// The pixel format flag set when the alpha mask is valid.
+pub const DDPF_ALPHAPIXELS: u32 = 0x1;
+
+/// The pixel format flag set when the data holds alpha only.
+pub const DDPF_ALPHA: u32 = 0x2;
+
+/// The capability flag set for cubemaps.
+pub const DDSCAPS2_CUBEMAP: u32 = 0x200;
+
+/// The capability flags of the individual cubemap faces.
+const DDSCAPS2_CUBEMAP_FACES: [u32; 6] = [0x400, 0x800, 0x1000, 0x2000, 0x4000, 0x8000];
+
+/// The capability flag set for volume textures.
+pub const DDSCAPS2_VOLUME: u32 = 0x20_0000;
+
+/// The resource dimension of volume textures in the DX10 header.
+pub const D3D10_RESOURCE_DIMENSION_TEXTURE3D: u32 = 4;
+
+/// The flag of the DX10 header set for cubemaps.
+pub const D3D10_RESOURCE_MISC_TEXTURECUBE: u32 = 0x4;
+
+/// An error that occurred while parsing a DDS file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The data does not start with the DDS magic number.
+    Magic,
+
+    /// The data ends before the headers or the last level.
+    UnexpectedEnd,
+
+    /// A header is not valid.
+    Invalid,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Magic => write!(f, "missing DDS magic number"),
+            Error::UnexpectedEnd => write!(f, "unexpected end of DDS data"),
+            Error::Invalid => write!(f, "invalid DDS header"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The pixel format of the DDS header.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PixelFormat {
+    /// The flags describing which of the other fields are valid.
+    pub flags: u32,
+
+    /// The four-character code of the format, `DX10` when the DX10 header
+    /// follows.
+    pub four_cc: [u8; 4],
+
+    /// The number of bits of an uncompressed pixel.
+    pub rgb_bit_count: u32,
+
+    /// The mask of the red, or luminance, bits of an uncompressed pixel.
+    pub red_mask: u32,
+
+    /// The mask of the green bits of an uncompressed pixel.
+    pub green_mask: u32,
+
+    /// The mask of the blue bits of an uncompressed pixel.
+    pub blue_mask: u32,
+
+    /// The mask of the alpha bits of an uncompressed pixel.
+    pub alpha_mask: u32,
+}
+
+/// The DDS header.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Header {
+    /// The flags describing which of the other fields are valid.
+    pub flags: u32,
+
+    /// The height of the base level in pixels.
+    pub height: u32,
+
+    /// The width of the base level in pixels.
+    pub width: u32,
+
+    /// The pitch of a row of the base level, or its size for compressed
+    /// formats.
+    pub pitch_or_linear_size: u32,
+
+    /// The depth of the base level of volume textures.
+    pub depth: u32,
+
+    /// The number of mip levels.
+    pub mip_map_count: u32,
+
+    /// The pixel format.
+    pub pixel_format: PixelFormat,
+
+    /// The capability flags describing the complexity of the surface.
+    pub caps: u32,
+
+    /// The capability flags describing cubemaps and volume textures.
+    pub caps2: u32,
+}
+
+/// The header of files with a DXGI format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Dx10Header {
+    /// The DXGI format of the data.
+    pub dxgi_format: u32,
+
+    /// The dimension of the texture.
+    pub resource_dimension: u32,
+
+    /// The flags of the texture, including
+    /// `D3D10_RESOURCE_MISC_TEXTURECUBE`.
+    pub misc_flag: u32,
+
+    /// The number of array elements, counting cubes rather than faces.
+    pub array_size: u32,
+
+    /// Further flags, whose lowest three bits hold the alpha mode.
+    pub misc_flags2: u32,
+}
+
+/// The location of a mip level of one array element and face.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Level {
+    /// The index of the array element.
+    pub layer: u32,
+
+    /// The index of the cubemap face, zero for other textures.
+    pub face: u32,
+
+    /// The index of the mip level.
+    pub level: u32,
+
+    /// The width of the level in pixels.
+    pub width: u32,
+
+    /// The height of the level in pixels.
+    pub height: u32,
+
+    /// The depth of the level in pixels.
+    pub depth: u32,
+
+    /// The offset of the level data from the start of the file.
+    pub offset: usize,
+
+    /// The length of the level data in bytes.
+    pub length: usize,
+}
+
+/// A parsed DDS file, borrowing the data of its levels.
+#[derive(Clone, Debug)]
+pub struct Dds<'a> {
+    /// The whole file.
+    data: &'a [u8],
+
+    /// The DDS header.
+    header: Header,
+
+    /// The DX10 header, if present.
+    dx10_header: Option<Dx10Header>,
+
+    /// The DXGI format of the data, if known.
+    dxgi_format: Option<u32>,
+
+    /// The number of array elements.
+    array_size: u32,
+
+    /// The number of cubemap faces.
+    face_count: u32,
+
+    /// The levels of every array element and face, if the size of the
+    /// format is known.
+    levels: Option<Vec<Level>>,
+}
+
+impl<'a> Dds<'a> {
+    /// Returns the DDS header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the DX10 header, present for files with a DXGI format.
+    pub fn dx10_header(&self) -> Option<&Dx10Header> {
+        self.dx10_header.as_ref()
+    }
+
+    /// Returns the DXGI format of the data.
+    ///
+    /// Legacy four-character codes and bit masks are mapped onto the
+    /// equivalent DXGI format when one exists.
+    pub fn dxgi_format(&self) -> Option<u32> {
+        self.dxgi_format
+    }
+
+    /// Returns the width of the base level in pixels.
+    pub fn width(&self) -> u32 {
+        self.header.width
+    }
+
+    /// Returns the height of the base level in pixels.
+    pub fn height(&self) -> u32 {
+        self.header.height
+    }
+
+    /// Returns the depth of the base level in pixels, one for textures that
+    /// are not volume textures.
+    pub fn depth(&self) -> u32 {
+        if self.is_volume() {
+            self.header.depth.max(1)
+        } else {
+            1
+        }
+    }
+
+    /// Returns the number of mip levels.
+    pub fn mip_count(&self) -> u32 {
+        self.header.mip_map_count.max(1)
+    }
+
+    /// Returns the number of array elements.
+    pub fn array_size(&self) -> u32 {
+        self.array_size
+    }
+
+    /// Returns the number of cubemap faces, one for textures that are not
+    /// cubemaps.
+    pub fn face_count(&self) -> u32 {
+        self.face_count
+    }
+
+    /// Returns whether the texture is a cubemap.
+    pub fn is_cubemap(&self) -> bool {
+        match self.dx10_header {
+            Some(dx10) => dx10.misc_flag & D3D10_RESOURCE_MISC_TEXTURECUBE != 0,
+            None => self.header.caps2 & DDSCAPS2_CUBEMAP != 0,
+        }
+    }
+
+    /// Returns whether the texture is a volume texture.
+    pub fn is_volume(&self) -> bool {
+        match self.dx10_header {
+            Some(dx10) => dx10.resource_dimension == D3D10_RESOURCE_DIMENSION_TEXTURE3D,
+            None => self.header.caps2 & DDSCAPS2_VOLUME != 0,
+        }
+    }
+
+    /// Returns the levels of every array element and face in the order they
+    /// are stored, or `None` if the size of the format is not known.
+    pub fn levels(&self) -> Option<&[Level]> {
+        self.levels.as_deref()
+    }
+
+    /// Returns the data of the given mip level of an array element and face.
+    pub fn level(&self, layer: u32, face: u32, level: u32) -> Option<&'a [u8]> {
+        let level = self
+            .levels
+            .as_ref()?
+            .iter()
+            .find(|l| l.layer == layer && l.face == face && l.level == level)?;
+        Some(&self.data[level.offset..level.offset + level.length])
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    let bytes = &data[offset..offset + 4];
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+/// Returns the DXGI format equivalent to a legacy pixel format.
+fn legacy_dxgi_format(format: &PixelFormat) -> Option<u32> {
+    if format.flags & DDPF_FOURCC != 0 {
+        return match &format.four_cc {
+            b"DXT1" => Some(71),
+            b"DXT2" | b"DXT3" => Some(74),
+            b"DXT4" | b"DXT5" => Some(77),
+            b"ATI1" | b"BC4U" => Some(80),
+            b"BC4S" => Some(81),
+            b"ATI2" | b"BC5U" => Some(83),
+            b"BC5S" => Some(84),
+            b"RGBG" => Some(68),
+            b"GRGB" => Some(69),
+            code => match u32::from_le_bytes(*code) {
+                // Direct3D 9 format numbers stored in place of a code.
+                36 => Some(11),
+                110 => Some(13),
+                111 => Some(54),
+                112 => Some(34),
+                113 => Some(10),
+                114 => Some(41),
+                115 => Some(16),
+                116 => Some(2),
+                _ => None,
+            },
+        };
+    }
+    let masks = (
+        format.red_mask,
+        format.green_mask,
+        format.blue_mask,
+        format.alpha_mask,
+    );
+    if format.flags & DDPF_RGB != 0 {
+        match (format.rgb_bit_count, masks) {
+            (32, (0xff, 0xff00, 0xff_0000, 0xff00_0000)) => Some(28),
+            (32, (0xff_0000, 0xff00, 0xff, 0xff00_0000)) => Some(87),
+            (32, (0xff_0000, 0xff00, 0xff, 0)) => Some(88),
+            (32, (0x3ff, 0xf_fc00, 0x3ff0_0000, 0xc000_0000)) => Some(24),
+            (32, (0xffff, 0xffff_0000, 0, 0)) => Some(35),
+            (32, (0xffff_ffff, 0, 0, 0)) => Some(41),
+            (16, (0xf800, 0x7e0, 0x1f, 0)) => Some(85),
+            (16, (0x7c00, 0x3e0, 0x1f, 0x8000)) => Some(86),
+            (16, (0xf00, 0xf0, 0xf, 0xf000)) => Some(115),
+            _ => None,
+        }
+    } else if format.flags & DDPF_LUMINANCE != 0 {
+        match (format.rgb_bit_count, masks) {
+            (8, (0xff, 0, 0, 0)) => Some(61),
+            (16, (0xffff, 0, 0, 0)) => Some(56),
+            (16, (0xff, 0, 0, 0xff00)) => Some(49),
+            _ => None,
+        }
+    } else if format.flags & DDPF_ALPHA != 0 && format.rgb_bit_count == 8 {
+        Some(65)
+    } else {
+        None
+    }
+}
+
+/// Returns the width and height of a block of the DXGI format in pixels and
+/// its size in bytes.
+fn dxgi_block(format: u32) -> Option<(u32, u32, usize)> {
+    let block = match format {
+        70..=72 | 79..=81 => (4, 4, 8),
+        73..=78 | 82..=84 | 94..=99 => (4, 4, 16),
+        68 | 69 => (2, 1, 4),
+        1..=4 => (1, 1, 16),
+        5..=8 => (1, 1, 12),
+        9..=22 => (1, 1, 8),
+        23..=47 | 67 | 87..=93 => (1, 1, 4),
+        48..=59 | 85 | 86 | 115 => (1, 1, 2),
+        60..=65 => (1, 1, 1),
+        _ => return None,
+    };
+    Some(block)
+}
+
+/// Parses a DDS file.
+pub fn parse(data: &[u8]) -> Result<Dds<'_>, Error> {
+    if data.len() < MAGIC.len() || &data[..MAGIC.len()] != MAGIC {
+        return Err(Error::Magic);
+    }
+    let mut offset = MAGIC.len() + HEADER_SIZE as usize;
+    if data.len() < offset {
+        return Err(Error::UnexpectedEnd);
+    }
+    if read_u32(data, 4) != HEADER_SIZE || read_u32(data, 76) != PIXEL_FORMAT_SIZE {
+        return Err(Error::Invalid);
+    }
+    let pixel_format = PixelFormat {
+        flags: read_u32(data, 80),
+        four_cc: [data[84], data[85], data[86], data[87]],
+        rgb_bit_count: read_u32(data, 88),
+        red_mask: read_u32(data, 92),
+        green_mask: read_u32(data, 96),
+        blue_mask: read_u32(data, 100),
+        alpha_mask: read_u32(data, 104),
+    };
+    let header = Header {
+        flags: read_u32(data, 8),
+        height: read_u32(data, 12),
+        width: read_u32(data, 16),
+        pitch_or_linear_size: read_u32(data, 20),
+        depth: read_u32(data, 24),
+        mip_map_count: read_u32(data, 28),
+        pixel_format,
+        caps: read_u32(data, 108),
+        caps2: read_u32(data, 112),
+    };
+
+    let dx10_header = if pixel_format.flags & DDPF_FOURCC != 0 && &pixel_format.four_cc == b"DX10" {
+        let dx10 = data
+            .get(offset..offset + DX10_HEADER_SIZE)
+            .ok_or(Error::UnexpectedEnd)?;
+        offset += DX10_HEADER_SIZE;
+        Some(Dx10Header {
+            dxgi_format: read_u32(dx10, 0),
+            resource_dimension: read_u32(dx10, 4),
+            misc_flag: read_u32(dx10, 8),
+            array_size: read_u32(dx10, 12),
+            misc_flags2: read_u32(dx10, 16),
+        })
+    } else {
+        None
+    };
+
+    let (dxgi_format, array_size, face_count) = match dx10_header {
+        Some(dx10) => {
+            let cube = dx10.misc_flag & D3D10_RESOURCE_MISC_TEXTURECUBE != 0;
+            (
+                Some(dx10.dxgi_format),
+                dx10.array_size.max(1),
+                if cube { 6 } else { 1 },
+            )
+        }
+        None => {
+            let faces = if header.caps2 & DDSCAPS2_CUBEMAP != 0 {
+                DDSCAPS2_CUBEMAP_FACES
+                    .iter()
+                    .filter(|&&face| header.caps2 & face != 0)
+                    .count() as u32
+            } else {
+                1
+            };
+            (legacy_dxgi_format(&pixel_format), 1, faces)
+        }
+    };
+    if header.width == 0 || header.height == 0 || face_count == 0 {
+        return Err(Error::Invalid);
+    }
+
+    let mut dds = Dds {
+        data,
+        header,
+        dx10_header,
+        dxgi_format,
+        array_size,
+        face_count,
+        levels: None,
+    };
+
+    // Uncompressed legacy formats without a DXGI equivalent are sized by
+    // their bit count.
+    let block = match dxgi_format {
+        Some(format) => dxgi_block(format),
+        None if pixel_format.flags & DDPF_FOURCC == 0 && pixel_format.rgb_bit_count % 8 == 0 => {
+            Some((1, 1, pixel_format.rgb_bit_count as usize / 8)).filter(|&(_, _, size)| size > 0)
+        }
+        None => None,
+    };
+    if let Some((block_width, block_height, block_size)) = block {
+        let mut levels = Vec::new();
+        for layer in 0..dds.array_size {
+            for face in 0..dds.face_count {
+                let (mut width, mut height, mut depth) = (dds.width(), dds.height(), dds.depth());
+                for level in 0..dds.mip_count() {
+                    let blocks_wide = div_ceil(width, block_width);
+                    let blocks_high = div_ceil(height, block_height);
+                    let length = (blocks_wide as usize)
+                        .checked_mul(blocks_high as usize)
+                        .and_then(|blocks| blocks.checked_mul(depth as usize))
+                        .and_then(|blocks| blocks.checked_mul(block_size))
+                        .ok_or(Error::Invalid)?;
+                    if data.len() - offset < length {
+                        return Err(Error::UnexpectedEnd);
+                    }
+                    levels.push(Level {
+                        layer,
+                        face,
+                        level,
+                        width,
+                        height,
+                        depth,
+                        offset,
+                        length,
+                    });
+                    offset += length;
+                    width = (width / 2).max(1);
+                    height = (height / 2).max(1);
+                    depth = (depth / 2).max(1);
+                }
+            }
+        }
+        dds.levels = Some(levels);
+    }
+    Ok(dds)
+}
+
+/// Divides and rounds up without overflowing.
+// `u32::div_ceil` requires a newer compiler than the minimum supported one.
+fn div_ceil(value: u32, divisor: u32) -> u32 {
+    value / divisor + (value % divisor != 0) as u32
+}
+
+/// A texture whose source is a DDS image.
+#[derive(Clone, Debug)]
+pub struct TextureDds<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::texture::TextureDds,
+}
+
+impl<'a> TextureDds<'a> {
+    /// Constructs a `TextureDds`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::texture::TextureDds,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the DDS image used by the texture.
+    ///
+    /// Imported DDS images are kept encoded and can be read with [`parse`].
+    pub fn source(&self) -> image::Image<'a> {
+        self.document
+            .images()
+            .nth(self.json.source.value())
+            .unwrap()
+    }
+}
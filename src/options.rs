@@ -0,0 +1,203 @@
+#[cfg(feature = "extensions")]
+use crate::extension::Registry;
+use crate::{Document, Error, Result};
+use json::validation::Error as ValidationError;
+use json::Path;
+
+/// Texture extensions accepted as required when textures without a source are
+/// allowed, since processing is then delegated to the user.
+const EMPTY_TEXTURE_EXTENSIONS: &[&str] = &["KHR_texture_basisu", "MSFT_texture_dds"];
+
+/// Specifies how thoroughly a document is validated when loaded.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Validation {
+    /// Reject the document if any validation check fails.
+    Complete,
+
+    /// Do not validate the document.
+    ///
+    /// Accessing invalid data through the wrapper types may panic.
+    Skip,
+}
+
+/// Options for loading glTF documents, selected at runtime.
+///
+/// The default options reproduce the behaviour of [`Gltf::from_slice`] and
+/// [`import`], as configured by the enabled cargo features.
+///
+/// ```
+/// # fn run() -> gltf::Result<()> {
+/// let options = gltf::LoadOptions {
+///     required_extensions: vec!["KHR_materials_unlit".to_string()],
+///     allow_empty_texture: true,
+///     load_images: false,
+///     ..Default::default()
+/// };
+/// # #[cfg(feature = "import")]
+/// let (document, buffers, images) = gltf::import_with_options("examples/Box.gltf", &options)?;
+/// # #[cfg(feature = "import")]
+/// assert!(images.is_empty());
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+///
+/// [`Gltf::from_slice`]: crate::Gltf::from_slice
+/// [`import`]: crate::import
+#[derive(Clone, Debug)]
+pub struct LoadOptions {
+    /// Names of the extensions accepted in `extensionsRequired`.
+    ///
+    /// Defaults to the [enabled extensions](json::extensions::ENABLED_EXTENSIONS).
+    /// Accepting an extension this crate does not support leaves its
+    /// processing to the user.
+    pub required_extensions: Vec<String>,
+
+    /// Extensions defined by the application.
+    ///
+    /// Registered extensions are validated and accepted in
+    /// `extensionsRequired`.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub registry: Registry,
+
+    /// How thoroughly the document is validated.
+    pub validation: Validation,
+
+    /// Accept textures without an image source.
+    ///
+    /// `KHR_texture_basisu` and `MSFT_texture_dds` are then accepted in
+    /// `extensionsRequired` as well. Always enabled by the
+    /// `allow_empty_texture` feature.
+    ///
+    /// Note that [`Texture::source`](crate::Texture::source) panics for such
+    /// textures unless the feature is enabled; use
+    /// [`Texture::try_source`](crate::Texture::try_source) instead.
+    pub allow_empty_texture: bool,
+
+    /// Accept animation channel targets without a node.
+    ///
+    /// Always enabled by the `allow_empty_animation_target_node` feature.
+    ///
+    /// Note that [`Target::node`](crate::animation::Target::node) panics for
+    /// such targets; use
+    /// [`Target::try_node`](crate::animation::Target::try_node) instead.
+    pub allow_empty_animation_target_node: bool,
+
+    /// Load the buffer data when importing.
+    ///
+    /// Meshes compressed with `KHR_draco_mesh_compression` are decompressed
+    /// from the buffers regardless, which are then read but not returned.
+    pub load_buffers: bool,
+
+    /// Decode the images when importing.
+    ///
+    /// Images stored in buffer views are read from the buffers, which are then
+    /// loaded even if `load_buffers` is `false`.
+    pub load_images: bool,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self {
+            required_extensions: json::extensions::ENABLED_EXTENSIONS
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            #[cfg(feature = "extensions")]
+            registry: Registry::default(),
+            validation: Validation::Complete,
+            allow_empty_texture: cfg!(feature = "allow_empty_texture"),
+            allow_empty_animation_target_node: cfg!(feature = "allow_empty_animation_target_node"),
+            load_buffers: true,
+            load_images: true,
+        }
+    }
+}
+
+impl LoadOptions {
+    /// Returns `true` if the named extension is accepted in
+    /// `extensionsRequired`.
+    pub fn accepts(&self, name: &str) -> bool {
+        if self
+            .required_extensions
+            .iter()
+            .any(|accepted| accepted == name)
+        {
+            return true;
+        }
+        if self.allow_empty_texture && EMPTY_TEXTURE_EXTENSIONS.contains(&name) {
+            return true;
+        }
+        #[cfg(feature = "extensions")]
+        if self.registry.contains(name) {
+            return true;
+        }
+        false
+    }
+
+    /// Returns the paths of the omitted values that are allowed by the
+    /// relaxations of these options.
+    fn relaxed(&self, root: &json::Root) -> Vec<Path> {
+        let empty = u32::MAX as usize;
+        let mut paths = Vec::new();
+        if self.allow_empty_texture {
+            for (index, texture) in root.textures.iter().enumerate() {
+                if texture.source.value() == empty {
+                    paths.push(Path::new().field("textures").index(index).field("source"));
+                }
+            }
+        }
+        if self.allow_empty_animation_target_node {
+            for (index, animation) in root.animations.iter().enumerate() {
+                for (channel_index, channel) in animation.channels.iter().enumerate() {
+                    if channel.target.node.value() == empty {
+                        let path = Path::new()
+                            .field("animations")
+                            .index(index)
+                            .field("channels")
+                            .index(channel_index)
+                            .field("target")
+                            .field("node");
+                        paths.push(path);
+                    }
+                }
+            }
+        }
+        paths
+    }
+
+    /// Validates a document according to these options.
+    pub fn validate(&self, document: &Document) -> Result<()> {
+        if self.validation == Validation::Skip {
+            return Ok(());
+        }
+        let errors = self.errors(document.as_json());
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Validation(errors))
+        }
+    }
+
+    /// Returns every validation error of `root`, regardless of
+    /// [`validation`](Self::validation).
+    pub(crate) fn errors(&self, root: &json::Root) -> Vec<(Path, ValidationError)> {
+        use json::validation::Validate;
+
+        let relaxed = self.relaxed(root);
+        let mut errors = Vec::new();
+        let mut report = |path: &dyn Fn() -> Path, error| errors.push((path(), error));
+        root.validate(root, Path::new, &mut |path, error| match error {
+            // Required extensions are checked below against the accepted
+            // extensions.
+            ValidationError::Unsupported => {}
+            ValidationError::Missing if relaxed.contains(&path()) => {}
+            _ => report(path, error),
+        });
+        root.validate_extensions_required(|name| self.accepts(name), Path::new, &mut report);
+        #[cfg(feature = "extensions")]
+        self.registry.validate_extensions(root, &mut report);
+        errors
+    }
+}
@@ -161,6 +161,25 @@ impl<'a> Node<'a> {
         ext.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
+    /// Returns the metadata packet of this node.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        let extensions = self.json.extensions.as_ref()?;
+        crate::khr_xmp_json_ld::Packet::resolve(self.document, extensions.khr_xmp_json_ld.as_ref())
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -182,6 +201,19 @@ impl<'a> Node<'a> {
         }
     }
 
+    /// Returns the per-instance attributes of the mesh of this node as
+    /// defined by the `EXT_mesh_gpu_instancing` extension.
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_gpu_instancing")))]
+    pub fn instancing(&self) -> Option<crate::ext_mesh_gpu_instancing::Instancing<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .ext_mesh_gpu_instancing
+            .as_ref()
+            .map(|json| crate::ext_mesh_gpu_instancing::Instancing::new(self.document, json))
+    }
+
     /// Returns the mesh referenced by this node.
     pub fn mesh(&self) -> Option<Mesh<'a>> {
         self.json
@@ -261,6 +293,35 @@ impl<'a> Scene<'a> {
         ext.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
+    /// Returns the image-based light of the scene as defined by the
+    /// `EXT_lights_image_based` extension.
+    #[cfg(feature = "EXT_lights_image_based")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_lights_image_based")))]
+    pub fn image_based_light(&self) -> Option<crate::ext_lights_image_based::Light<'a>> {
+        let extensions = self.json.extensions.as_ref()?;
+        let index = extensions.ext_lights_image_based.as_ref()?.light.value();
+        Some(self.document.image_based_lights()?.nth(index).unwrap())
+    }
+
+    /// Returns the metadata packet of this scene.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        let extensions = self.json.extensions.as_ref()?;
+        crate::khr_xmp_json_ld::Packet::resolve(self.document, extensions.khr_xmp_json_ld.as_ref())
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
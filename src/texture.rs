@@ -117,6 +117,17 @@ impl<'a> Sampler<'a> {
         ext.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
@@ -169,6 +180,14 @@ impl<'a> Texture<'a> {
     }
 
     /// Returns the image used by this texture.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the texture has no image source, which validation only
+    /// accepts when [`LoadOptions::allow_empty_texture`] is set; use
+    /// [`try_source`](Self::try_source) for such documents.
+    ///
+    /// [`LoadOptions::allow_empty_texture`]: crate::LoadOptions::allow_empty_texture
     #[cfg(not(feature = "allow_empty_texture"))]
     pub fn source(&self) -> image::Image<'a> {
         self.document
@@ -177,6 +196,46 @@ impl<'a> Texture<'a> {
             .unwrap()
     }
 
+    /// Returns the image used by this texture, or `None` if the texture has
+    /// no image source.
+    ///
+    /// Unlike [`source`](Self::source), this never panics, regardless of the
+    /// `allow_empty_texture` feature.
+    pub fn try_source(&self) -> Option<image::Image<'a>> {
+        self.document
+            .images()
+            .nth(self.json.primary_source().value())
+    }
+
+    /// Returns the KTX2 image source of the `KHR_texture_basisu` extension.
+    ///
+    /// When present, [`source`](Self::source) resolves to the same image.
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_basisu")))]
+    pub fn texture_basisu(&self) -> Option<crate::khr_texture_basisu::TextureBasisu<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .texture_basisu
+            .as_ref()
+            .map(|json| crate::khr_texture_basisu::TextureBasisu::new(self.document, json))
+    }
+
+    /// Returns the DDS image source of the `MSFT_texture_dds` extension.
+    ///
+    /// When present, [`source`](Self::source) resolves to the same image
+    /// unless the texture also has a KTX2 source.
+    #[cfg(feature = "MSFT_texture_dds")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "MSFT_texture_dds")))]
+    pub fn texture_dds(&self) -> Option<crate::msft_texture_dds::TextureDds<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .texture_dds
+            .as_ref()
+            .map(|json| crate::msft_texture_dds::TextureDds::new(self.document, json))
+    }
+
     /// Returns extension data unknown to this crate version.
     #[cfg(feature = "extensions")]
     #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
@@ -193,6 +252,17 @@ impl<'a> Texture<'a> {
         ext.others.get(ext_name)
     }
 
+    /// Deserializes the data of an extension defined by the application.
+    ///
+    /// Returns `None` if the extension is absent or its data is malformed.
+    /// See the [`extension`](crate::extension) module for validating such
+    /// data.
+    #[cfg(feature = "extensions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+    pub fn extension<T: crate::extension::Extension>(&self) -> Option<T> {
+        crate::extension::deserialize(self.extension_value(T::NAME)?)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
@@ -0,0 +1,692 @@
+use std::ops::Range;
+
+use super::{Code, Issues};
+use byteorder::{ByteOrder, LE};
+use json::accessor::{ComponentType, GenericComponentType, IndexComponentType, Type};
+use json::animation::{Interpolation, Property};
+use json::mesh::Semantic;
+use json::validation::Checked;
+use json::{Path, Value};
+
+/// The maximum deviation from unit length of normals, tangents and rotations.
+const UNIT_LENGTH_THRESHOLD: f64 = 0.0005;
+
+/// The maximum deviation from one of the sum of the joint weights of a vertex.
+const WEIGHTS_THRESHOLD: f64 = 0.0005;
+
+/// The maximum number of components read from a sparse accessor without a
+/// buffer view, whose size is otherwise only bounded by its declared count.
+const MAX_SPARSE_COMPONENTS: usize = 1 << 22;
+
+/// The binary data of a document.
+pub(super) struct Data<'a> {
+    /// The JSON of the document.
+    pub(super) root: &'a json::Root,
+
+    /// The data of each buffer, if available.
+    pub(super) buffers: Vec<Option<&'a [u8]>>,
+}
+
+/// The memory layout of the elements of an accessor.
+#[derive(Clone, Copy)]
+struct Layout {
+    component_type: ComponentType,
+    columns: usize,
+    rows: usize,
+    column_stride: usize,
+}
+
+impl Layout {
+    fn new(accessor: &json::Accessor) -> Option<Self> {
+        let component_type = match accessor.component_type {
+            Checked::Valid(GenericComponentType(component_type)) => component_type,
+            Checked::Invalid => return None,
+        };
+        let (columns, rows) = match accessor.type_ {
+            Checked::Valid(Type::Mat2) => (2, 2),
+            Checked::Valid(Type::Mat3) => (3, 3),
+            Checked::Valid(Type::Mat4) => (4, 4),
+            Checked::Valid(type_) => (1, type_.multiplicity()),
+            Checked::Invalid => return None,
+        };
+        // Matrix columns start on 4-byte boundaries.
+        let column_size = rows * component_type.size();
+        let column_stride = if columns > 1 {
+            (column_size + 3) / 4 * 4
+        } else {
+            column_size
+        };
+        Some(Self {
+            component_type,
+            columns,
+            rows,
+            column_stride,
+        })
+    }
+
+    /// The number of components of an element.
+    fn components(self) -> usize {
+        self.columns * self.rows
+    }
+
+    /// The size of an element in bytes, including padding.
+    fn size(self) -> usize {
+        self.columns * self.column_stride
+    }
+
+    /// Appends the components of the element stored in `bytes`.
+    fn read(self, bytes: &[u8], values: &mut Vec<f64>) {
+        let component_size = self.component_type.size();
+        for column in 0..self.columns {
+            for row in 0..self.rows {
+                let offset = column * self.column_stride + row * component_size;
+                values.push(read_component(self.component_type, &bytes[offset..]));
+            }
+        }
+    }
+}
+
+impl<'a> Data<'a> {
+    /// Returns the bytes of a buffer view.
+    fn view(&self, index: usize) -> Option<&'a [u8]> {
+        let view = self.root.buffer_views.get(index)?;
+        let buffer = (*self.buffers.get(view.buffer.value())?)?;
+        let start = view.byte_offset.map_or(0, |offset| offset.0) as usize;
+        let end = start.checked_add(view.byte_length.0 as usize)?;
+        buffer.get(start..end)
+    }
+
+    /// Reads the components of every element of an accessor, with the sparse
+    /// substitutions applied.
+    ///
+    /// Returns `None` if the accessor does not fit the buffer data, or if it
+    /// is a sparse accessor without a buffer view of more than
+    /// [`MAX_SPARSE_COMPONENTS`] components.
+    fn read(&self, accessor: &json::Accessor) -> Option<Vec<f64>> {
+        let layout = Layout::new(accessor)?;
+        let count = accessor.count.0 as usize;
+        let mut values = match accessor.buffer_view {
+            Some(view) => {
+                let bytes = self.view(view.value())?;
+                let stride = self.root.buffer_views[view.value()]
+                    .byte_stride
+                    .map_or(layout.size(), |stride| stride.0);
+                // Overlapping elements would not bound the count by the data.
+                if stride < layout.size() {
+                    return None;
+                }
+                let offset = accessor.byte_offset.map_or(0, |offset| offset.0) as usize;
+                if count > 0 {
+                    bytes.get(element(offset, count - 1, stride, layout.size())?)?;
+                }
+                let mut values = Vec::with_capacity(count.checked_mul(layout.components())?);
+                for index in 0..count {
+                    let range = element(offset, index, stride, layout.size())?;
+                    layout.read(&bytes[range], &mut values);
+                }
+                values
+            }
+            None => {
+                let len = count.checked_mul(layout.components())?;
+                if len > MAX_SPARSE_COMPONENTS {
+                    return None;
+                }
+                vec![0.0; len]
+            }
+        };
+
+        if let Some(sparse) = accessor.sparse.as_ref() {
+            let index_type = match sparse.indices.component_type {
+                Checked::Valid(IndexComponentType(index_type)) => index_type,
+                Checked::Invalid => return None,
+            };
+            let indices = self.view(sparse.indices.buffer_view.value())?;
+            let substitutes = self.view(sparse.values.buffer_view.value())?;
+            let indices_offset = sparse.indices.byte_offset.0 as usize;
+            let values_offset = sparse.values.byte_offset.0 as usize;
+            let components = layout.components();
+            let mut substitute = Vec::with_capacity(components);
+            for position in 0..sparse.count.0 as usize {
+                let range = element(
+                    indices_offset,
+                    position,
+                    index_type.size(),
+                    index_type.size(),
+                )?;
+                let index = read_component(index_type, indices.get(range)?) as usize;
+                let range = element(values_offset, position, layout.size(), layout.size())?;
+                substitute.clear();
+                layout.read(substitutes.get(range)?, &mut substitute);
+                let start = index.checked_mul(components)?;
+                values
+                    .get_mut(start..start.checked_add(components)?)?
+                    .copy_from_slice(&substitute);
+            }
+        }
+
+        Some(values)
+    }
+}
+
+/// Returns the byte range of the element at `index`.
+fn element(offset: usize, index: usize, stride: usize, size: usize) -> Option<Range<usize>> {
+    let start = index.checked_mul(stride)?.checked_add(offset)?;
+    Some(start..start.checked_add(size)?)
+}
+
+/// Returns the number of bytes spanned by `count` elements, starting at
+/// `offset`.
+fn span(offset: u64, count: u64, stride: u64, size: u64) -> Option<u64> {
+    match count.checked_sub(1) {
+        Some(last) => last
+            .checked_mul(stride)?
+            .checked_add(offset)?
+            .checked_add(size),
+        None => Some(offset),
+    }
+}
+
+fn read_component(component_type: ComponentType, bytes: &[u8]) -> f64 {
+    match component_type {
+        ComponentType::I8 => f64::from(bytes[0] as i8),
+        ComponentType::U8 => f64::from(bytes[0]),
+        ComponentType::I16 => f64::from(LE::read_i16(bytes)),
+        ComponentType::U16 => f64::from(LE::read_u16(bytes)),
+        ComponentType::U32 => f64::from(LE::read_u32(bytes)),
+        ComponentType::F32 => f64::from(LE::read_f32(bytes)),
+    }
+}
+
+/// Converts a normalized integer component to its floating point value.
+fn denormalize(component_type: ComponentType, value: f64) -> f64 {
+    match component_type {
+        ComponentType::I8 => (value / 127.0).max(-1.0),
+        ComponentType::U8 => value / 255.0,
+        ComponentType::I16 => (value / 32767.0).max(-1.0),
+        ComponentType::U16 => value / 65535.0,
+        ComponentType::U32 | ComponentType::F32 => value,
+    }
+}
+
+fn is_float(accessor: &json::Accessor) -> bool {
+    matches!(
+        accessor.component_type,
+        Checked::Valid(GenericComponentType(ComponentType::F32))
+    )
+}
+
+fn length(vector: &[f64]) -> f64 {
+    vector
+        .iter()
+        .map(|component| component * component)
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Returns the index and length of the first selected element whose leading
+/// `dimensions` components are not of unit length.
+///
+/// Elements that are not finite are skipped since they are reported
+/// separately.
+fn first_non_unit<S>(
+    values: &[f64],
+    components: usize,
+    dimensions: usize,
+    select: S,
+) -> Option<(usize, f64)>
+where
+    S: Fn(usize) -> bool,
+{
+    values
+        .chunks(components)
+        .enumerate()
+        .filter(|(index, element)| select(*index) && element.iter().all(|value| value.is_finite()))
+        .map(|(index, element)| (index, length(&element[..dimensions])))
+        .find(|(_, length)| (length - 1.0).abs() > UNIT_LENGTH_THRESHOLD)
+}
+
+/// Checks that buffer views and accessors fit their buffers and are
+/// correctly aligned.
+pub(super) fn check_layout(root: &json::Root, issues: &mut Issues) {
+    for (index, view) in root.buffer_views.iter().enumerate() {
+        let path = || Path::new().field("bufferViews").index(index);
+        let end = view
+            .byte_offset
+            .map_or(0, |offset| offset.0)
+            .checked_add(view.byte_length.0);
+        if let Some(buffer) = root.buffers.get(view.buffer.value()) {
+            if end.map_or(true, |end| end > buffer.byte_length.0) {
+                issues.push(
+                    Code::BufferViewTooLong,
+                    format!(
+                        "BufferView does not fit buffer ({}) byteLength ({}).",
+                        view.buffer.value(),
+                        buffer.byte_length.0
+                    ),
+                    path().field("byteLength"),
+                );
+            }
+        }
+        if let Some(stride) = view.byte_stride {
+            if stride.0 % 4 != 0 {
+                issues.push(
+                    Code::BufferViewByteStrideAlignment,
+                    format!("byteStride {} is not a multiple of 4.", stride.0),
+                    path().field("byteStride"),
+                );
+            }
+        }
+    }
+
+    for (index, accessor) in root.accessors.iter().enumerate() {
+        let layout = match Layout::new(accessor) {
+            Some(layout) => layout,
+            None => continue,
+        };
+        let path = || Path::new().field("accessors").index(index);
+        let component_size = layout.component_type.size() as u64;
+        let size = layout.size() as u64;
+
+        if let Some(view) = accessor
+            .buffer_view
+            .and_then(|view| root.buffer_views.get(view.value()).map(|json| (view, json)))
+        {
+            let (view_index, view) = view;
+            let offset = accessor.byte_offset.map_or(0, |offset| offset.0);
+            let total_offset = view
+                .byte_offset
+                .map_or(0, |offset| offset.0)
+                .wrapping_add(offset);
+            if offset % component_size != 0 {
+                issues.push(
+                    Code::AccessorOffsetAlignment,
+                    format!(
+                        "Offset {} is not a multiple of componentType length {}.",
+                        offset, component_size
+                    ),
+                    path().field("byteOffset"),
+                );
+            } else if total_offset % component_size != 0 {
+                issues.push(
+                    Code::AccessorTotalOffsetAlignment,
+                    format!(
+                        "Accessor's total byteOffset {} isn't a multiple of componentType length {}.",
+                        total_offset, component_size
+                    ),
+                    path().field("byteOffset"),
+                );
+            }
+
+            let stride = match view.byte_stride {
+                Some(stride) => {
+                    if (stride.0 as u64) < size {
+                        issues.push(
+                            Code::AccessorSmallByteStride,
+                            format!(
+                                "Referenced bufferView's byteStride value {} is less than accessor element's length {}.",
+                                stride.0, size
+                            ),
+                            path().field("bufferView"),
+                        );
+                    }
+                    stride.0 as u64
+                }
+                None => size,
+            };
+
+            let length = span(offset, accessor.count.0, stride, size);
+            if length.map_or(true, |length| length > view.byte_length.0) {
+                issues.push(
+                    Code::AccessorTooLong,
+                    format!(
+                        "Accessor (offset: {}, count: {}) does not fit referenced bufferView [{}] length {}.",
+                        offset,
+                        accessor.count.0,
+                        view_index.value(),
+                        view.byte_length.0
+                    ),
+                    path(),
+                );
+            }
+        }
+
+        if let Some(sparse) = accessor.sparse.as_ref() {
+            let index_size = match sparse.indices.component_type {
+                Checked::Valid(IndexComponentType(index_type)) => index_type.size() as u64,
+                Checked::Invalid => continue,
+            };
+            let parts = [
+                (
+                    "indices",
+                    sparse.indices.buffer_view.value(),
+                    sparse.indices.byte_offset.0,
+                    index_size,
+                ),
+                (
+                    "values",
+                    sparse.values.buffer_view.value(),
+                    sparse.values.byte_offset.0,
+                    size,
+                ),
+            ];
+            for (field, view_index, offset, size) in parts {
+                let view = match root.buffer_views.get(view_index) {
+                    Some(view) => view,
+                    None => continue,
+                };
+                let length = span(offset, sparse.count.0, size, size);
+                if length.map_or(true, |length| length > view.byte_length.0) {
+                    issues.push(
+                        Code::AccessorTooLong,
+                        format!(
+                            "Sparse {} (offset: {}, count: {}) do not fit referenced bufferView [{}] length {}.",
+                            field, offset, sparse.count.0, view_index, view.byte_length.0
+                        ),
+                        path().field("sparse").field(field),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks the contents of the buffers.
+///
+/// Assumes the JSON of the document is valid.
+pub(super) fn check_data(data: &Data, issues: &mut Issues) {
+    check_buffers(data, issues);
+    check_accessors(data, issues);
+    check_primitives(data, issues);
+    check_animations(data, issues);
+}
+
+fn check_buffers(data: &Data, issues: &mut Issues) {
+    for (index, buffer) in data.root.buffers.iter().enumerate() {
+        let bytes = match data.buffers.get(index) {
+            Some(Some(bytes)) => bytes,
+            _ => continue,
+        };
+        // Binary chunks may be padded, so only shorter data is an error.
+        if (bytes.len() as u64) < buffer.byte_length.0 {
+            issues.push(
+                Code::BufferByteLengthMismatch,
+                format!(
+                    "Actual data length {} is less than the declared buffer byteLength {}.",
+                    bytes.len(),
+                    buffer.byte_length.0
+                ),
+                Path::new()
+                    .field("buffers")
+                    .index(index)
+                    .field("byteLength"),
+            );
+        }
+    }
+}
+
+fn check_accessors(data: &Data, issues: &mut Issues) {
+    for (index, accessor) in data.root.accessors.iter().enumerate() {
+        let path = || Path::new().field("accessors").index(index);
+        let (layout, values) = match Layout::new(accessor).zip(data.read(accessor)) {
+            Some(read) => read,
+            None => continue,
+        };
+        let components = layout.components();
+        let float = is_float(accessor);
+
+        if float {
+            if let Some(position) = values.iter().position(|value| !value.is_finite()) {
+                issues.push(
+                    Code::AccessorInvalidFloat,
+                    format!(
+                        "Invalid float value {} at element {}.",
+                        values[position],
+                        position / components
+                    ),
+                    path(),
+                );
+                continue;
+            }
+        }
+        if values.is_empty() {
+            continue;
+        }
+
+        let bounds = [
+            (&accessor.min, "min", "minimum", Code::AccessorMinMismatch),
+            (&accessor.max, "max", "maximum", Code::AccessorMaxMismatch),
+        ];
+        for (bound, field, name, code) in bounds {
+            let declared = match bound.as_ref().and_then(Value::as_array) {
+                Some(declared) => declared,
+                None => continue,
+            };
+            for (component, declared) in declared.iter().enumerate().take(components) {
+                let declared = match declared.as_f64() {
+                    Some(declared) => declared,
+                    None => continue,
+                };
+                let actual = values.iter().skip(component).step_by(components).copied();
+                let actual = if code == Code::AccessorMinMismatch {
+                    actual.fold(f64::INFINITY, f64::min)
+                } else {
+                    actual.fold(f64::NEG_INFINITY, f64::max)
+                };
+                // Float bounds are compared at the precision of the data.
+                let matches = if float {
+                    declared as f32 == actual as f32
+                } else {
+                    declared == actual
+                };
+                if !matches {
+                    issues.push(
+                        code,
+                        format!(
+                            "Declared {} value for this component ({}) does not match actual {} ({}).",
+                            name, declared, name, actual
+                        ),
+                        path().field(field).index(component),
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn check_primitives(data: &Data, issues: &mut Issues) {
+    let root = data.root;
+    for (mesh_index, mesh) in root.meshes.iter().enumerate() {
+        for (primitive_index, primitive) in mesh.primitives.iter().enumerate() {
+            let path = || {
+                Path::new()
+                    .field("meshes")
+                    .index(mesh_index)
+                    .field("primitives")
+                    .index(primitive_index)
+            };
+            let accessor = |index: &json::Index<json::Accessor>| &root.accessors[index.value()];
+
+            let vertices = primitive
+                .attributes
+                .values()
+                .map(|index| accessor(index).count.0)
+                .min();
+            if let Some((indices, vertices)) = primitive.indices.as_ref().zip(vertices) {
+                let values = data.read(accessor(indices)).unwrap_or_default();
+                if let Some((position, value)) = values
+                    .iter()
+                    .enumerate()
+                    .find(|(_, &value)| value >= vertices as f64)
+                {
+                    issues.push(
+                        Code::AccessorIndexOob,
+                        format!(
+                            "Indices accessor element at index {} has value {} that is greater than the maximum vertex index available ({}).",
+                            position,
+                            value,
+                            vertices.saturating_sub(1)
+                        ),
+                        path().field("indices"),
+                    );
+                }
+            }
+
+            for (semantic, index) in &primitive.attributes {
+                let (dimensions, components) = match semantic {
+                    Checked::Valid(Semantic::Normals) => (3, 3),
+                    // The fourth component of tangents is their handedness.
+                    Checked::Valid(Semantic::Tangents) => (3, 4),
+                    _ => continue,
+                };
+                if !is_float(accessor(index)) {
+                    continue;
+                }
+                let values = data.read(accessor(index)).unwrap_or_default();
+                if let Some((position, length)) =
+                    first_non_unit(&values, components, dimensions, |_| true)
+                {
+                    issues.push(
+                        Code::AccessorNonUnit,
+                        format!(
+                            "Element at index {} is not of unit length: {}.",
+                            position, length
+                        ),
+                        path().field("attributes").key(&semantic.to_string()),
+                    );
+                }
+            }
+
+            check_weights(data, primitive, path, issues);
+        }
+    }
+}
+
+/// Checks that the joint weights of each vertex sum to one.
+fn check_weights<P>(data: &Data, primitive: &json::mesh::Primitive, path: P, issues: &mut Issues)
+where
+    P: Fn() -> Path,
+{
+    let mut sets = primitive
+        .attributes
+        .iter()
+        .filter(|(semantic, _)| matches!(semantic, Checked::Valid(Semantic::Weights(_))))
+        .map(|(semantic, index)| (semantic, &data.root.accessors[index.value()]))
+        .peekable();
+    let first = match sets.peek() {
+        Some((semantic, _)) => semantic.to_string(),
+        None => return,
+    };
+
+    let mut sums: Option<Vec<f64>> = None;
+    for (_, accessor) in sets {
+        let (layout, values) = match Layout::new(accessor).zip(data.read(accessor)) {
+            Some(read) => read,
+            None => return,
+        };
+        let components = layout.components();
+        let count = values.len() / components;
+        let sums = sums.get_or_insert_with(|| vec![0.0; count]);
+        if sums.len() != count {
+            return;
+        }
+        for (sum, element) in sums.iter_mut().zip(values.chunks(components)) {
+            *sum += element
+                .iter()
+                .map(|&value| {
+                    if accessor.normalized {
+                        denormalize(layout.component_type, value)
+                    } else {
+                        value
+                    }
+                })
+                .sum::<f64>();
+        }
+    }
+
+    let sums = sums.unwrap_or_default();
+    if let Some((position, sum)) = sums
+        .iter()
+        .enumerate()
+        .find(|(_, sum)| sum.is_finite() && (*sum - 1.0).abs() > WEIGHTS_THRESHOLD)
+    {
+        issues.push(
+            Code::AccessorWeightsNonNormalized,
+            format!(
+                "Weights of the vertex at index {} have non-normalized sum: {}.",
+                position, sum
+            ),
+            path().field("attributes").key(&first),
+        );
+    }
+}
+
+fn check_animations(data: &Data, issues: &mut Issues) {
+    let root = data.root;
+    for (animation_index, animation) in root.animations.iter().enumerate() {
+        let path = |sampler: usize| {
+            Path::new()
+                .field("animations")
+                .index(animation_index)
+                .field("samplers")
+                .index(sampler)
+        };
+
+        for (sampler_index, sampler) in animation.samplers.iter().enumerate() {
+            let times = data
+                .read(&root.accessors[sampler.input.value()])
+                .unwrap_or_default();
+            if let Some(position) = times.iter().position(|&time| time < 0.0) {
+                issues.push(
+                    Code::AccessorAnimationInputNegative,
+                    format!(
+                        "Animation input accessor element at index {} is negative: {}.",
+                        position, times[position]
+                    ),
+                    path(sampler_index).field("input"),
+                );
+            } else if let Some(position) = times.windows(2).position(|pair| pair[1] <= pair[0]) {
+                issues.push(
+                    Code::AccessorAnimationInputNonIncreasing,
+                    format!(
+                        "Animation input accessor element at index {} is less than or equal to previous: {} <= {}.",
+                        position + 1,
+                        times[position + 1],
+                        times[position]
+                    ),
+                    path(sampler_index).field("input"),
+                );
+            }
+        }
+
+        let mut rotations: Vec<usize> = animation
+            .channels
+            .iter()
+            .filter(|channel| channel.target.path == Checked::Valid(Property::Rotation))
+            .map(|channel| channel.sampler.value())
+            .collect();
+        rotations.sort_unstable();
+        rotations.dedup();
+        for sampler_index in rotations {
+            let sampler = &animation.samplers[sampler_index];
+            let output = &root.accessors[sampler.output.value()];
+            if !is_float(output) {
+                continue;
+            }
+            // Cubic spline samplers store each value between its tangents.
+            let cubic = sampler.interpolation == Checked::Valid(Interpolation::CubicSpline);
+            let values = data.read(output).unwrap_or_default();
+            if let Some((position, length)) =
+                first_non_unit(&values, 4, 4, |index| !cubic || index % 3 == 1)
+            {
+                issues.push(
+                    Code::AccessorAnimationSamplerOutputNonNormalizedQuaternion,
+                    format!(
+                        "Animation sampler output accessor element at index {} is not a unit quaternion: {}.",
+                        position, length
+                    ),
+                    path(sampler_index).field("output"),
+                );
+            }
+        }
+    }
+}
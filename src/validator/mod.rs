@@ -0,0 +1,623 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::edit::{prune, Kind};
+use crate::{Buffer, Document, LoadOptions};
+use json::validation::{Checked, Error as ValidationError};
+use json::{Path, Value};
+
+mod data;
+
+/// The severity of a validation issue.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// The asset violates the specification.
+    Error,
+
+    /// The asset is valid but likely to be processed incorrectly.
+    Warning,
+
+    /// Information about the asset that may be of interest.
+    Information,
+
+    /// A suggestion for improving the asset.
+    Hint,
+}
+
+impl Severity {
+    /// Returns the name of the severity, for example `"ERROR"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "ERROR",
+            Severity::Warning => "WARNING",
+            Severity::Information => "INFO",
+            Severity::Hint => "HINT",
+        }
+    }
+
+    /// Returns the numeric severity used in validation reports, from `0` for
+    /// errors to `3` for hints.
+    pub fn level(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Identifies the kind of a validation issue.
+///
+/// The codes follow the naming of the Khronos glTF-Validator and remain
+/// stable across releases.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Code {
+    /// An index refers to an object that does not exist.
+    UnresolvedReference,
+
+    /// A value is not valid.
+    InvalidValue,
+
+    /// A required property is missing.
+    UndefinedProperty,
+
+    /// A size or offset exceeds the limits of the system.
+    ValueNotInRange,
+
+    /// A required extension is not supported.
+    UnsupportedExtension,
+
+    /// A required extension is not listed in `extensionsUsed`.
+    UnusedExtensionRequired,
+
+    /// The minor version of the asset is newer than supported.
+    UnknownAssetMinorVersion,
+
+    /// An object is not reachable from any scene or animation.
+    UnusedObject,
+
+    /// A node has no children, mesh, camera, skin or extensions.
+    NodeEmpty,
+
+    /// A buffer view holding vertex or index data has no target.
+    BufferViewTargetMissing,
+
+    /// The data of a buffer is shorter than its `byteLength`.
+    BufferByteLengthMismatch,
+
+    /// A buffer view does not fit its buffer.
+    BufferViewTooLong,
+
+    /// The `byteStride` of a buffer view is not a multiple of 4.
+    BufferViewByteStrideAlignment,
+
+    /// An accessor does not fit its buffer view.
+    AccessorTooLong,
+
+    /// The `byteStride` of a buffer view is less than the size of the
+    /// elements of an accessor.
+    AccessorSmallByteStride,
+
+    /// The `byteOffset` of an accessor is not a multiple of its component
+    /// size.
+    AccessorOffsetAlignment,
+
+    /// The offset of an accessor in its buffer is not a multiple of its
+    /// component size.
+    AccessorTotalOffsetAlignment,
+
+    /// The `min` of an accessor does not match its contents.
+    AccessorMinMismatch,
+
+    /// The `max` of an accessor does not match its contents.
+    AccessorMaxMismatch,
+
+    /// An accessor contains NaN or infinite values.
+    AccessorInvalidFloat,
+
+    /// A vertex index is not less than the number of vertices.
+    AccessorIndexOob,
+
+    /// A normal or tangent is not of unit length.
+    AccessorNonUnit,
+
+    /// The joint weights of a vertex do not sum to one.
+    AccessorWeightsNonNormalized,
+
+    /// An animation input contains negative times.
+    AccessorAnimationInputNegative,
+
+    /// The times of an animation input are not strictly increasing.
+    AccessorAnimationInputNonIncreasing,
+
+    /// An animated rotation is not a unit quaternion.
+    AccessorAnimationSamplerOutputNonNormalizedQuaternion,
+}
+
+impl Code {
+    /// Returns the name of the code, for example `"UNRESOLVED_REFERENCE"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Code::UnresolvedReference => "UNRESOLVED_REFERENCE",
+            Code::InvalidValue => "INVALID_VALUE",
+            Code::UndefinedProperty => "UNDEFINED_PROPERTY",
+            Code::ValueNotInRange => "VALUE_NOT_IN_RANGE",
+            Code::UnsupportedExtension => "UNSUPPORTED_EXTENSION",
+            Code::UnusedExtensionRequired => "UNUSED_EXTENSION_REQUIRED",
+            Code::UnknownAssetMinorVersion => "UNKNOWN_ASSET_MINOR_VERSION",
+            Code::UnusedObject => "UNUSED_OBJECT",
+            Code::NodeEmpty => "NODE_EMPTY",
+            Code::BufferViewTargetMissing => "BUFFER_VIEW_TARGET_MISSING",
+            Code::BufferByteLengthMismatch => "BUFFER_BYTE_LENGTH_MISMATCH",
+            Code::BufferViewTooLong => "BUFFER_VIEW_TOO_LONG",
+            Code::BufferViewByteStrideAlignment => "BUFFER_VIEW_BYTESTRIDE_ALIGNMENT",
+            Code::AccessorTooLong => "ACCESSOR_TOO_LONG",
+            Code::AccessorSmallByteStride => "ACCESSOR_SMALL_BYTESTRIDE",
+            Code::AccessorOffsetAlignment => "ACCESSOR_OFFSET_ALIGNMENT",
+            Code::AccessorTotalOffsetAlignment => "ACCESSOR_TOTAL_OFFSET_ALIGNMENT",
+            Code::AccessorMinMismatch => "ACCESSOR_MIN_MISMATCH",
+            Code::AccessorMaxMismatch => "ACCESSOR_MAX_MISMATCH",
+            Code::AccessorInvalidFloat => "ACCESSOR_INVALID_FLOAT",
+            Code::AccessorIndexOob => "ACCESSOR_INDEX_OOB",
+            Code::AccessorNonUnit => "ACCESSOR_NON_UNIT",
+            Code::AccessorWeightsNonNormalized => "ACCESSOR_WEIGHTS_NON_NORMALIZED",
+            Code::AccessorAnimationInputNegative => "ACCESSOR_ANIMATION_INPUT_NEGATIVE",
+            Code::AccessorAnimationInputNonIncreasing => "ACCESSOR_ANIMATION_INPUT_NON_INCREASING",
+            Code::AccessorAnimationSamplerOutputNonNormalizedQuaternion => {
+                "ACCESSOR_ANIMATION_SAMPLER_OUTPUT_NON_NORMALIZED_QUATERNION"
+            }
+        }
+    }
+
+    /// Returns the severity issues with this code are reported with, unless
+    /// overridden by the [`Validator`].
+    pub fn severity(self) -> Severity {
+        match self {
+            Code::UnresolvedReference
+            | Code::InvalidValue
+            | Code::UndefinedProperty
+            | Code::ValueNotInRange
+            | Code::UnsupportedExtension
+            | Code::UnusedExtensionRequired => Severity::Error,
+            Code::BufferByteLengthMismatch
+            | Code::BufferViewTooLong
+            | Code::BufferViewByteStrideAlignment
+            | Code::AccessorTooLong
+            | Code::AccessorSmallByteStride
+            | Code::AccessorOffsetAlignment
+            | Code::AccessorTotalOffsetAlignment
+            | Code::AccessorMinMismatch
+            | Code::AccessorMaxMismatch
+            | Code::AccessorInvalidFloat
+            | Code::AccessorIndexOob
+            | Code::AccessorNonUnit
+            | Code::AccessorWeightsNonNormalized
+            | Code::AccessorAnimationInputNegative
+            | Code::AccessorAnimationInputNonIncreasing
+            | Code::AccessorAnimationSamplerOutputNonNormalizedQuaternion => Severity::Error,
+            Code::UnknownAssetMinorVersion => Severity::Warning,
+            Code::UnusedObject => Severity::Information,
+            Code::NodeEmpty | Code::BufferViewTargetMissing => Severity::Hint,
+        }
+    }
+
+    /// Returns the code corresponding to an error of the JSON validation.
+    fn of(error: ValidationError) -> Self {
+        match error {
+            ValidationError::IndexOutOfBounds => Code::UnresolvedReference,
+            ValidationError::Invalid => Code::InvalidValue,
+            ValidationError::Missing => Code::UndefinedProperty,
+            ValidationError::Oversize => Code::ValueNotInRange,
+            ValidationError::Unsupported => Code::UnsupportedExtension,
+        }
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A problem found by the [`Validator`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Issue {
+    /// The kind of the issue.
+    pub code: Code,
+
+    /// The severity of the issue.
+    pub severity: Severity,
+
+    /// A human-readable description of the issue.
+    pub message: String,
+
+    /// The path of the offending value.
+    pub path: Path,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {} ({})",
+            self.severity, self.code, self.message, self.path
+        )
+    }
+}
+
+/// The issues found in a document by the [`Validator`].
+#[derive(Clone, Debug)]
+pub struct Report {
+    /// The issues, in the order they were found.
+    issues: Vec<Issue>,
+
+    /// Whether issues were dropped after reaching the maximum.
+    truncated: bool,
+
+    /// Summary of the document.
+    info: Value,
+}
+
+impl Report {
+    /// Returns the issues, in the order they were found.
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    /// Returns the number of issues with the given severity.
+    pub fn count(&self, severity: Severity) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .count()
+    }
+
+    /// Returns `true` if any issue is an error.
+    pub fn has_errors(&self) -> bool {
+        self.count(Severity::Error) > 0
+    }
+
+    /// Returns `true` if issues were dropped after reaching the maximum set
+    /// by [`Validator::max_issues`].
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Serializes the report in the format of the Khronos glTF-Validator.
+    ///
+    /// Paths are converted to JSON pointers. Fields describing the source of
+    /// the asset, such as `uri` and `mimeType`, are left to the caller.
+    pub fn to_json(&self) -> Value {
+        let messages: Vec<Value> = self
+            .issues
+            .iter()
+            .map(|issue| {
+                serde_json::json!({
+                    "code": issue.code.as_str(),
+                    "message": issue.message,
+                    "severity": issue.severity.level(),
+                    "pointer": issue.path.pointer(),
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "validatorVersion": concat!("gltf ", env!("CARGO_PKG_VERSION")),
+            "issues": {
+                "numErrors": self.count(Severity::Error),
+                "numWarnings": self.count(Severity::Warning),
+                "numInfos": self.count(Severity::Information),
+                "numHints": self.count(Severity::Hint),
+                "messages": messages,
+                "truncated": self.truncated,
+            },
+            "info": self.info,
+        })
+    }
+}
+
+/// Validates documents and reports every issue found.
+///
+/// In addition to the errors that cause loading to fail, the validator
+/// reports warnings, information and hints. The report may be serialized for
+/// gating assets in continuous integration.
+///
+/// ```
+/// use gltf::validator::{Code, Severity, Validator};
+///
+/// # fn run() -> gltf::Result<()> {
+/// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+/// let mut validator = Validator::new();
+/// validator.override_severity(Code::BufferViewTargetMissing, Severity::Warning);
+/// let report = validator.validate(&gltf);
+/// assert!(!report.has_errors());
+/// println!("{}", report.to_json());
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Validator {
+    /// Options of the checks shared with loading.
+    options: LoadOptions,
+
+    /// Severities replacing the defaults of their codes.
+    overrides: HashMap<Code, Severity>,
+
+    /// The maximum number of issues reported.
+    max_issues: Option<usize>,
+}
+
+/// Collects the issues of a document.
+struct Issues<'a> {
+    validator: &'a Validator,
+    issues: Vec<Issue>,
+    truncated: bool,
+}
+
+impl Issues<'_> {
+    fn push(&mut self, code: Code, message: String, path: Path) {
+        if Some(self.issues.len()) == self.validator.max_issues {
+            self.truncated = true;
+            return;
+        }
+        let severity = self
+            .validator
+            .overrides
+            .get(&code)
+            .copied()
+            .unwrap_or_else(|| code.severity());
+        self.issues.push(Issue {
+            code,
+            severity,
+            message,
+            path,
+        });
+    }
+}
+
+impl Validator {
+    /// Creates a validator with the default [`LoadOptions`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a validator whose checks shared with loading follow the given
+    /// options.
+    ///
+    /// [`LoadOptions::validation`] is ignored.
+    pub fn with_options(options: LoadOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Reports issues with the given code at `severity` instead of the
+    /// default of the code.
+    pub fn override_severity(&mut self, code: Code, severity: Severity) -> &mut Self {
+        self.overrides.insert(code, severity);
+        self
+    }
+
+    /// Limits the number of issues reported.
+    pub fn max_issues(&mut self, max: usize) -> &mut Self {
+        self.max_issues = Some(max);
+        self
+    }
+
+    /// Validates a document.
+    ///
+    /// Only the JSON of the document is inspected; see
+    /// [`validate_with_data`](Self::validate_with_data) to also check the
+    /// contents of the buffers.
+    pub fn validate(&self, document: &Document) -> Report {
+        self.run(document, None)
+    }
+
+    /// Validates a document along with the contents of its buffers.
+    ///
+    /// In addition to the checks of [`validate`](Self::validate), this
+    /// verifies that the accessors match their declared `min` and `max`, that
+    /// vertex indices are in range, that floating point data is finite, that
+    /// normals, tangents and animated rotations are of unit length, that the
+    /// joint weights of each vertex sum to one and that animation times are
+    /// strictly increasing. Only normals, tangents and rotations with
+    /// floating point components are checked for unit length.
+    ///
+    /// The contents are only checked if the JSON has no errors. Buffers for
+    /// which `get_buffer_data` returns `None`, and accessors that do not fit
+    /// the given data, are skipped.
+    ///
+    /// ```
+    /// # #[cfg(feature = "import")]
+    /// # fn run() -> gltf::Result<()> {
+    /// use gltf::validator::Validator;
+    ///
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let report = Validator::new()
+    ///     .validate_with_data(&document, |buffer| Some(&buffers[buffer.index()]));
+    /// assert!(!report.has_errors());
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(feature = "import")]
+    /// # run().unwrap();
+    /// ```
+    pub fn validate_with_data<'a, 's, F>(
+        &self,
+        document: &'a Document,
+        get_buffer_data: F,
+    ) -> Report
+    where
+        F: Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let data = data::Data {
+            root: document.as_json(),
+            buffers: document.buffers().map(get_buffer_data).collect(),
+        };
+        self.run(document, Some(data))
+    }
+
+    fn run(&self, document: &Document, data: Option<data::Data>) -> Report {
+        let root = document.as_json();
+        let mut issues = Issues {
+            validator: self,
+            issues: Vec::new(),
+            truncated: false,
+        };
+        let errors = self.options.errors(root);
+        let valid = errors.is_empty();
+        for (path, error) in errors {
+            issues.push(Code::of(error), format!("{}.", error), path);
+        }
+        check_extensions_required(root, &mut issues);
+        check_asset_version(root, &mut issues);
+        check_unused_objects(root, &mut issues);
+        check_empty_nodes(root, &mut issues);
+        check_buffer_view_targets(root, &mut issues);
+        data::check_layout(root, &mut issues);
+        // The contents are only read through valid JSON.
+        if let Some(data) = data.filter(|_| valid) {
+            data::check_data(&data, &mut issues);
+        }
+        Report {
+            issues: issues.issues,
+            truncated: issues.truncated,
+            info: info(root),
+        }
+    }
+}
+
+/// Summarizes the document for the report.
+fn info(root: &json::Root) -> Value {
+    serde_json::json!({
+        "version": root.asset.version,
+        "generator": root.asset.generator,
+        "extensionsUsed": root.extensions_used,
+        "extensionsRequired": root.extensions_required,
+        "animationCount": root.animations.len(),
+        "materialCount": root.materials.len(),
+        "hasSkins": !root.skins.is_empty(),
+        "hasTextures": !root.textures.is_empty(),
+    })
+}
+
+fn check_extensions_required(root: &json::Root, issues: &mut Issues) {
+    for (index, name) in root.extensions_required.iter().enumerate() {
+        if !root.extensions_used.contains(name) {
+            issues.push(
+                Code::UnusedExtensionRequired,
+                format!("Extension `{}` is required but not used.", name),
+                Path::new()
+                    .field("extensionsRequired")
+                    .index(index)
+                    .value_str(name),
+            );
+        }
+    }
+}
+
+fn check_asset_version(root: &json::Root, issues: &mut Issues) {
+    let minor = root
+        .asset
+        .version
+        .strip_prefix("2.")
+        .and_then(|minor| minor.parse::<u32>().ok());
+    if let Some(minor) = minor.filter(|&minor| minor > 0) {
+        issues.push(
+            Code::UnknownAssetMinorVersion,
+            format!("Unknown glTF minor asset version: {}.", minor),
+            Path::new().field("asset").field("version"),
+        );
+    }
+}
+
+fn check_unused_objects(root: &json::Root, issues: &mut Issues) {
+    let keep = prune::reachable(&mut root.clone());
+    for &kind in Kind::ALL {
+        for (index, _) in keep[&kind].iter().enumerate().filter(|(_, &kept)| !kept) {
+            issues.push(
+                Code::UnusedObject,
+                "This object may be unused.".to_string(),
+                kind.path(index),
+            );
+        }
+    }
+}
+
+fn check_empty_nodes(root: &json::Root, issues: &mut Issues) {
+    // Nodes used as joints or animated are not considered empty.
+    let mut used = vec![false; root.nodes.len()];
+    let joints = root.skins.iter().flat_map(|skin| skin.joints.iter());
+    let targets = root
+        .animations
+        .iter()
+        .flat_map(|animation| animation.channels.iter())
+        .map(|channel| &channel.target.node);
+    for node in joints.chain(targets) {
+        if let Some(flag) = used.get_mut(node.value()) {
+            *flag = true;
+        }
+    }
+
+    for (index, node) in root.nodes.iter().enumerate() {
+        let empty = node.children.as_ref().map_or(true, Vec::is_empty)
+            && node.mesh.is_none()
+            && node.camera.is_none()
+            && node.skin.is_none()
+            && node.extensions.is_none();
+        if empty && !used[index] {
+            issues.push(
+                Code::NodeEmpty,
+                "Empty node encountered.".to_string(),
+                Path::new().field("nodes").index(index),
+            );
+        }
+    }
+}
+
+fn check_buffer_view_targets(root: &json::Root, issues: &mut Issues) {
+    let mut vertex_or_index = vec![false; root.buffer_views.len()];
+    for mesh in &root.meshes {
+        for primitive in &mesh.primitives {
+            let targets = primitive.targets.iter().flatten().flat_map(|target| {
+                let json::mesh::MorphTarget {
+                    positions,
+                    normals,
+                    tangents,
+                } = target;
+                [positions, normals, tangents].into_iter().flatten()
+            });
+            let accessors = primitive
+                .attributes
+                .values()
+                .chain(primitive.indices.iter())
+                .chain(targets);
+            for accessor in accessors {
+                let view = root
+                    .accessors
+                    .get(accessor.value())
+                    .and_then(|accessor| accessor.buffer_view);
+                if let Some(flag) = view.and_then(|view| vertex_or_index.get_mut(view.value())) {
+                    *flag = true;
+                }
+            }
+        }
+    }
+
+    for (index, view) in root.buffer_views.iter().enumerate() {
+        let missing = !matches!(view.target, Some(Checked::Valid(_)));
+        if vertex_or_index[index] && missing {
+            issues.push(
+                Code::BufferViewTargetMissing,
+                "bufferView.target should be set for vertex or index data.".to_string(),
+                Path::new()
+                    .field("bufferViews")
+                    .index(index)
+                    .field("target"),
+            );
+        }
+    }
+}
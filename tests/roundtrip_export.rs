@@ -0,0 +1,284 @@
+//! Export roundtrip test.
+//!
+//! Import some glTF, export it as standard and binary glTF, import the result
+//! and compare it to the original.
+
+use std::{env, fs, path};
+
+/// A 2x1 RGBA image containing a red and a green pixel.
+const IMAGE_GLTF: &str = r#"{
+    "asset": { "version": "2.0" },
+    "images": [
+        {
+            "uri": "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAIAAAABCAYAAAD0In+KAAAADklEQVR4nGP4z8DwHwQBEPgD/U6VwW8AAAAASUVORK5CYII="
+        }
+    ]
+}"#;
+
+fn output_directory(name: &str) -> path::PathBuf {
+    let dir = env::temp_dir().join("gltf-roundtrip-export").join(name);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn positions(document: &gltf::Document, buffers: &[gltf::buffer::Data]) -> Vec<[f32; 3]> {
+    document
+        .meshes()
+        .flat_map(|mesh| mesh.primitives())
+        .flat_map(|primitive| {
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+            reader.read_positions().unwrap().collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn roundtrip(file_name: &str) {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let path = output_directory("box").join(file_name);
+    gltf::export(
+        &path,
+        &document,
+        &buffers,
+        Some(path::Path::new("examples")),
+    )
+    .unwrap();
+
+    let (output, output_buffers, _) = gltf::import(&path).unwrap();
+    assert_eq!(output.buffers().count(), 1);
+    assert_eq!(output.accessors().count(), document.accessors().count());
+    assert_eq!(
+        positions(&output, &output_buffers),
+        positions(&document, &buffers)
+    );
+}
+
+#[test]
+fn test_export_standard() {
+    roundtrip("Box.gltf");
+    let dir = output_directory("box");
+    assert_eq!(fs::read(dir.join("Box_buffer0.bin")).unwrap().len(), 648);
+}
+
+#[test]
+fn test_export_binary() {
+    roundtrip("Box.glb");
+}
+
+#[test]
+fn test_export_images() {
+    let (document, buffers, images) = gltf::import_slice(IMAGE_GLTF.as_bytes()).unwrap();
+    let dir = output_directory("image");
+
+    for file_name in ["Image.gltf", "Image.glb"] {
+        let path = dir.join(file_name);
+        gltf::export(&path, &document, &buffers, None).unwrap();
+        let (output, _, output_images) = gltf::import(&path).unwrap();
+        let mime_type = output.as_json().images[0].mime_type.as_ref();
+        assert_eq!(mime_type.map(|x| x.0.as_str()), Some("image/png"));
+        assert_eq!(output_images[0].pixels, images[0].pixels);
+    }
+}
+
+#[test]
+fn test_export_images_without_decoding() {
+    use gltf::ResourceResolver;
+
+    // The image data is not a valid JPEG, so it must be copied as it is.
+    let uri = "data:image/jpeg;base64,AAECAwQFBgc=";
+    let gltf = IMAGE_GLTF.replace(
+        "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAIAAAABCAYAAAD0In+KAAAADklEQVR4nGP4z8DwHwQBEPgD/U6VwW8AAAAASUVORK5CYII=",
+        uri,
+    );
+    let document = gltf::Gltf::from_slice(gltf.as_bytes()).unwrap().document;
+    let encoded = gltf::DefaultResolver::new(None).resolve(uri).unwrap();
+    let dir = output_directory("image-bytes");
+
+    let path = dir.join("Image.gltf");
+    gltf::export(&path, &document, &[], None).unwrap();
+    assert_eq!(fs::read(dir.join("Image_image0.jpg")).unwrap(), encoded);
+
+    let path = dir.join("Image.glb");
+    gltf::export(&path, &document, &[], None).unwrap();
+    let glb = fs::read(&path).unwrap();
+    let gltf = gltf::Gltf::from_slice(&glb).unwrap();
+    let image = &gltf.document.as_json().images[0];
+    assert_eq!(image.mime_type.as_ref().unwrap().0, "image/jpeg");
+    assert_eq!(&gltf.blob.unwrap()[..encoded.len()], &encoded[..]);
+}
+
+#[test]
+fn test_export_unknown_image_type() {
+    // The image has a MIME type without a known file extension and is
+    // exported next to a buffer of the same index.
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 4, "uri": "data:application/octet-stream;base64,AAECAw=="}],
+        "images": [{"uri": "data:image/x-example;base64,BAUGBw=="}]
+    }"#;
+    let document = gltf::Gltf::from_slice(json.as_bytes()).unwrap().document;
+    let buffers = [gltf::buffer::Data(vec![0, 1, 2, 3])];
+    let dir = output_directory("unknown-image");
+    gltf::export(dir.join("Asset.gltf"), &document, &buffers, None).unwrap();
+    assert_eq!(
+        fs::read(dir.join("Asset_buffer0.bin")).unwrap(),
+        [0, 1, 2, 3]
+    );
+    assert_eq!(
+        fs::read(dir.join("Asset_image0.bin")).unwrap(),
+        [4, 5, 6, 7]
+    );
+
+    let output = gltf::Gltf::open(dir.join("Asset.gltf")).unwrap();
+    let json = output.as_json();
+    assert_eq!(json.buffers[0].uri.as_deref(), Some("Asset_buffer0.bin"));
+    assert_eq!(json.images[0].uri.as_deref(), Some("Asset_image0.bin"));
+}
+
+#[test]
+fn test_pack_and_unpack() {
+    let dir = output_directory("pack");
+    let packed = dir.join("Box.glb");
+    gltf::pack("examples/Box.gltf", &packed).unwrap();
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let (output, output_buffers, _) = gltf::import(&packed).unwrap();
+    assert!(output
+        .as_json()
+        .buffers
+        .iter()
+        .all(|buffer| buffer.uri.is_none()));
+    assert_eq!(
+        positions(&output, &output_buffers),
+        positions(&document, &buffers)
+    );
+
+    let unpacked = dir.join("Unpacked.gltf");
+    gltf::unpack(&packed, &unpacked).unwrap();
+    let (output, output_buffers, _) = gltf::import(&unpacked).unwrap();
+    assert!(dir.join("Unpacked_buffer0.bin").exists());
+    assert_eq!(
+        positions(&output, &output_buffers),
+        positions(&document, &buffers)
+    );
+}
+
+#[test]
+fn test_pack_and_unpack_images() {
+    let resolver = gltf::DefaultResolver::new(None);
+    let glb = gltf::pack_slice(IMAGE_GLTF.as_bytes(), &resolver).unwrap();
+    let (document, _, images) = gltf::import_slice(&glb).unwrap();
+    let image = &document.as_json().images[0];
+    assert!(image.uri.is_none());
+    assert!(image.buffer_view.is_some());
+    assert_eq!(images[0].width, 2);
+
+    let dir = output_directory("pack-images");
+    let packed = dir.join("Image.glb");
+    std::fs::write(&packed, &glb).unwrap();
+    let unpacked = dir.join("Image.gltf");
+    gltf::unpack(&packed, &unpacked).unwrap();
+    let (document, _, output_images) = gltf::import(&unpacked).unwrap();
+    let image = &document.as_json().images[0];
+    assert_eq!(image.uri.as_deref(), Some("Image_image0.png"));
+    assert!(image.buffer_view.is_none());
+    assert_eq!(output_images[0].pixels, images[0].pixels);
+    assert_eq!(document.views().count(), 0);
+    assert_eq!(document.buffers().count(), 0);
+}
+
+#[test]
+fn test_unpack_removes_image_data() {
+    let gltf = fs::read_to_string("examples/Box.gltf").unwrap();
+    let image = &IMAGE_GLTF[IMAGE_GLTF.find("\"images\"").unwrap()..IMAGE_GLTF.rfind('}').unwrap()];
+    let gltf = gltf.replacen('{', &format!("{{{},", image.trim_end()), 1);
+    let resolver = gltf::DefaultResolver::new(Some(path::Path::new("examples")));
+    let glb = gltf::pack_slice(gltf.as_bytes(), &resolver).unwrap();
+
+    let dir = output_directory("unpack-images");
+    let packed = dir.join("Box.glb");
+    fs::write(&packed, &glb).unwrap();
+    let unpacked = dir.join("Box.gltf");
+    gltf::unpack(&packed, &unpacked).unwrap();
+    assert_eq!(fs::read(dir.join("Box_buffer0.bin")).unwrap().len(), 648);
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let (output, output_buffers, output_images) = gltf::import(&unpacked).unwrap();
+    assert_eq!(output.views().count(), document.views().count());
+    assert_eq!(output_images[0].width, 2);
+    assert_eq!(
+        positions(&output, &output_buffers),
+        positions(&document, &buffers)
+    );
+}
+
+#[test]
+fn test_unpack_invalid_image_view() {
+    use std::borrow::Cow;
+
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 4}],
+        "bufferViews": [{"buffer": 0, "byteOffset": 4, "byteLength": 4}],
+        "images": [{"bufferView": 0, "mimeType": "image/png"}]
+    }"#;
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: Cow::Borrowed(json.as_bytes()),
+        bin: Some(Cow::Owned(vec![0; 4])),
+    };
+    let dir = output_directory("invalid-view");
+    let packed = dir.join("Image.glb");
+    fs::write(&packed, glb.to_vec().unwrap()).unwrap();
+    let result = gltf::unpack(&packed, dir.join("Image.gltf"));
+    assert!(matches!(result, Err(gltf::Error::BufferLength { .. })));
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+#[test]
+fn test_pack_meshopt_fallback() {
+    // Five vertices of four floats each, compressed with meshoptimizer.
+    let gltf = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["EXT_meshopt_compression"],
+        "extensionsRequired": ["EXT_meshopt_compression"],
+        "buffers": [
+            {
+                "byteLength": 83,
+                "uri": "data:application/octet-stream;base64,oAAAATMAAAD//wEzwAAAfn1+AAABDMAAAP//AQwAAAB+AAABAMAAAP8BAMAAAH4AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgD8="
+            },
+            {"byteLength": 80, "extensions": {"EXT_meshopt_compression": {"fallback": true}}}
+        ],
+        "bufferViews": [{
+            "buffer": 1,
+            "byteLength": 80,
+            "byteStride": 16,
+            "extensions": {
+                "EXT_meshopt_compression": {
+                    "buffer": 0,
+                    "byteLength": 83,
+                    "byteStride": 16,
+                    "count": 5,
+                    "mode": "ATTRIBUTES"
+                }
+            }
+        }]
+    }"#;
+    let (_, expected, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+
+    let glb = gltf::pack_slice(gltf.as_bytes(), &gltf::DefaultResolver::new(None)).unwrap();
+    let (document, buffers, _) = gltf::import_slice(&glb).unwrap();
+    let fallback = document.buffers().nth(1).unwrap();
+    assert!(fallback.is_meshopt_fallback());
+    assert!(document.as_json().buffers[1].uri.is_none());
+    let view = document.views().next().unwrap();
+    assert_eq!(view.buffer().index(), 1);
+    assert_eq!(view.meshopt_compression().unwrap().buffer().index(), 0);
+    assert_eq!(
+        gltf::Gltf::from_slice(&glb).unwrap().blob.unwrap().len(),
+        84
+    );
+    assert_eq!(&buffers[1][..80], &expected[1][..80]);
+}
@@ -0,0 +1,99 @@
+use gltf::builder::Builder;
+use gltf::json;
+use json::validation::Checked::Valid;
+use json::validation::Validate;
+
+fn triangle(builder: &mut Builder, positions: &[[f32; 3]], indices: &[u16]) {
+    let positions = builder.attribute(positions);
+    let indices = builder.indices(indices);
+    let mesh = builder.push(json::Mesh {
+        extensions: None,
+        extras: Default::default(),
+        name: None,
+        primitives: vec![json::mesh::Primitive {
+            attributes: [(Valid(json::mesh::Semantic::Positions), positions)].into(),
+            extensions: None,
+            extras: Default::default(),
+            indices: Some(indices),
+            material: None,
+            mode: Valid(json::mesh::Mode::Triangles),
+            targets: None,
+        }],
+        weights: None,
+    });
+    let node = builder.push(json::Node {
+        mesh: Some(mesh),
+        ..Default::default()
+    });
+    builder.push(json::Scene {
+        extensions: None,
+        extras: Default::default(),
+        name: None,
+        nodes: vec![node],
+    });
+}
+
+#[test]
+fn test_build_primitive() {
+    let positions = [[0.0, 0.5, 0.0], [-0.5, -0.5, 0.0], [0.5, -0.5, 0.0]];
+    let mut builder = Builder::new();
+    triangle(&mut builder, &positions, &[0, 1, 2]);
+    let (document, buffer) = builder.build().unwrap();
+
+    let root = document.as_json();
+    let mut errors = Vec::new();
+    root.validate(root, json::Path::new, &mut |path, error| {
+        errors.push((path(), error))
+    });
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let accessor = document.accessors().next().unwrap();
+    assert_eq!(
+        accessor.min(),
+        Some(json::Value::from(vec![-0.5, -0.5, 0.0]))
+    );
+    assert_eq!(accessor.max(), Some(json::Value::from(vec![0.5, 0.5, 0.0])));
+
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let reader = primitive.reader(|_| Some(&buffer));
+    let output: Vec<_> = reader.read_positions().unwrap().collect();
+    assert_eq!(output, positions);
+    let indices: Vec<_> = reader.read_indices().unwrap().into_u32().collect();
+    assert_eq!(indices, [0, 1, 2]);
+}
+
+#[test]
+fn test_build_padded_attribute() {
+    let mut builder = Builder::new();
+    let colors = builder.normalized_attribute(&[[255u8, 0, 0], [0, 255, 0]]);
+    let (document, buffer) = builder.build().unwrap();
+
+    let view = document.views().next().unwrap();
+    assert_eq!(view.stride(), Some(4));
+    assert_eq!(view.length(), 8);
+    assert_eq!(buffer, [255, 0, 0, 0, 0, 255, 0, 0]);
+    assert!(document
+        .accessors()
+        .nth(colors.value())
+        .unwrap()
+        .normalized());
+}
+
+#[test]
+fn test_build_empty_data() {
+    let mut builder = Builder::new();
+    triangle(&mut builder, &[], &[]);
+    match builder.build() {
+        Err(gltf::Error::Validation(errors)) => {
+            let paths: Vec<_> = errors.iter().map(|(path, _)| path.to_string()).collect();
+            assert_eq!(paths, ["accessors[0].count", "accessors[1].count"]);
+        }
+        _ => panic!("expected a validation error"),
+    }
+}
@@ -0,0 +1,395 @@
+use gltf::builder::Builder;
+use gltf::json;
+
+fn positions(document: &gltf::Document, buffers: &[gltf::buffer::Data]) -> Vec<[f32; 3]> {
+    document
+        .meshes()
+        .flat_map(|mesh| mesh.primitives())
+        .flat_map(|primitive| {
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+            reader.read_positions().unwrap().collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Imports the box example with unreferenced objects and buffer data added.
+fn import_box_with_garbage() -> (json::Root, Vec<gltf::buffer::Data>) {
+    let (document, mut buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mut root = document.into_json();
+
+    // Insert unused bytes before the existing data.
+    buffers[0].0.splice(0..0, [0xff; 8]);
+    root.buffers[0].byte_length = json::validation::USize64::from(buffers[0].len());
+    for view in &mut root.buffer_views {
+        let offset = view.byte_offset.unwrap_or_default();
+        view.byte_offset = Some(json::validation::USize64(offset.0 + 8));
+    }
+
+    let mut builder = Builder::from_json(root);
+    let unused = builder.accessor(&[1.0f32, 2.0, 3.0]);
+    builder.push(json::Node {
+        name: Some("Unused".to_owned()),
+        ..Default::default()
+    });
+    builder.push(json::Material {
+        name: Some("Unused".to_owned()),
+        ..Default::default()
+    });
+    builder.push(json::Skin {
+        extensions: None,
+        extras: Default::default(),
+        inverse_bind_matrices: Some(unused),
+        joints: vec![json::Index::new(0)],
+        name: None,
+        skeleton: None,
+    });
+    let (document, data) = builder.build().unwrap();
+    buffers.push(gltf::buffer::Data(data));
+    (document.into_json(), buffers)
+}
+
+#[test]
+fn test_prune() {
+    let (mut root, _) = import_box_with_garbage();
+    assert_eq!(root.accessors.len(), 4);
+    gltf::edit::prune(&mut root);
+    assert_eq!(root.accessors.len(), 3);
+    assert_eq!(root.buffer_views.len(), 2);
+    assert_eq!(root.buffers.len(), 1);
+    assert_eq!(root.nodes.len(), 2);
+    assert_eq!(root.materials.len(), 1);
+    assert!(root.skins.is_empty());
+    gltf::Document::from_json(root).unwrap();
+}
+
+#[test]
+fn test_prune_with_data() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let (mut root, mut output_buffers) = import_box_with_garbage();
+    gltf::edit::prune_with_data(&mut root, &mut output_buffers);
+    assert_eq!(output_buffers.len(), 1);
+    assert_eq!(output_buffers[0].len(), 648);
+    assert_eq!(root.buffers[0].byte_length.0, 648);
+    assert_eq!(root.buffer_views[0].byte_offset.unwrap().0, 576);
+
+    let output = gltf::Document::from_json(root).unwrap();
+    assert_eq!(
+        positions(&output, &output_buffers),
+        positions(&document, &buffers)
+    );
+}
+
+#[test]
+fn test_merge() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mut root = document.clone().into_json();
+    gltf::edit::merge(&mut root, document.clone().into_json());
+    assert_eq!(root.accessors.len(), 6);
+    assert_eq!(root.buffers.len(), 2);
+    assert_eq!(root.scenes.len(), 2);
+    assert_eq!(root.meshes[1].primitives[0].indices.unwrap().value(), 3);
+    assert_eq!(root.buffer_views[3].buffer.value(), 1);
+
+    gltf::edit::merge_scenes(&mut root);
+    assert_eq!(root.scenes.len(), 1);
+    assert_eq!(root.scene.unwrap().value(), 0);
+    let nodes: Vec<_> = root.scenes[0]
+        .nodes
+        .iter()
+        .map(|node| node.value())
+        .collect();
+    assert_eq!(nodes, [0, 2]);
+
+    let output = gltf::Document::from_json(root).unwrap();
+    let buffers = [buffers.clone(), buffers].concat();
+    assert_eq!(positions(&output, &buffers).len(), 48);
+}
+
+#[test]
+fn test_merge_with_data_binary() {
+    let (document, buffers, _) = gltf::import("examples/Box.glb").unwrap();
+    let mut root = document.clone().into_json();
+    let mut output_buffers = buffers.clone();
+    let other_buffers = buffers.clone();
+    gltf::edit::merge_with_data(
+        &mut root,
+        &mut output_buffers,
+        document.clone().into_json(),
+        other_buffers,
+    );
+    assert_eq!(root.buffers.len(), 1);
+    assert_eq!(output_buffers.len(), 1);
+    assert_eq!(root.buffers[0].byte_length.0, 1296);
+    assert!(output_buffers[0].len() >= 1296);
+
+    let output = gltf::Document::from_json(root).unwrap();
+    let expected = [
+        positions(&document, &buffers),
+        positions(&document, &buffers),
+    ]
+    .concat();
+    assert_eq!(positions(&output, &output_buffers), expected);
+}
+
+#[test]
+fn test_dedup() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mut root = document.clone().into_json();
+    let mut output_buffers = buffers.clone();
+    gltf::edit::merge_with_data(
+        &mut root,
+        &mut output_buffers,
+        document.clone().into_json(),
+        buffers.clone(),
+    );
+    root.materials[1].name = Some("Copy".to_owned());
+    assert_eq!(root.accessors.len(), 6);
+    assert_eq!(root.materials.len(), 2);
+
+    gltf::edit::dedup(&mut root, &output_buffers);
+    assert_eq!(root.accessors.len(), 3);
+    assert_eq!(root.materials.len(), 1);
+    assert_eq!(root.meshes[1].primitives[0].indices.unwrap().value(), 0);
+    assert_eq!(root.meshes[1].primitives[0].material.unwrap().value(), 0);
+
+    gltf::edit::prune_with_data(&mut root, &mut output_buffers);
+    assert_eq!(root.buffers.len(), 1);
+    assert_eq!(root.buffer_views.len(), 2);
+    let output = gltf::Document::from_json(root).unwrap();
+    let expected = [
+        positions(&document, &buffers),
+        positions(&document, &buffers),
+    ]
+    .concat();
+    assert_eq!(positions(&output, &output_buffers), expected);
+}
+
+#[test]
+fn test_dedup_images() {
+    let dir = std::env::temp_dir().join("gltf-dedup-images");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("a.png"), [1, 2, 3]).unwrap();
+    std::fs::write(dir.join("b.png"), [1, 2, 3]).unwrap();
+    let root = json::Root::from_str(
+        r#"{
+            "asset": {"version": "2.0"},
+            "images": [
+                {"uri": "data:image/png;base64,AAECAw=="},
+                {"uri": "data:application/octet-stream;base64,AAECAw=="},
+                {"uri": "a.png"},
+                {"uri": "b.png"}
+            ],
+            "textures": [{"source": 0}, {"source": 1}, {"source": 2}, {"source": 3}]
+        }"#,
+    )
+    .unwrap();
+    let sources = |root: &json::Root| -> Vec<usize> {
+        root.textures
+            .iter()
+            .map(|texture| texture.source.value())
+            .collect()
+    };
+
+    let mut output = root.clone();
+    gltf::edit::dedup(&mut output, &[] as &[gltf::buffer::Data]);
+    // Textures of identical images become identical themselves.
+    assert_eq!(output.images.len(), 3);
+    assert_eq!(sources(&output), [0, 1, 2]);
+
+    let mut output = root;
+    let resolver = gltf::DefaultResolver::new(Some(&dir));
+    gltf::edit::dedup_with_resolver(&mut output, &[] as &[gltf::buffer::Data], &resolver).unwrap();
+    assert_eq!(output.images.len(), 2);
+    assert_eq!(sources(&output), [0, 1]);
+}
+
+#[test]
+fn test_repack() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mut root = document.clone().into_json();
+    let mut output_buffers = buffers.clone();
+    gltf::edit::repack(&mut root, &mut output_buffers, false);
+    assert_eq!(root.buffer_views[0].byte_offset.unwrap().0, 0);
+    assert_eq!(root.buffer_views[1].byte_offset.unwrap().0, 72);
+    assert_eq!(root.buffers[0].byte_length.0, 648);
+
+    let output = gltf::Document::from_json(root).unwrap();
+    assert_eq!(
+        positions(&output, &output_buffers),
+        positions(&document, &buffers)
+    );
+}
+
+#[test]
+fn test_repack_merge() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let (mut root, mut output_buffers) = import_box_with_garbage();
+    assert_eq!(root.buffers.len(), 2);
+    gltf::edit::repack(&mut root, &mut output_buffers, true);
+    assert_eq!(root.buffers.len(), 1);
+    assert_eq!(output_buffers.len(), 1);
+    assert_eq!(root.buffer_views[2].buffer.value(), 0);
+    assert_eq!(root.buffer_views[2].byte_offset.unwrap().0, 648);
+    assert_eq!(root.buffers[0].byte_length.0, 660);
+
+    let output = gltf::Document::from_json(root).unwrap();
+    assert_eq!(
+        positions(&output, &output_buffers),
+        positions(&document, &buffers)
+    );
+}
+
+#[test]
+fn test_editor() {
+    use gltf::edit::Editor;
+    use json::Index;
+
+    let (document, _, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mut editor = Editor::new(document);
+    assert!(editor.reparent(Index::new(0), Some(Index::new(1))).is_err());
+    assert!(editor.reparent(Index::new(5), None).is_err());
+
+    let child = editor
+        .add_node(Default::default(), Some(Index::new(1)))
+        .unwrap();
+    editor.reparent(Index::new(1), None).unwrap();
+    assert!(editor.as_json().nodes[0].children.is_none());
+    assert_eq!(editor.as_json().scenes[0].nodes.len(), 2);
+    assert_eq!(editor.as_json().nodes[1].children, Some(vec![child]));
+
+    editor.set_material(Index::new(0), 0, None).unwrap();
+    assert!(editor.set_material(Index::new(0), 1, None).is_err());
+    assert!(editor.as_json().meshes[0].primitives[0].material.is_none());
+
+    editor.remove_node(Index::new(0)).unwrap();
+    assert_eq!(editor.as_json().nodes.len(), 2);
+    assert_eq!(editor.as_json().scenes[0].nodes, [Index::new(0)]);
+    assert_eq!(
+        editor.as_json().nodes[0].children,
+        Some(vec![Index::new(1)])
+    );
+
+    editor.remove_node(Index::new(0)).unwrap();
+    let document = editor.build().unwrap();
+    assert_eq!(document.nodes().count(), 0);
+    assert_eq!(document.meshes().count(), 1);
+}
+
+#[test]
+fn test_editor_skin_joints() {
+    use gltf::edit::Editor;
+    use json::Index;
+
+    let root = json::Root::from_str(
+        r#"{
+            "asset": {"version": "2.0"},
+            "nodes": [
+                {"name": "removed"},
+                {"name": "hip", "children": [2]},
+                {"name": "knee"},
+                {"name": "skinned", "skin": 0}
+            ],
+            "skins": [{"joints": [1, 2], "skeleton": 1}]
+        }"#,
+    )
+    .unwrap();
+    let mut editor = Editor::from_json(root);
+
+    // Without scenes, there is no place for new root nodes.
+    assert!(editor.add_node(Default::default(), None).is_err());
+    assert!(editor.reparent(Index::new(2), None).is_err());
+
+    editor.remove_node(Index::new(0)).unwrap();
+    assert_eq!(editor.as_json().nodes.len(), 3);
+    assert_eq!(
+        editor.as_json().skins[0].joints,
+        [Index::new(0), Index::new(1)]
+    );
+    assert_eq!(editor.as_json().skins[0].skeleton, Some(Index::new(0)));
+    assert_eq!(editor.as_json().nodes[2].skin, Some(Index::new(0)));
+
+    // Removing the hip would also remove the knee.
+    assert!(editor.remove_node(Index::new(0)).is_err());
+    assert!(editor.remove_node(Index::new(1)).is_err());
+    assert_eq!(editor.as_json().nodes.len(), 3);
+    assert_eq!(editor.as_json().skins[0].joints.len(), 2);
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+#[test]
+fn test_prune_variant_mappings() {
+    let mut root = json::Root::from_str(
+        r#"{
+            "asset": {"version": "2.0"},
+            "extensionsUsed": ["KHR_materials_variants"],
+            "extensions": {"KHR_materials_variants": {"variants": [
+                {"name": "day"}, {"name": "night"}
+            ]}},
+            "materials": [{"name": "unused"}, {"name": "day"}, {"name": "night"}],
+            "meshes": [{"primitives": [{
+                "attributes": {},
+                "extensions": {"KHR_materials_variants": {"mappings": [
+                    {"material": 1, "variants": [0]},
+                    {"material": 2, "variants": [1]}
+                ]}}
+            }]}],
+            "nodes": [{"mesh": 0}],
+            "scenes": [{"nodes": [0]}]
+        }"#,
+    )
+    .unwrap();
+
+    // The materials are only referenced by the variant mappings.
+    gltf::edit::prune(&mut root);
+    assert_eq!(root.materials.len(), 2);
+    assert_eq!(root.materials[0].name.as_deref(), Some("day"));
+    let extensions = root.meshes[0].primitives[0].extensions.as_ref().unwrap();
+    let mappings = &extensions.khr_materials_variants.as_ref().unwrap().mappings;
+    let materials: Vec<_> = mappings.iter().map(|mapping| mapping.material).collect();
+    assert_eq!(materials, [0, 1]);
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+#[test]
+fn test_apply_variant() {
+    use json::Index;
+
+    let mut root = json::Root::from_str(
+        r#"{
+            "asset": {"version": "2.0"},
+            "extensionsUsed": ["KHR_materials_variants"],
+            "extensions": {"KHR_materials_variants": {"variants": [
+                {"name": "day"}, {"name": "night"}
+            ]}},
+            "materials": [{}, {}, {}],
+            "meshes": [{"primitives": [{
+                "attributes": {},
+                "material": 0,
+                "extensions": {"KHR_materials_variants": {"mappings": [
+                    {"material": 1, "variants": [0]},
+                    {"material": 2, "variants": [1]}
+                ]}}
+            }]}],
+            "nodes": [{"mesh": 0}],
+            "scenes": [{"nodes": [0]}]
+        }"#,
+    )
+    .unwrap();
+
+    assert!(gltf::edit::apply_variant(&mut root, 2, false).is_err());
+    assert!(gltf::edit::apply_variant(&mut root, "noon", false).is_err());
+
+    gltf::edit::apply_variant(&mut root, "night", false).unwrap();
+    assert_eq!(root.meshes[0].primitives[0].material, Some(Index::new(2)));
+    assert_eq!(root.extensions_used, ["KHR_materials_variants"]);
+
+    gltf::edit::apply_variant(&mut root, 0, true).unwrap();
+    assert_eq!(root.meshes[0].primitives[0].material, Some(Index::new(1)));
+    assert!(root.extensions_used.is_empty());
+    let extensions = root.meshes[0].primitives[0].extensions.as_ref().unwrap();
+    assert!(extensions.khr_materials_variants.is_none());
+
+    gltf::edit::prune(&mut root);
+    assert_eq!(root.materials.len(), 1);
+    assert_eq!(root.meshes[0].primitives[0].material, Some(Index::new(0)));
+}
@@ -0,0 +1,311 @@
+use gltf::validator::{Code, Severity, Validator};
+
+const INVALID_GLTF: &[u8] = br#"{
+    "asset": {"version": "2.1"},
+    "extensionsRequired": ["EXT_example"],
+    "accessors": [
+        {
+            "bufferView": 0,
+            "componentType": 5126,
+            "count": 3,
+            "type": "VEC3",
+            "min": [0, 0, 0],
+            "max": [1, 1, 1]
+        }
+    ],
+    "buffers": [{"byteLength": 36}],
+    "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    "materials": [{}],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "material": 2}]}],
+    "nodes": [{"mesh": 0}, {}],
+    "scenes": [{"nodes": [0, 1]}]
+}"#;
+
+fn codes(report: &gltf::validator::Report) -> Vec<(Code, Severity, String)> {
+    report
+        .issues()
+        .iter()
+        .map(|issue| (issue.code, issue.severity, issue.path.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_validator() {
+    let gltf = gltf::Gltf::from_slice_without_validation(INVALID_GLTF).unwrap();
+    let report = Validator::new().validate(&gltf);
+    assert_eq!(
+        codes(&report),
+        [
+            (
+                Code::UnresolvedReference,
+                Severity::Error,
+                "meshes[0].primitives[0].material".to_string()
+            ),
+            (
+                Code::UnsupportedExtension,
+                Severity::Error,
+                "extensionsRequired[0] = \"EXT_example\"".to_string()
+            ),
+            (
+                Code::UnusedExtensionRequired,
+                Severity::Error,
+                "extensionsRequired[0] = \"EXT_example\"".to_string()
+            ),
+            (
+                Code::UnknownAssetMinorVersion,
+                Severity::Warning,
+                "asset.version".to_string()
+            ),
+            (
+                Code::UnusedObject,
+                Severity::Information,
+                "materials[0]".to_string()
+            ),
+            (Code::NodeEmpty, Severity::Hint, "nodes[1]".to_string()),
+            (
+                Code::BufferViewTargetMissing,
+                Severity::Hint,
+                "bufferViews[0].target".to_string()
+            ),
+        ]
+    );
+    assert!(report.has_errors());
+    assert_eq!(report.count(Severity::Error), 3);
+
+    let json = report.to_json();
+    let issues = &json["issues"];
+    assert_eq!(issues["numErrors"], 3);
+    assert_eq!(issues["numWarnings"], 1);
+    assert_eq!(issues["numInfos"], 1);
+    assert_eq!(issues["numHints"], 2);
+    assert_eq!(issues["truncated"], false);
+    let message = &issues["messages"][0];
+    assert_eq!(message["code"], "UNRESOLVED_REFERENCE");
+    assert_eq!(message["severity"], 0);
+    assert_eq!(message["pointer"], "/meshes/0/primitives/0/material");
+    assert_eq!(issues["messages"][1]["pointer"], "/extensionsRequired/0");
+    assert_eq!(json["info"]["version"], "2.1");
+}
+
+#[test]
+fn test_validator_configuration() {
+    let gltf = gltf::Gltf::from_slice_without_validation(INVALID_GLTF).unwrap();
+
+    let mut options = gltf::LoadOptions::default();
+    options.required_extensions.push("EXT_example".to_string());
+    let mut validator = Validator::with_options(options);
+    validator
+        .override_severity(Code::UnusedExtensionRequired, Severity::Warning)
+        .max_issues(3);
+    let report = validator.validate(&gltf);
+    assert_eq!(
+        codes(&report),
+        [
+            (
+                Code::UnresolvedReference,
+                Severity::Error,
+                "meshes[0].primitives[0].material".to_string()
+            ),
+            (
+                Code::UnusedExtensionRequired,
+                Severity::Warning,
+                "extensionsRequired[0] = \"EXT_example\"".to_string()
+            ),
+            (
+                Code::UnknownAssetMinorVersion,
+                Severity::Warning,
+                "asset.version".to_string()
+            ),
+        ]
+    );
+    assert!(report.is_truncated());
+    assert_eq!(report.to_json()["issues"]["truncated"], true);
+}
+
+#[test]
+fn test_validator_box() {
+    let gltf = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    let report = Validator::new().validate(&gltf);
+    assert!(!report.has_errors());
+    assert_eq!(report.count(Severity::Warning), 0);
+}
+
+/// Builds a little-endian buffer from `f32` values.
+fn floats(values: &[f32]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect()
+}
+
+#[test]
+fn test_validator_data() {
+    let positions = floats(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, f32::NAN, 0.0]);
+    let normals = floats(&[0.0, 0.0, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 1.0]);
+    let weights = floats(&[0.5, 0.5, 0.0, 0.0, 0.5, 0.25, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+    let times = floats(&[0.0, 1.0, 1.0]);
+    let rotations = floats(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0]);
+    let indices = [0, 1, 2, 3];
+    let data = [
+        positions,
+        normals,
+        weights,
+        times,
+        rotations,
+        indices.to_vec(),
+    ]
+    .concat();
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+        "asset": {"version": "2.0"},
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+            {"bufferView": 0, "byteOffset": 36, "componentType": 5126, "count": 3, "type": "VEC3"},
+            {"bufferView": 0, "byteOffset": 72, "componentType": 5126, "count": 3, "type": "VEC4"},
+            {"bufferView": 0, "byteOffset": 120, "componentType": 5126, "count": 3, "type": "SCALAR", "min": [0], "max": [2]},
+            {"bufferView": 0, "byteOffset": 132, "componentType": 5126, "count": 3, "type": "VEC4"},
+            {"bufferView": 1, "componentType": 5121, "count": 4, "type": "SCALAR"},
+            {"bufferView": 3, "componentType": 5123, "count": 1, "type": "SCALAR"},
+            {"bufferView": 2, "byteOffset": 1, "componentType": 5126, "count": 1, "type": "VEC2"}
+        ],
+        "animations": [{
+            "channels": [{"sampler": 0, "target": {"node": 0, "path": "rotation"}}],
+            "samplers": [{"input": 3, "output": 4}]
+        }],
+        "buffers": [{"byteLength": 184}],
+        "bufferViews": [
+            {"buffer": 0, "byteLength": 180, "target": 34962},
+            {"buffer": 0, "byteOffset": 180, "byteLength": 4, "target": 34963},
+            {"buffer": 0, "byteOffset": 180, "byteLength": 4, "byteStride": 6},
+            {"buffer": 0, "byteOffset": 181, "byteLength": 2}
+        ],
+        "meshes": [{
+            "primitives": [{
+                "attributes": {"POSITION": 0, "NORMAL": 1, "WEIGHTS_0": 2},
+                "indices": 5
+            }]
+        }],
+        "nodes": [{"mesh": 0}],
+        "scenes": [{"nodes": [0]}]
+    }"#,
+    )
+    .unwrap();
+
+    let mut validator = Validator::new();
+    validator
+        .override_severity(Code::UnusedObject, Severity::Hint)
+        .override_severity(Code::BufferViewTargetMissing, Severity::Hint);
+    let report = validator.validate_with_data(&gltf, |_| Some(&data));
+    let errors: Vec<_> = codes(&report)
+        .into_iter()
+        .filter(|(_, severity, _)| *severity == Severity::Error)
+        .map(|(code, _, path)| (code, path))
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            (
+                Code::BufferViewByteStrideAlignment,
+                "bufferViews[2].byteStride".to_string()
+            ),
+            (
+                Code::AccessorTotalOffsetAlignment,
+                "accessors[6].byteOffset".to_string()
+            ),
+            (
+                Code::AccessorOffsetAlignment,
+                "accessors[7].byteOffset".to_string()
+            ),
+            (
+                Code::AccessorSmallByteStride,
+                "accessors[7].bufferView".to_string()
+            ),
+            (Code::AccessorTooLong, "accessors[7]".to_string()),
+            (Code::AccessorInvalidFloat, "accessors[0]".to_string()),
+            (Code::AccessorMaxMismatch, "accessors[3].max[0]".to_string()),
+            (
+                Code::AccessorIndexOob,
+                "meshes[0].primitives[0].indices".to_string()
+            ),
+            (
+                Code::AccessorNonUnit,
+                "meshes[0].primitives[0].attributes[\"NORMAL\"]".to_string()
+            ),
+            (
+                Code::AccessorWeightsNonNormalized,
+                "meshes[0].primitives[0].attributes[\"WEIGHTS_0\"]".to_string()
+            ),
+            (
+                Code::AccessorAnimationInputNonIncreasing,
+                "animations[0].samplers[0].input".to_string()
+            ),
+            (
+                Code::AccessorAnimationSamplerOutputNonNormalizedQuaternion,
+                "animations[0].samplers[0].output".to_string()
+            ),
+        ]
+    );
+    let message = &report.issues()[report
+        .issues()
+        .iter()
+        .position(|issue| issue.code == Code::AccessorIndexOob)
+        .unwrap()]
+    .message;
+    assert!(message.starts_with("Indices accessor element at index 3 has value 3"));
+
+    // Data that is too short is reported and not read.
+    let report = Validator::new().validate_with_data(&gltf, |_| Some(&data[..100]));
+    let issue = report
+        .issues()
+        .iter()
+        .find(|issue| issue.code == Code::BufferByteLengthMismatch)
+        .unwrap();
+    assert_eq!(issue.path.to_string(), "buffers[0].byteLength");
+    assert!(!report
+        .issues()
+        .iter()
+        .any(|issue| issue.code == Code::AccessorMaxMismatch));
+
+    // Without data only the layout is checked.
+    let report = Validator::new().validate(&gltf);
+    assert_eq!(report.count(Severity::Error), 5);
+}
+
+#[test]
+fn test_validator_data_box() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let report =
+        Validator::new().validate_with_data(&document, |buffer| Some(&buffers[buffer.index()]));
+    assert!(!report.has_errors());
+}
+
+#[test]
+fn test_validator_data_large_sparse() {
+    let data = [floats(&[1.0]), vec![0, 0, 0, 0]].concat();
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+        "asset": {"version": "2.0"},
+        "accessors": [{
+            "componentType": 5126,
+            "count": 4294967295,
+            "type": "SCALAR",
+            "sparse": {
+                "count": 1,
+                "indices": {"bufferView": 1, "componentType": 5125},
+                "values": {"bufferView": 0}
+            }
+        }],
+        "buffers": [{"byteLength": 8}],
+        "bufferViews": [
+            {"buffer": 0, "byteLength": 4},
+            {"buffer": 0, "byteOffset": 4, "byteLength": 4}
+        ]
+    }"#,
+    )
+    .unwrap();
+
+    // The accessor is too large to be read, so its data is not checked.
+    let report = Validator::new().validate_with_data(&gltf, |_| Some(&data));
+    assert!(!report.has_errors(), "{:?}", codes(&report));
+}
@@ -126,3 +126,1161 @@ fn test_sparse_accessor_without_base_buffer_view_yield_all_values() {
         assert_eq!(o - EXPECTED_OUTPUTS[i], 0.0);
     }
 }
+
+#[cfg(feature = "KHR_mesh_quantization")]
+#[test]
+fn test_read_quantized_attributes() {
+    use gltf::builder::Builder;
+    use gltf::json;
+    use json::validation::Checked::Valid;
+
+    let mut builder = Builder::new();
+    let positions = builder.attribute(&[[0i16, 2, 0], [-2, -2, 0], [2, -2, 0]]);
+    let normals = builder.normalized_attribute(&[[0i8, 0, 127]; 3]);
+    let tex_coords = builder.normalized_attribute(&[[0u16, 0], [65535, 0], [0, 65535]]);
+    let primitive = json::mesh::Primitive {
+        attributes: [
+            (Valid(json::mesh::Semantic::Positions), positions),
+            (Valid(json::mesh::Semantic::Normals), normals),
+            (Valid(json::mesh::Semantic::TexCoords(0)), tex_coords),
+        ]
+        .into(),
+        extensions: None,
+        extras: Default::default(),
+        indices: None,
+        material: None,
+        mode: Valid(json::mesh::Mode::Triangles),
+        targets: None,
+    };
+    builder.push(json::Mesh {
+        extensions: None,
+        extras: Default::default(),
+        name: None,
+        primitives: vec![primitive],
+        weights: None,
+    });
+    assert!(builder.clone().build().is_err());
+    builder
+        .as_json_mut()
+        .extensions_used
+        .push("KHR_mesh_quantization".to_owned());
+    let (document, buffer) = builder.build().unwrap();
+
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let reader = primitive.reader(|_| Some(&buffer));
+    assert!(reader.read_positions().is_none());
+    let positions: Vec<_> = reader
+        .read_positions_quantized()
+        .unwrap()
+        .into_f32()
+        .collect();
+    assert_eq!(
+        positions,
+        [[0.0, 2.0, 0.0], [-2.0, -2.0, 0.0], [2.0, -2.0, 0.0]]
+    );
+    let normals: Vec<_> = reader
+        .read_normals_quantized()
+        .unwrap()
+        .into_f32()
+        .collect();
+    assert_eq!(normals, [[0.0, 0.0, 1.0]; 3]);
+    let tex_coords: Vec<_> = reader
+        .read_tex_coords_quantized(0)
+        .unwrap()
+        .into_f32()
+        .collect();
+    assert_eq!(tex_coords, [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+}
+
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+#[test]
+fn test_mesh_gpu_instancing() {
+    use gltf::builder::Builder;
+    use gltf::json;
+    use json::extensions::scene::ext_mesh_gpu_instancing::MeshGpuInstancing;
+    use json::validation::Checked::Valid;
+
+    let mut builder = Builder::new();
+    let positions = builder.attribute(&[[0.0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    let mesh = builder.push(json::Mesh {
+        extensions: None,
+        extras: Default::default(),
+        name: None,
+        primitives: vec![json::mesh::Primitive {
+            attributes: [(Valid(json::mesh::Semantic::Positions), positions)].into(),
+            extensions: None,
+            extras: Default::default(),
+            indices: None,
+            material: None,
+            mode: Valid(json::mesh::Mode::Triangles),
+            targets: None,
+        }],
+        weights: None,
+    });
+    let translations = builder.attribute(&[[1.0f32, 2.0, 3.0], [-1.0, 0.0, 0.5]]);
+    let rotations = builder.normalized_attribute(&[[0i16, 0, 0, 32767], [32767, 0, 0, 0]]);
+    let scales = builder.attribute(&[[1.0f32; 3], [2.0; 3]]);
+    let ids = builder.attribute(&[7.0f32, 8.0]);
+    let instancing = MeshGpuInstancing {
+        attributes: [
+            ("TRANSLATION".to_owned(), translations),
+            ("ROTATION".to_owned(), rotations),
+            ("SCALE".to_owned(), scales),
+            ("_ID".to_owned(), ids),
+        ]
+        .into(),
+        extensions: None,
+        extras: Default::default(),
+    };
+    builder.push(json::Node {
+        mesh: Some(mesh),
+        extensions: Some(json::extensions::scene::Node {
+            ext_mesh_gpu_instancing: Some(instancing),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    builder
+        .as_json_mut()
+        .extensions_used
+        .push("EXT_mesh_gpu_instancing".to_owned());
+
+    // Every attribute must have one element per instance.
+    let mut mismatched = builder.clone();
+    let extra = mismatched.attribute(&[[1.0f32; 3]; 3]);
+    mismatched.as_json_mut().nodes[0]
+        .extensions
+        .as_mut()
+        .unwrap()
+        .ext_mesh_gpu_instancing
+        .as_mut()
+        .unwrap()
+        .attributes
+        .insert("SCALE".to_owned(), extra);
+    assert!(mismatched.build().is_err());
+
+    let (document, buffer) = builder.build().unwrap();
+    let node = document.nodes().next().unwrap();
+    let instancing = node.instancing().unwrap();
+    assert_eq!(instancing.count(), 2);
+    assert_eq!(instancing.attributes().len(), 4);
+    assert_eq!(instancing.get("_ID").unwrap().index(), ids.value());
+
+    let reader = instancing.reader(|_| Some(&buffer));
+    let translations: Vec<_> = reader.read_translations().unwrap().collect();
+    assert_eq!(translations, [[1.0, 2.0, 3.0], [-1.0, 0.0, 0.5]]);
+    let rotations: Vec<_> = reader.read_rotations().unwrap().collect();
+    assert_eq!(rotations, [[0.0, 0.0, 0.0, 1.0], [1.0, 0.0, 0.0, 0.0]]);
+    let scales: Vec<_> = reader.read_scales().unwrap().collect();
+    assert_eq!(scales, [[1.0; 3], [2.0; 3]]);
+    let ids: Vec<_> = reader.read_f32::<1>("_ID").unwrap().collect();
+    assert_eq!(ids, [[7.0], [8.0]]);
+    assert!(reader.read_f32::<3>("_ID").is_none());
+    assert!(reader.read_f32::<3>("_MISSING").is_none());
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+#[test]
+fn test_meshopt_compression() {
+    use gltf::ext_meshopt_compression::{self, Mode};
+    use std::borrow::Cow;
+
+    // Five vertices of four floats each, encoded with meshoptimizer.
+    let vertices: [f32; 20] = [
+        0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.5, 0.5,
+        1.0, 1.0,
+    ];
+    let mut compressed = vec![
+        0xa0, 0x00, 0x00, 0x01, 0x33, 0x00, 0x00, 0x00, 0xff, 0xff, 0x01, 0x33, 0xc0, 0x00, 0x00,
+        0x7e, 0x7d, 0x7e, 0x00, 0x00, 0x01, 0x0c, 0xc0, 0x00, 0x00, 0xff, 0xff, 0x01, 0x0c, 0x00,
+        0x00, 0x00, 0x7e, 0x00, 0x00, 0x01, 0x00, 0xc0, 0x00, 0x00, 0xff, 0x01, 0x00, 0xc0, 0x00,
+        0x00, 0x7e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3f,
+    ];
+    let expected: Vec<u8> = vertices.iter().flat_map(|x| x.to_le_bytes()).collect();
+    assert_eq!(
+        ext_meshopt_compression::decode_vertex_buffer(&compressed, 5, 16).unwrap(),
+        expected
+    );
+    assert_eq!(
+        ext_meshopt_compression::decode_vertex_buffer(&compressed[..80], 5, 16),
+        Err(ext_meshopt_compression::Error::UnexpectedEnd)
+    );
+
+    let triangles = [
+        0xe0, 0xf0, 0x10, 0xfe, 0xff, 0x6f, 0x3f, 0xf0, 0x0c, 0xff, 0x02, 0x02, 0x02, 0xb6, 0x01,
+        0x63, 0x00, 0x76, 0x87, 0x56, 0x67, 0x78, 0xa9, 0x86, 0x65, 0x89, 0x68, 0x98, 0x01, 0x69,
+        0x00, 0x00,
+    ];
+    let indices: Vec<u8> = [0u16, 1, 2, 2, 1, 3, 4, 6, 5, 7, 8, 9, 2, 3, 100, 9, 8, 50]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect();
+    assert_eq!(
+        ext_meshopt_compression::decode_index_buffer(&triangles, 18, 2).unwrap(),
+        indices
+    );
+
+    // A grid of quads followed by triangles referring to vertices out of
+    // order, encoded with the reference encoder of meshoptimizer 0.12.
+    let grid = [
+        0xe0, 0xfe, 0x1f, 0x10, 0x0f, 0x10, 0x0f, 0x8f, 0x1f, 0x14, 0x0f, 0x13, 0x0f, 0x8f, 0x1f,
+        0x13, 0x0f, 0x13, 0x0f, 0xff, 0xff, 0xff, 0x1b, 0x0f, 0x08, 0x02, 0x02, 0x02, 0x02, 0x02,
+        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0xa4, 0x00, 0x1a, 0x1d, 0xff, 0x28, 0x02, 0x02, 0x00,
+        0x76, 0x87, 0x56, 0x67, 0x78, 0xa9, 0x86, 0x65, 0x89, 0x68, 0x98, 0x01, 0x69, 0x00, 0x00,
+    ];
+    let indices: Vec<u8> = [
+        0u16, 1, 4, 4, 1, 5, 5, 1, 2, 5, 2, 6, 6, 2, 3, 6, 3, 7, 4, 5, 8, 8, 5, 9, 9, 5, 6, 9, 6,
+        10, 10, 6, 7, 10, 7, 11, 8, 9, 12, 12, 9, 13, 13, 9, 10, 13, 10, 14, 14, 10, 11, 14, 11,
+        15, 15, 3, 12, 0, 15, 7, 20, 21, 22, 22, 21, 9,
+    ]
+    .iter()
+    .flat_map(|x| x.to_le_bytes())
+    .collect();
+    assert_eq!(
+        ext_meshopt_compression::decode_index_buffer(&grid, 66, 2).unwrap(),
+        indices
+    );
+
+    // In version 1, a zero `codeaux` read from the data restarts the
+    // numbering, both when the first vertex is the next one (0xfe) and when
+    // it is encoded explicitly (0xff).
+    let mut restart = vec![0xe1, 0xfe, 0xff, 0x00, 0x00, 0x0a];
+    restart.extend_from_slice(&[0; 16]);
+    let indices: Vec<u8> = [0u16, 1, 2, 5, 0, 1]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect();
+    assert_eq!(
+        ext_meshopt_compression::decode_index_buffer(&restart, 6, 2).unwrap(),
+        indices
+    );
+
+    let sequence = [0xd1, 0x14, 0x06, 0xa1, 0x1f, 0x00, 0x00, 0x00, 0x00];
+    let indices: Vec<u8> = [5u32, 3, 1000]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect();
+    assert_eq!(
+        ext_meshopt_compression::decode_index_sequence(&sequence, 3, 4).unwrap(),
+        indices
+    );
+
+    let mut exponential = ((-2i32 << 24) as u32 | 6).to_le_bytes();
+    ext_meshopt_compression::decode_filter_exponential(&mut exponential, 4).unwrap();
+    assert_eq!(f32::from_le_bytes(exponential), 1.5);
+
+    // The compressed vertices are decoded into the fallback buffer on import.
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["EXT_meshopt_compression"],
+        "extensionsRequired": ["EXT_meshopt_compression"],
+        "buffers": [
+            {"byteLength": 83},
+            {"byteLength": 80, "extensions": {"EXT_meshopt_compression": {"fallback": true}}}
+        ],
+        "bufferViews": [{
+            "buffer": 1,
+            "byteLength": 80,
+            "byteStride": 16,
+            "extensions": {
+                "EXT_meshopt_compression": {
+                    "buffer": 0,
+                    "byteLength": 83,
+                    "byteStride": 16,
+                    "count": 5,
+                    "mode": "ATTRIBUTES"
+                }
+            }
+        }]
+    }"#;
+    compressed.resize(84, 0);
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: Cow::Borrowed(json.as_bytes()),
+        bin: Some(Cow::Owned(compressed)),
+    };
+    let (document, buffers, _) = gltf::import_slice(glb.to_vec().unwrap()).unwrap();
+    let view = document.views().next().unwrap();
+    let compression = view.meshopt_compression().unwrap();
+    assert_eq!(compression.mode(), Mode::Attributes);
+    assert!(view.buffer().is_meshopt_fallback());
+    assert_eq!(&buffers[1][..80], &expected[..]);
+
+    // Invalid references are reported rather than panicking when validation
+    // is skipped.
+    let json = json.replace(r#""buffer": 0"#, r#""buffer": 2"#);
+    let glb = gltf::binary::Glb {
+        json: Cow::Owned(json.into_bytes()),
+        ..glb
+    };
+    let options = gltf::LoadOptions {
+        validation: gltf::Validation::Skip,
+        ..Default::default()
+    };
+    let resolver = gltf::DefaultResolver::new(None);
+    let result = gltf::import_slice_with_options(glb.to_vec().unwrap(), &resolver, &options);
+    assert!(matches!(
+        result,
+        Err(gltf::Error::MeshoptCompression {
+            view: 0,
+            error: ext_meshopt_compression::Error::UnexpectedEnd,
+        })
+    ));
+}
+
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[test]
+fn test_draco_mesh_compression() {
+    use gltf::khr_draco_mesh_compression::{self, Values};
+    use std::borrow::Cow;
+
+    // A quad of two triangles with sequential connectivity, generic
+    // positions and quantized texture coordinates predicted from the
+    // previous value.
+    let mut compressed = b"DRACO".to_vec();
+    compressed.extend_from_slice(&[2, 2, 1, 0, 0, 0]);
+    compressed.extend_from_slice(&[2, 4, 1, 0, 1, 2, 0, 2, 3]);
+    compressed.extend_from_slice(&[1, 2, 0, 9, 3, 0, 0, 3, 9, 2, 0, 1, 0, 2]);
+    let positions: [f32; 12] = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0];
+    compressed.extend(positions.iter().flat_map(|x| x.to_le_bytes()));
+    compressed.extend_from_slice(&[0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 2, 0]);
+    compressed.extend(0i32.to_le_bytes());
+    compressed.extend(255i32.to_le_bytes());
+    for value in [0.0f32, 0.0, 1.0] {
+        compressed.extend(value.to_le_bytes());
+    }
+    compressed.push(8);
+
+    let mesh = khr_draco_mesh_compression::decode(&compressed).unwrap();
+    assert_eq!(mesh.indices, [0, 1, 2, 0, 2, 3]);
+    assert_eq!(mesh.num_points, 4);
+    assert_eq!(
+        mesh.attribute(0).unwrap().values,
+        Values::Float(positions.to_vec())
+    );
+    assert_eq!(
+        mesh.attribute(1).unwrap().values,
+        Values::Float(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0])
+    );
+
+    // A single triangle with Edgebreaker connectivity, whose values are
+    // coded in the order of a depth first traversal.
+    let mut edgebreaker = b"DRACO".to_vec();
+    edgebreaker.extend_from_slice(&[2, 2, 1, 1, 0, 0, 0]);
+    edgebreaker.extend_from_slice(&[3, 1, 0, 1, 0, 5, 1, 7, 255, 1, 1, 0]);
+    edgebreaker.extend_from_slice(&[1, 0xff, 0, 0, 1, 0, 9, 3, 0, 0, 0]);
+    edgebreaker.extend(positions[..9].iter().flat_map(|x| x.to_le_bytes()));
+    let mesh = khr_draco_mesh_compression::decode(&edgebreaker).unwrap();
+    assert_eq!(mesh.indices, [0, 1, 2]);
+    assert_eq!(
+        mesh.attribute(0).unwrap().values,
+        Values::Float(vec![1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0])
+    );
+
+    // Corrupted data is rejected without panicking.
+    for data in [&compressed, &edgebreaker] {
+        for position in 5..data.len() {
+            for value in [0, 1, 2, 3, 4, 5, 7, 9, 0x7f, 0x80, 0xfe, 0xff] {
+                let mut data = data.clone();
+                data[position] = value;
+                let _ = khr_draco_mesh_compression::decode(&data);
+            }
+        }
+    }
+
+    assert_eq!(
+        khr_draco_mesh_compression::decode(&compressed[..40]),
+        Err(khr_draco_mesh_compression::Error::UnexpectedEnd)
+    );
+    assert_eq!(
+        khr_draco_mesh_compression::decode(b"DRACO\x01\x02"),
+        Err(khr_draco_mesh_compression::Error::Version(1, 2))
+    );
+
+    // The primitive is decoded on import and read like uncompressed data.
+    let json = format!(
+        r#"{{
+        "asset": {{"version": "2.0"}},
+        "extensionsUsed": ["KHR_draco_mesh_compression"],
+        "extensionsRequired": ["KHR_draco_mesh_compression"],
+        "buffers": [{{"byteLength": {length}}}],
+        "bufferViews": [{{"buffer": 0, "byteLength": {length}}}],
+        "accessors": [
+            {{"componentType": 5123, "count": 6, "type": "SCALAR"}},
+            {{
+                "componentType": 5126,
+                "count": 4,
+                "type": "VEC3",
+                "min": [0.0, 0.0, 0.0],
+                "max": [1.0, 1.0, 0.0]
+            }},
+            {{"componentType": 5126, "count": 4, "type": "VEC2"}}
+        ],
+        "meshes": [{{
+            "primitives": [{{
+                "attributes": {{"POSITION": 1, "TEXCOORD_0": 2}},
+                "indices": 0,
+                "extensions": {{
+                    "KHR_draco_mesh_compression": {{
+                        "bufferView": 0,
+                        "attributes": {{"POSITION": 0, "TEXCOORD_0": 1}}
+                    }}
+                }}
+            }}]
+        }}]
+    }}"#,
+        length = compressed.len()
+    );
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: Cow::Borrowed(json.as_bytes()),
+        bin: Some(Cow::Owned(compressed)),
+    };
+    let glb = glb.to_vec().unwrap();
+    let options = gltf::LoadOptions {
+        load_buffers: false,
+        load_images: false,
+        ..Default::default()
+    };
+    let resolver = gltf::DefaultResolver::new(None);
+    let (document, buffers, _) =
+        gltf::import_slice_with_options(&glb, &resolver, &options).unwrap();
+    assert!(buffers.is_empty());
+    assert!(document.extensions_used().next().is_none());
+
+    let (document, buffers, _) = gltf::import_slice(glb).unwrap();
+    assert!(document.extensions_required().next().is_none());
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    assert!(primitive.draco_mesh_compression().is_none());
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    let indices: Vec<u32> = reader.read_indices().unwrap().into_u32().collect();
+    assert_eq!(indices, [0, 1, 2, 0, 2, 3]);
+    let positions: Vec<[f32; 3]> = reader.read_positions().unwrap().collect();
+    assert_eq!(positions[2], [1.0, 1.0, 0.0]);
+    let tex_coords: Vec<[f32; 2]> = reader.read_tex_coords(0).unwrap().into_f32().collect();
+    assert_eq!(tex_coords, [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+}
+
+#[cfg(feature = "KHR_texture_basisu")]
+#[test]
+fn test_texture_basisu() {
+    use gltf::khr_texture_basisu::{self, Error, Level, SupercompressionScheme};
+
+    // A 4x4 UASTC texture with one level, a key/value pair and a basic data
+    // format descriptor with one sample.
+    let mut ktx2 = vec![
+        0xab, 0x4b, 0x54, 0x58, 0x20, 0x32, 0x30, 0xbb, 0x0d, 0x0a, 0x1a, 0x0a,
+    ];
+    for value in [0u32, 1, 4, 4, 0, 0, 1, 1, 0] {
+        ktx2.extend(value.to_le_bytes());
+    }
+    let dfd_offset = 80 + 24;
+    let dfd_length = 4 + 24 + 16;
+    let kvd_offset = dfd_offset + dfd_length;
+    let kvd = b"KTXwriter\0test\0";
+    let kvd_length = 4 + kvd.len() + 1;
+    let level_offset = kvd_offset + kvd_length;
+    for value in [dfd_offset, dfd_length, kvd_offset, kvd_length] {
+        ktx2.extend((value as u32).to_le_bytes());
+    }
+    ktx2.extend([0u8; 16]);
+    for value in [level_offset, 16, 16] {
+        ktx2.extend((value as u64).to_le_bytes());
+    }
+    ktx2.extend((dfd_length as u32).to_le_bytes());
+    ktx2.extend(0u32.to_le_bytes());
+    ktx2.extend((2u32 | 40 << 16).to_le_bytes());
+    ktx2.extend([166, 1, 2, 0, 3, 3, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0]);
+    ktx2.extend([0, 0, 127, 0, 0, 0, 0, 0]);
+    ktx2.extend(0xffff_ffffu32.to_le_bytes());
+    ktx2.extend(0xffff_ffffu32.to_le_bytes());
+    ktx2.extend((kvd.len() as u32).to_le_bytes());
+    ktx2.extend(kvd);
+    ktx2.push(0);
+    ktx2.extend(0..16u8);
+
+    let parsed = khr_texture_basisu::parse(&ktx2).unwrap();
+    assert_eq!(parsed.header().pixel_width, 4);
+    assert_eq!(
+        parsed.header().supercompression_scheme,
+        SupercompressionScheme::None
+    );
+    assert_eq!(
+        parsed.levels(),
+        [Level {
+            offset: level_offset,
+            length: 16,
+            uncompressed_length: 16,
+        }]
+    );
+    assert_eq!(parsed.level(0), Some(&ktx2[level_offset..]));
+    assert_eq!(parsed.key_value("KTXwriter"), Some(&b"test\0"[..]));
+    let descriptor = parsed.basic_descriptor().unwrap();
+    assert_eq!(
+        descriptor.color_model,
+        khr_texture_basisu::KHR_DF_MODEL_UASTC
+    );
+    assert_eq!(
+        descriptor.transfer_function,
+        khr_texture_basisu::KHR_DF_TRANSFER_SRGB
+    );
+    assert_eq!(descriptor.texel_block_dimensions, [4, 4, 1, 1]);
+    assert_eq!(descriptor.samples.len(), 1);
+    assert_eq!(descriptor.samples[0].bit_length, 128);
+    assert!(parsed.is_basis_universal());
+
+    assert_eq!(
+        khr_texture_basisu::parse(b"KTX 11").unwrap_err(),
+        Error::Identifier
+    );
+    assert_eq!(
+        khr_texture_basisu::parse(&ktx2[..ktx2.len() - 1]).unwrap_err(),
+        Error::UnexpectedEnd
+    );
+
+    // The texture has no core source and resolves to the KTX2 image.
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["KHR_texture_basisu"],
+        "extensionsRequired": ["KHR_texture_basisu"],
+        "images": [{"uri": "a.png"}, {"uri": "b.ktx2", "mimeType": "image/ktx2"}],
+        "textures": [{"extensions": {"KHR_texture_basisu": {"source": 1}}}]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let texture = gltf.textures().next().unwrap();
+    assert_eq!(texture.texture_basisu().unwrap().source().index(), 1);
+    #[cfg(not(feature = "allow_empty_texture"))]
+    assert_eq!(texture.source().index(), 1);
+    #[cfg(feature = "allow_empty_texture")]
+    assert_eq!(texture.source().unwrap().index(), 1);
+
+    // KTX2 images are kept encoded on import.
+    let json = format!(
+        r#"{{
+        "asset": {{"version": "2.0"}},
+        "buffers": [{{"byteLength": {length}}}],
+        "bufferViews": [{{"buffer": 0, "byteLength": {length}}}],
+        "images": [{{"bufferView": 0, "mimeType": "image/ktx2"}}]
+    }}"#,
+        length = ktx2.len()
+    );
+    let mut bin = ktx2.clone();
+    bin.resize((ktx2.len() + 3) & !3, 0);
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: std::borrow::Cow::Borrowed(json.as_bytes()),
+        bin: Some(std::borrow::Cow::Owned(bin)),
+    };
+    let (_, _, images) = gltf::import_slice(glb.to_vec().unwrap()).unwrap();
+    assert_eq!(images[0].format, gltf::image::Format::Ktx2);
+    assert_eq!((images[0].width, images[0].height), (4, 4));
+    assert_eq!(images[0].pixels, ktx2);
+    let parsed = khr_texture_basisu::parse(&images[0].pixels).unwrap();
+    assert_eq!(parsed.level(0), Some(&ktx2[level_offset..]));
+}
+
+#[cfg(feature = "MSFT_texture_dds")]
+#[test]
+fn test_texture_dds() {
+    use gltf::msft_texture_dds::{self, Error, Level};
+
+    fn header(height: u32, width: u32, mips: u32, flags: u32, four_cc: &[u8; 4]) -> Vec<u8> {
+        let mut dds = b"DDS ".to_vec();
+        for value in [124, 0, height, width, 0, 0, mips] {
+            dds.extend(u32::to_le_bytes(value));
+        }
+        dds.extend([0; 44]);
+        dds.extend(32u32.to_le_bytes());
+        dds.extend(flags.to_le_bytes());
+        dds.extend(four_cc);
+        dds.extend([0; 40]);
+        dds
+    }
+
+    // A 4x4 BC1 texture array of two elements with three levels each.
+    let mut dx10 = header(4, 4, 3, 0x4, b"DX10");
+    for value in [71u32, 3, 0, 2, 0] {
+        dx10.extend(value.to_le_bytes());
+    }
+    dx10.extend((0..48).map(|i| i as u8));
+    let dds = msft_texture_dds::parse(&dx10).unwrap();
+    assert_eq!(dds.dxgi_format(), Some(71));
+    assert_eq!((dds.width(), dds.height(), dds.mip_count()), (4, 4, 3));
+    assert_eq!((dds.array_size(), dds.face_count()), (2, 1));
+    let levels = dds.levels().unwrap();
+    assert_eq!(levels.len(), 6);
+    assert_eq!(
+        levels[4],
+        Level {
+            layer: 1,
+            face: 0,
+            level: 1,
+            width: 2,
+            height: 2,
+            depth: 1,
+            offset: 148 + 32,
+            length: 8,
+        }
+    );
+    assert_eq!(dds.level(1, 0, 2), Some(&dx10[148 + 40..]));
+    assert_eq!(
+        msft_texture_dds::parse(&dx10[..dx10.len() - 1]).unwrap_err(),
+        Error::UnexpectedEnd
+    );
+
+    // A legacy cubemap of 2x2 RGBA pixels.
+    let mut legacy = header(2, 2, 1, 0x41, &[0; 4]);
+    legacy[88..108].copy_from_slice(
+        &[32u32, 0xff, 0xff00, 0xff_0000, 0xff00_0000]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<_>>(),
+    );
+    legacy[112..116].copy_from_slice(&0xfe00u32.to_le_bytes());
+    legacy.extend([0; 6 * 16]);
+    let dds = msft_texture_dds::parse(&legacy).unwrap();
+    assert_eq!(dds.dxgi_format(), Some(28));
+    assert!(dds.is_cubemap());
+    assert_eq!(dds.face_count(), 6);
+    assert_eq!(dds.levels().unwrap().len(), 6);
+    assert_eq!(dds.level(0, 5, 0).unwrap().len(), 16);
+
+    assert_eq!(msft_texture_dds::parse(b"KTX ").unwrap_err(), Error::Magic);
+
+    // The size of the levels does not overflow.
+    let wide = header(1, u32::MAX, 1, 0x4, b"DXT1");
+    assert_eq!(
+        msft_texture_dds::parse(&wide).unwrap_err(),
+        Error::UnexpectedEnd
+    );
+
+    // DDS images are kept encoded on import.
+    let json = format!(
+        r#"{{
+        "asset": {{"version": "2.0"}},
+        "buffers": [{{"byteLength": {length}}}],
+        "bufferViews": [{{"buffer": 0, "byteLength": {length}}}],
+        "images": [{{"bufferView": 0, "mimeType": "image/vnd-ms.dds"}}]
+    }}"#,
+        length = legacy.len()
+    );
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: std::borrow::Cow::Borrowed(json.as_bytes()),
+        bin: Some(std::borrow::Cow::Borrowed(&legacy)),
+    };
+    let (_, _, images) = gltf::import_slice(glb.to_vec().unwrap()).unwrap();
+    assert_eq!(images[0].format, gltf::image::Format::Dds);
+    assert_eq!((images[0].width, images[0].height), (2, 2));
+    assert_eq!(images[0].pixels, legacy);
+
+    // The texture resolves to the DDS image rather than the core source.
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["MSFT_texture_dds"],
+        "images": [{"uri": "a.png"}, {"uri": "b.dds", "mimeType": "image/vnd-ms.dds"}],
+        "textures": [{"source": 0, "extensions": {"MSFT_texture_dds": {"source": 1}}}]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let texture = gltf.textures().next().unwrap();
+    assert_eq!(texture.texture_dds().unwrap().source().index(), 1);
+    #[cfg(not(feature = "allow_empty_texture"))]
+    assert_eq!(texture.source().index(), 1);
+    #[cfg(feature = "allow_empty_texture")]
+    assert_eq!(texture.source().unwrap().index(), 1);
+}
+
+#[cfg(feature = "KHR_animation_pointer")]
+#[test]
+fn test_animation_pointer() {
+    use gltf::animation::util::{Pointer, ReadOutputs};
+    use gltf::animation::Property;
+    use gltf::khr_animation_pointer::{Object, ValueType};
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "buffers": [{"byteLength": 48}],
+            "bufferViews": [{"buffer": 0, "byteLength": 48}],
+            "accessors": [
+                {"bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR"},
+                {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC2"}
+            ],
+            "materials": [{}, {}],
+            "nodes": [{}],
+            "animations": [{
+                "samplers": [{"input": 0, "output": 1}, {"input": 0, "output": 0}],
+                "channels": [
+                    {
+                        "sampler": 0,
+                        "target": {
+                            "path": "pointer",
+                            "extensions": {"KHR_animation_pointer": {
+                                "pointer": "/materials/1/normalTexture/extensions/KHR_texture_transform/offset"
+                            }}
+                        }
+                    },
+                    {"sampler": 1, "target": {"node": 0, "path": "scale"}}
+                ]
+            }]
+        }"#,
+    )
+    .unwrap();
+    let animation = gltf.animations().next().unwrap();
+    let mut channels = animation.channels();
+
+    let buffer: Vec<u8> = [0.0f32, 1.0, 0.25, 0.5, 0.75, 1.0]
+        .iter()
+        .chain(&[0.0; 6])
+        .flat_map(|x| x.to_le_bytes())
+        .collect();
+    let channel = channels.next().unwrap();
+    match channel.reader(|_| Some(&buffer)).read_outputs() {
+        Some(ReadOutputs::Pointer(Pointer::Vec2(outputs))) => {
+            assert_eq!(outputs.collect::<Vec<_>>(), [[0.0, 1.0], [0.25, 0.5]]);
+        }
+        _ => panic!("expected float2 outputs"),
+    }
+
+    let target = channel.target();
+    assert_eq!(target.property(), Property::Pointer);
+    assert!(target.try_node().is_none());
+    let pointer = target.pointer().unwrap();
+    assert_eq!(
+        pointer.property(),
+        "normalTexture/extensions/KHR_texture_transform/offset"
+    );
+    assert_eq!(pointer.value_type(), ValueType::Float2);
+    match pointer.object() {
+        Object::Material(material) => assert_eq!(material.index(), Some(1)),
+        _ => panic!("expected a material"),
+    }
+
+    let target = channels.next().unwrap().target();
+    assert_eq!(target.node().index(), 0);
+    assert!(target.pointer().is_none());
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+#[test]
+fn test_xmp_json_ld() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {
+                "version": "2.0",
+                "extensions": {"KHR_xmp_json_ld": {"packet": 0}}
+            },
+            "extensionsUsed": ["KHR_xmp_json_ld"],
+            "extensions": {"KHR_xmp_json_ld": {"packets": [
+                {
+                    "@context": {"dc": "http://purl.org/dc/elements/1.1/"},
+                    "dc:title": {"@type": "rdf:Alt", "rdf:_1": {"@value": "Box"}}
+                },
+                {
+                    "@context": {"xmpRights": "http://ns.adobe.com/xap/1.0/rights/"},
+                    "xmpRights:Owner": "Someone"
+                }
+            ]}},
+            "materials": [{"extensions": {"KHR_xmp_json_ld": {"packet": 1}}}],
+            "nodes": [{}]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(gltf.xmp_packets().unwrap().len(), 2);
+
+    let packet = gltf.xmp_packet().unwrap();
+    assert_eq!(packet.index(), 0);
+    assert!(packet.context().unwrap().contains_key("dc"));
+    assert!(packet.get("dc:title").is_some());
+
+    let material = gltf.materials().next().unwrap();
+    let packet = material.xmp_packet().unwrap();
+    assert_eq!(packet.index(), 1);
+    assert_eq!(packet.get("xmpRights:Owner").unwrap(), "Someone");
+    assert_eq!(packet.properties().len(), 2);
+
+    assert!(gltf.nodes().next().unwrap().xmp_packet().is_none());
+}
+
+#[cfg(all(feature = "EXT_lights_image_based", feature = "import"))]
+#[test]
+fn test_lights_image_based() {
+    use gltf::image::Format;
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "extensionsUsed": ["EXT_lights_image_based"],
+            "images": [{"uri": "level0.hdr"}, {"uri": "level1.hdr"}],
+            "extensions": {"EXT_lights_image_based": {"lights": [{
+                "intensity": 2.0,
+                "irradianceCoefficients": [
+                    [1.0, 1.0, 1.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+                    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+                    [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]
+                ],
+                "specularImageSize": 2,
+                "specularImages": [[0, 0, 0, 0, 0, 0], [1, 1, 1, 1, 1, 1]]
+            }]}},
+            "scenes": [{"nodes": [], "extensions": {"EXT_lights_image_based": {"light": 0}}}]
+        }"#,
+    )
+    .unwrap();
+    let light = gltf.scenes().next().unwrap().image_based_light().unwrap();
+    assert_eq!(light.rotation(), [0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(light.intensity(), 2.0);
+    assert_eq!(light.irradiance_coefficients().len(), 9);
+    assert_eq!(light.mip_count(), 2);
+    assert_eq!(light.specular_image_size_at(1), 1);
+    let levels: Vec<_> = light.specular_images().collect();
+    assert_eq!(levels[1][5].index(), 1);
+
+    // Uncompressed Radiance RGBE images of one level's face size.
+    let resolver = |uri: &str| -> gltf::Result<Vec<u8>> {
+        let size = if uri == "level0.hdr" { 2 } else { 1 };
+        let mut hdr =
+            format!("#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y {size} +X {size}\n").into_bytes();
+        for _ in 0..size * size {
+            hdr.extend([128, 64, 32, 129]);
+        }
+        Ok(hdr)
+    };
+    let cubemap = gltf::import_image_based_light_with_resolver(&light, &resolver, &[]).unwrap();
+    assert_eq!(cubemap.len(), 2);
+    for (level, faces) in cubemap.iter().enumerate() {
+        for face in faces {
+            assert_eq!(face.format, Format::R32G32B32FLOAT);
+            assert_eq!(face.width, light.specular_image_size_at(level));
+            assert_eq!(face.height, light.specular_image_size_at(level));
+        }
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+#[test]
+fn test_material_iridescence() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "extensionsUsed": ["KHR_materials_iridescence"],
+            "images": [{"uri": "iridescence.png"}],
+            "textures": [{"source": 0}, {"source": 0}],
+            "materials": [
+                {"extensions": {"KHR_materials_iridescence": {
+                    "iridescenceFactor": 1.0,
+                    "iridescenceTexture": {"index": 0},
+                    "iridescenceIor": 1.8,
+                    "iridescenceThicknessMaximum": 1200.0,
+                    "iridescenceThicknessTexture": {"index": 1, "texCoord": 1}
+                }}},
+                {"extensions": {"KHR_materials_iridescence": {}}},
+                {}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let mut materials = gltf.materials();
+
+    let iridescence = materials.next().unwrap().iridescence().unwrap();
+    assert_eq!(iridescence.iridescence_factor(), 1.0);
+    assert_eq!(iridescence.iridescence_ior(), 1.8);
+    assert_eq!(iridescence.iridescence_thickness_minimum(), 100.0);
+    assert_eq!(iridescence.iridescence_thickness_maximum(), 1200.0);
+    let texture = iridescence.iridescence_texture().unwrap();
+    assert_eq!(texture.texture().index(), 0);
+    assert_eq!(texture.tex_coord(), 0);
+    let texture = iridescence.iridescence_thickness_texture().unwrap();
+    assert_eq!(texture.texture().index(), 1);
+    assert_eq!(texture.tex_coord(), 1);
+
+    let iridescence = materials.next().unwrap().iridescence().unwrap();
+    assert_eq!(iridescence.iridescence_factor(), 0.0);
+    assert_eq!(iridescence.iridescence_ior(), 1.3);
+    assert_eq!(iridescence.iridescence_thickness_minimum(), 100.0);
+    assert_eq!(iridescence.iridescence_thickness_maximum(), 400.0);
+    assert!(iridescence.iridescence_texture().is_none());
+    assert!(iridescence.iridescence_thickness_texture().is_none());
+
+    assert!(materials.next().unwrap().iridescence().is_none());
+}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[test]
+fn test_material_anisotropy() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "extensionsUsed": ["KHR_materials_anisotropy"],
+            "images": [{"uri": "anisotropy.png"}],
+            "textures": [{"source": 0}],
+            "materials": [
+                {"extensions": {"KHR_materials_anisotropy": {
+                    "anisotropyStrength": 0.5,
+                    "anisotropyRotation": 1.57,
+                    "anisotropyTexture": {"index": 0}
+                }}},
+                {"extensions": {"KHR_materials_anisotropy": {}}},
+                {}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let mut materials = gltf.materials();
+
+    let anisotropy = materials.next().unwrap().anisotropy().unwrap();
+    assert_eq!(anisotropy.anisotropy_strength(), 0.5);
+    assert_eq!(anisotropy.anisotropy_rotation(), 1.57);
+    assert_eq!(
+        anisotropy.anisotropy_texture().unwrap().texture().index(),
+        0
+    );
+
+    let anisotropy = materials.next().unwrap().anisotropy().unwrap();
+    assert_eq!(anisotropy.anisotropy_strength(), 0.0);
+    assert_eq!(anisotropy.anisotropy_rotation(), 0.0);
+    assert!(anisotropy.anisotropy_texture().is_none());
+
+    assert!(materials.next().unwrap().anisotropy().is_none());
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+#[test]
+fn test_material_for_variant() {
+    let gltf = gltf::Gltf::from_slice_without_validation(
+        br#"{
+            "asset": {"version": "2.0"},
+            "extensionsUsed": ["KHR_materials_variants"],
+            "extensions": {"KHR_materials_variants": {"variants": [
+                {"name": "day"}, {"name": "night"}, {"name": "dusk"}
+            ]}},
+            "materials": [{}, {}, {}],
+            "meshes": [{"primitives": [
+                {
+                    "attributes": {},
+                    "material": 0,
+                    "extensions": {"KHR_materials_variants": {"mappings": [
+                        {"material": 1, "variants": [0]},
+                        {"material": 2, "variants": [1]}
+                    ]}}
+                },
+                {"attributes": {}}
+            ]}]
+        }"#,
+    )
+    .unwrap();
+    let mesh = gltf.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+
+    let primitive = primitives.next().unwrap();
+    let material = |variant: Option<gltf::Material>| variant.map(|m| m.index());
+    assert_eq!(material(primitive.material_for_variant(0)), Some(Some(1)));
+    assert_eq!(
+        material(primitive.material_for_variant("night")),
+        Some(Some(2))
+    );
+    assert_eq!(
+        material(primitive.material_for_variant("dusk")),
+        Some(Some(0))
+    );
+    assert!(primitive.material_for_variant(3).is_none());
+    assert!(primitive.material_for_variant("noon").is_none());
+
+    let primitive = primitives.next().unwrap();
+    assert_eq!(material(primitive.material_for_variant("day")), Some(None));
+}
+
+#[cfg(feature = "extensions")]
+#[test]
+fn test_extension_registry() {
+    use gltf::extension::{Extension, Kind, Registry};
+    use gltf::json::validation::{Error, Validate};
+    use gltf::json::Path;
+
+    #[derive(serde_derive::Deserialize)]
+    struct Tag {
+        label: String,
+    }
+
+    impl Validate for Tag {
+        fn validate<P, R>(&self, _root: &gltf::json::Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            if self.label.is_empty() {
+                report(&|| path().field("label"), Error::Invalid);
+            }
+        }
+    }
+
+    impl Extension for Tag {
+        const NAME: &'static str = "EXT_example_tag";
+    }
+
+    let gltf = gltf::Gltf::from_slice_without_validation(
+        br#"{
+            "asset": {"version": "2.0"},
+            "extensionsUsed": ["EXT_example_tag"],
+            "extensionsRequired": ["EXT_example_tag"],
+            "meshes": [{"primitives": [
+                {"attributes": {}, "extensions": {"EXT_example_tag": {"label": ""}}}
+            ]}],
+            "nodes": [
+                {"extensions": {"EXT_example_tag": {"label": "door"}}},
+                {"extensions": {"EXT_example_tag": {"name": "window"}}},
+                {}
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let mut nodes = gltf.nodes();
+    assert_eq!(
+        nodes.next().unwrap().extension::<Tag>().unwrap().label,
+        "door"
+    );
+    assert!(nodes.next().unwrap().extension::<Tag>().is_none());
+    assert!(nodes.next().unwrap().extension::<Tag>().is_none());
+
+    let errors = |registry: &Registry| match registry.validate(&gltf) {
+        Err(gltf::Error::Validation(errors)) => errors
+            .into_iter()
+            .map(|(path, error)| (path.to_string(), error))
+            .filter(|(path, _)| !path.contains("POSITION"))
+            .collect::<Vec<_>>(),
+        _ => panic!("expected validation errors"),
+    };
+
+    let mut registry = Registry::new();
+    assert_eq!(
+        errors(&registry),
+        [(
+            "extensionsRequired[0] = \"EXT_example_tag\"".to_string(),
+            Error::Unsupported
+        )]
+    );
+
+    registry.register::<Tag>(Kind::Node);
+    assert!(registry.contains("EXT_example_tag"));
+    assert_eq!(
+        errors(&registry),
+        [(
+            "nodes[1].extensions.EXT_example_tag".to_string(),
+            Error::Invalid
+        )]
+    );
+
+    registry.register::<Tag>(Kind::Primitive);
+    assert_eq!(
+        errors(&registry),
+        [
+            (
+                "nodes[1].extensions.EXT_example_tag".to_string(),
+                Error::Invalid
+            ),
+            (
+                "meshes[0].primitives[0].extensions.EXT_example_tag.label".to_string(),
+                Error::Invalid
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_load_options() {
+    use gltf::json::validation::Error;
+    use gltf::{Gltf, LoadOptions, Validation};
+
+    let json = br#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["EXT_example"],
+        "extensionsRequired": ["EXT_example"],
+        "images": [{"uri": "image.png"}],
+        "textures": [{}, {"source": 0}]
+    }"#;
+
+    let errors = |options: &LoadOptions| match Gltf::from_slice_with_options(json, options) {
+        Ok(_) => Vec::new(),
+        Err(gltf::Error::Validation(errors)) => errors
+            .into_iter()
+            .map(|(path, error)| (path.to_string(), error))
+            .collect(),
+        Err(error) => panic!("{}", error),
+    };
+
+    let mut options = LoadOptions::default();
+    let unsupported = (
+        "extensionsRequired[0] = \"EXT_example\"".to_string(),
+        Error::Unsupported,
+    );
+    if cfg!(feature = "allow_empty_texture") {
+        assert_eq!(errors(&options), [unsupported]);
+    } else {
+        let missing = ("textures[0].source".to_string(), Error::Missing);
+        assert_eq!(errors(&options), [missing, unsupported]);
+    }
+
+    options.required_extensions.push("EXT_example".to_string());
+    options.allow_empty_texture = true;
+    assert!(errors(&options).is_empty());
+
+    let gltf = Gltf::from_slice_with_options(json, &options).unwrap();
+    let mut textures = gltf.textures();
+    assert!(textures.next().unwrap().try_source().is_none());
+    assert_eq!(textures.next().unwrap().try_source().unwrap().index(), 0);
+
+    options.required_extensions.clear();
+    options.validation = Validation::Skip;
+    assert!(errors(&options).is_empty());
+}
+
+#[test]
+fn test_import_with_options() {
+    let options = gltf::LoadOptions {
+        load_images: false,
+        ..Default::default()
+    };
+    let (document, buffers, images) =
+        gltf::import_with_options("examples/Box.gltf", &options).unwrap();
+    assert_eq!(buffers.len(), document.buffers().count());
+    assert!(images.is_empty());
+
+    let options = gltf::LoadOptions {
+        load_buffers: false,
+        load_images: false,
+        ..Default::default()
+    };
+    let (_, buffers, images) = gltf::import_with_options("examples/Box.gltf", &options).unwrap();
+    assert!(buffers.is_empty());
+    assert!(images.is_empty());
+}